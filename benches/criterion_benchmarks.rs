@@ -59,7 +59,7 @@ fn bench_border_styles(c: &mut Criterion) {
                     let _ = boxen(
                         black_box("Test content"),
                         Some(BoxenOptions {
-                            border_style: *style,
+                            border_style: style.clone(),
                             ..Default::default()
                         }),
                     );
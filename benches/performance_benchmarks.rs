@@ -385,7 +385,7 @@ fn bench_border_styles(c: &mut Criterion) {
                     boxen(
                         SIMPLE_TEXT,
                         Some(BoxenOptions {
-                            border_style: *style,
+                            border_style: style.clone(),
                             ..Default::default()
                         }),
                     )
@@ -83,7 +83,7 @@ fn demonstrate_border_styles() -> Result<(), Box<dyn std::error::Error>> {
     for (name, style) in &styles {
         println!("{name} border:");
         let options = BoxenOptions {
-            border_style: *style,
+            border_style: style.clone(),
             ..Default::default()
         };
         println!("{}", boxen(format!("{name} style example"), Some(options))?);
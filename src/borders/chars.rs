@@ -11,6 +11,7 @@
 //! - **Round**: Rounded corner characters (╭╮╰╯│─)
 //! - **Bold**: Thick/bold line characters (┏┓┗┛┃━)
 //! - **Classic**: ASCII-compatible characters (+|-) for maximum compatibility
+//! - **Classic rounded**: ASCII characters with `.` and `'` corners (.-'|)
 //! - **Mixed**: Combinations of single/double horizontal and vertical lines
 //!
 //! ## Usage Examples
@@ -26,10 +27,15 @@
 //! // Create custom uniform borders
 //! let stars = BorderChars::uniform('*');
 //!
+//! // Or spell out all eight positions in one string
+//! let template = BorderChars::from_template("╔═╗║╝═╚║").unwrap();
+//! assert_eq!(template, double);
+//!
 //! // Validate border characters
 //! assert!(single.validate().is_ok());
 //! ```
 
+use crate::error::{BoxenError, BoxenResult, ErrorRecommendation};
 use crate::options::BorderChars;
 
 impl BorderChars {
@@ -153,6 +159,100 @@ impl BorderChars {
         }
     }
 
+    /// Create `BorderChars` for ASCII borders with rounded-looking corners
+    #[must_use]
+    pub fn classic_rounded() -> Self {
+        Self {
+            top_left: '.',
+            top_right: '.',
+            bottom_left: '\'',
+            bottom_right: '\'',
+            left: '|',
+            right: '|',
+            top: '-',
+            bottom: '-',
+        }
+    }
+
+    /// Create `BorderChars` from an eight-character template string
+    ///
+    /// Characters are read clockwise starting at the top-left corner: top-left,
+    /// top, top-right, right, bottom-right, bottom, bottom-left, left. Each
+    /// position must be a single character; combining marks are rejected since
+    /// they would form a multi-character grapheme.
+    ///
+    /// # Errors
+    ///
+    /// Returns `BoxenError::InvalidBorderStyle` if the template does not contain
+    /// exactly eight characters or any character fails [`validate`](Self::validate).
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use ::boxen::BorderChars;
+    ///
+    /// let chars = BorderChars::from_template("+-+|+-+|").unwrap();
+    /// assert_eq!(chars, BorderChars::classic());
+    ///
+    /// assert!(BorderChars::from_template("+-+").is_err());
+    /// ```
+    pub fn from_template(template: &str) -> BoxenResult<Self> {
+        let chars: Vec<char> = template.chars().collect();
+        let has_combining_mark = chars
+            .iter()
+            .any(|ch| unicode_width::UnicodeWidthChar::width(*ch) == Some(0));
+
+        let [
+            top_left,
+            top,
+            top_right,
+            right,
+            bottom_right,
+            bottom,
+            bottom_left,
+            left,
+        ] = chars[..]
+        else {
+            return Err(Self::template_error(format!(
+                "Border template must contain exactly 8 characters, got {}",
+                chars.len()
+            )));
+        };
+        if has_combining_mark {
+            return Err(Self::template_error(
+                "Border template positions must be single characters without combining marks"
+                    .to_string(),
+            ));
+        }
+
+        let border_chars = Self {
+            top_left,
+            top_right,
+            bottom_left,
+            bottom_right,
+            left,
+            right,
+            top,
+            bottom,
+        };
+        border_chars.validate().map_err(|msg| {
+            Self::template_error(format!("Border template validation failed: {msg}"))
+        })?;
+        Ok(border_chars)
+    }
+
+    fn template_error(message: String) -> BoxenError {
+        BoxenError::invalid_border_style(
+            message,
+            vec![ErrorRecommendation::with_auto_fix(
+                "Invalid border template".to_string(),
+                "List top-left, top, top-right, right, bottom-right, bottom, bottom-left, left"
+                    .to_string(),
+                "BorderChars::from_template(\"┌─┐│┘─└│\")".to_string(),
+            )],
+        )
+    }
+
     /// Validate that all border characters are printable and not whitespace
     ///
     /// # Errors
@@ -287,6 +387,20 @@ mod tests {
         assert_eq!(chars.bottom, '-');
     }
 
+    #[test]
+    fn test_classic_rounded_border_chars() {
+        let chars = BorderChars::classic_rounded();
+        assert_eq!(chars.top_left, '.');
+        assert_eq!(chars.top_right, '.');
+        assert_eq!(chars.bottom_left, '\'');
+        assert_eq!(chars.bottom_right, '\'');
+        assert_eq!(chars.left, '|');
+        assert_eq!(chars.right, '|');
+        assert_eq!(chars.top, '-');
+        assert_eq!(chars.bottom, '-');
+        assert!(chars.validate().is_ok());
+    }
+
     #[test]
     fn test_uniform_border_chars() {
         let chars = BorderChars::uniform('*');
@@ -338,4 +452,37 @@ mod tests {
         assert!(!is_box_drawing_char(' '));
         assert!(!is_box_drawing_char('*'));
     }
+
+    #[test]
+    fn test_from_template() {
+        assert_eq!(
+            BorderChars::from_template("┌─┐│┘─└│").unwrap(),
+            BorderChars::single()
+        );
+
+        let chars = BorderChars::from_template("1234567A").unwrap();
+        assert_eq!(chars.top_left, '1');
+        assert_eq!(chars.top, '2');
+        assert_eq!(chars.top_right, '3');
+        assert_eq!(chars.right, '4');
+        assert_eq!(chars.bottom_right, '5');
+        assert_eq!(chars.bottom, '6');
+        assert_eq!(chars.bottom_left, '7');
+        assert_eq!(chars.left, 'A');
+    }
+
+    #[test]
+    fn test_from_template_errors() {
+        for template in ["", "┌─┐│┘─└", "┌─┐│┘─└││", "┌─┐│ ─└│", "e\u{0301}┐│┘─└│"]
+        {
+            let error = BorderChars::from_template(template).unwrap_err();
+            assert!(
+                matches!(error, BoxenError::InvalidBorderStyle { .. }),
+                "{template:?}"
+            );
+        }
+
+        let error = BorderChars::from_template("abc").unwrap_err();
+        assert!(error.to_string().contains("got 3"));
+    }
 }
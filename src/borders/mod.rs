@@ -39,8 +39,10 @@
 //! - **`SingleDouble`**: Single horizontal lines with double vertical lines
 //! - **`DoubleSingle`**: Double horizontal lines with single vertical lines
 //! - **Classic**: ASCII-compatible characters (+, -, |) for maximum compatibility
+//! - **`ClassicRounded`**: ASCII characters with `.` and `'` corners for a softer look
 //! - **None**: No visible border (content only)
 //! - **Custom**: User-defined character set with validation
+//! - **Registered**: A character set registered by name with [`register_style`]
 //!
 //! ### Visual Examples
 //!
@@ -50,10 +52,10 @@
 //! │Hello│        ║Hello║        │Hello│        ┃Hello┃
 //! └─────┘        ╚═════╝        ╰─────╯        ┗━━━━━┛
 //!
-//! Classic:       SingleDouble:  DoubleSingle:
-//! +-----+        ╓─────╖        ╒═════╕
-//! |Hello|        ║Hello║        │Hello│
-//! +-----+        ╙─────╜        ╘═════╛
+//! Classic:       SingleDouble:  DoubleSingle:  ClassicRounded:
+//! +-----+        ╓─────╖        ╒═════╕        .-----.
+//! |Hello|        ║Hello║        │Hello│        |Hello|
+//! +-----+        ╙─────╜        ╘═════╛        '-----'
 //! ```
 //!
 //! ## Border Character Validation
@@ -95,7 +97,9 @@
 //! between threads or used in concurrent rendering operations.
 
 pub mod chars;
+pub mod registry;
 pub mod styles;
 
 // Re-export key utilities for convenient access
+pub use registry::{get_registered, register_style, registered_styles};
 pub use styles::BorderStyleUtils;
@@ -0,0 +1,88 @@
+//! # Border Style Registry
+//!
+//! A process-wide table of named border character sets, for themes or plugins
+//! that want to refer to their borders by name. Register a set once with
+//! [`register_style`] and select it with `BorderStyle::Registered(name)`; the
+//! characters are looked up each time the border is drawn.
+//!
+//! ```rust
+//! use ::boxen::{BorderChars, BorderStyle, builder};
+//! use ::boxen::borders::register_style;
+//!
+//! register_style("stars", BorderChars::uniform('*'));
+//!
+//! let result = builder()
+//!     .border_style(BorderStyle::Registered("stars".to_string()))
+//!     .render("Hi")
+//!     .unwrap();
+//! assert_eq!(result, "****\n*Hi*\n****");
+//! ```
+
+use crate::options::BorderChars;
+use std::collections::HashMap;
+use std::sync::{OnceLock, PoisonError, RwLock};
+
+/// Registered border character sets, keyed by name
+static REGISTRY: OnceLock<RwLock<HashMap<String, BorderChars>>> = OnceLock::new();
+
+fn registry() -> &'static RwLock<HashMap<String, BorderChars>> {
+    REGISTRY.get_or_init(|| RwLock::new(HashMap::new()))
+}
+
+/// Register `chars` under `name`, replacing any set already registered there
+///
+/// The characters are validated when a box using them is rendered, like
+/// `BorderStyle::Custom`.
+pub fn register_style(name: &str, chars: BorderChars) {
+    registry()
+        .write()
+        .unwrap_or_else(PoisonError::into_inner)
+        .insert(name.to_string(), chars);
+}
+
+/// Look up the border characters registered under `name`
+#[must_use]
+pub fn get_registered(name: &str) -> Option<BorderChars> {
+    registry()
+        .read()
+        .unwrap_or_else(PoisonError::into_inner)
+        .get(name)
+        .copied()
+}
+
+/// Names of all registered border styles, sorted
+#[must_use]
+pub fn registered_styles() -> Vec<String> {
+    let mut names: Vec<String> = registry()
+        .read()
+        .unwrap_or_else(PoisonError::into_inner)
+        .keys()
+        .cloned()
+        .collect();
+    names.sort();
+    names
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_register_and_get() {
+        assert_eq!(get_registered("registry-test-missing"), None);
+
+        register_style("registry-test", BorderChars::uniform('#'));
+        assert_eq!(
+            get_registered("registry-test"),
+            Some(BorderChars::uniform('#'))
+        );
+        assert!(registered_styles().contains(&"registry-test".to_string()));
+
+        // Registering again replaces the set
+        register_style("registry-test", BorderChars::classic());
+        assert_eq!(
+            get_registered("registry-test"),
+            Some(BorderChars::classic())
+        );
+    }
+}
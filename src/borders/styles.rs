@@ -49,6 +49,7 @@ impl BorderStyle {
             BorderStyle::SingleDouble => Ok(BorderChars::single_double()),
             BorderStyle::DoubleSingle => Ok(BorderChars::double_single()),
             BorderStyle::Classic => Ok(BorderChars::classic()),
+            BorderStyle::ClassicRounded => Ok(BorderChars::classic_rounded()),
             BorderStyle::Custom(chars) => {
                 chars.validate().map_err(|msg| {
                     BoxenError::invalid_border_style(
@@ -61,6 +62,19 @@ impl BorderStyle {
                 })?;
                 Ok(*chars)
             }
+            BorderStyle::Registered(name) => {
+                let chars = crate::borders::get_registered(name).ok_or_else(|| {
+                    BoxenError::invalid_border_style(
+                        format!("No border style registered as '{name}'"),
+                        vec![crate::error::ErrorRecommendation::with_auto_fix(
+                            "Unregistered border style".to_string(),
+                            "Register the characters before rendering".to_string(),
+                            format!("boxen::borders::register_style(\"{name}\", chars)"),
+                        )],
+                    )
+                })?;
+                BorderStyle::Custom(chars).get_chars()
+            }
         }
     }
 
@@ -72,7 +86,7 @@ impl BorderStyle {
 
     /// Get the display name of this border style
     #[must_use]
-    pub fn name(&self) -> &'static str {
+    pub fn name(&self) -> &str {
         match self {
             BorderStyle::None => "none",
             BorderStyle::Single => "single",
@@ -82,7 +96,9 @@ impl BorderStyle {
             BorderStyle::SingleDouble => "singleDouble",
             BorderStyle::DoubleSingle => "doubleSingle",
             BorderStyle::Classic => "classic",
+            BorderStyle::ClassicRounded => "classicRounded",
             BorderStyle::Custom(_) => "custom",
+            BorderStyle::Registered(name) => name,
         }
     }
 
@@ -101,9 +117,10 @@ impl BorderStyle {
             "singledouble" | "single_double" => Ok(BorderStyle::SingleDouble),
             "doublesingle" | "double_single" => Ok(BorderStyle::DoubleSingle),
             "classic" => Ok(BorderStyle::Classic),
+            "classicrounded" | "classic_rounded" => Ok(BorderStyle::ClassicRounded),
             _ => Err(BoxenError::invalid_border_style(
                 format!(
-                    "Unknown border style: '{name}'. Valid styles are: none, single, double, round, bold, singleDouble, doubleSingle, classic"
+                    "Unknown border style: '{name}'. Valid styles are: none, single, double, round, bold, singleDouble, doubleSingle, classic, classicRounded"
                 ),
                 vec![
                     crate::error::ErrorRecommendation::suggestion_only(
@@ -133,6 +150,7 @@ impl BorderStyle {
             "singleDouble",
             "doubleSingle",
             "classic",
+            "classicRounded",
         ]
     }
 
@@ -155,6 +173,15 @@ impl BorderStyle {
     }
 }
 
+impl std::str::FromStr for BorderStyle {
+    type Err = BoxenError;
+
+    /// Parse a predefined border style name (see [`BorderStyle::from_name`])
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::from_name(s.trim())
+    }
+}
+
 /// Utility functions for working with border styles
 pub struct BorderStyleUtils;
 
@@ -179,7 +206,9 @@ impl BorderStyleUtils {
             | (BorderStyle::Bold, BorderStyle::Bold)
             | (BorderStyle::SingleDouble, BorderStyle::SingleDouble)
             | (BorderStyle::DoubleSingle, BorderStyle::DoubleSingle)
-            | (BorderStyle::Classic, BorderStyle::Classic) => true,
+            | (BorderStyle::Classic, BorderStyle::Classic)
+            | (BorderStyle::ClassicRounded, BorderStyle::ClassicRounded) => true,
+            (BorderStyle::Registered(a_name), BorderStyle::Registered(b_name)) => a_name == b_name,
             (BorderStyle::Custom(a_chars), BorderStyle::Custom(b_chars)) => {
                 a_chars.top_left == b_chars.top_left
                     && a_chars.top_right == b_chars.top_right
@@ -235,6 +264,34 @@ mod tests {
         assert!(BorderStyle::None.get_chars().is_ok());
     }
 
+    #[test]
+    fn test_border_style_from_str() {
+        assert_eq!(
+            "double".parse::<BorderStyle>().unwrap(),
+            BorderStyle::Double
+        );
+        assert_eq!(
+            " Single_Double ".parse::<BorderStyle>().unwrap(),
+            BorderStyle::SingleDouble
+        );
+        assert!("dotted".parse::<BorderStyle>().is_err());
+        assert_eq!(
+            "classic_rounded".parse::<BorderStyle>().unwrap(),
+            BorderStyle::ClassicRounded
+        );
+    }
+
+    #[test]
+    fn test_classic_rounded_box() {
+        let options = crate::BoxenOptions {
+            border_style: BorderStyle::ClassicRounded,
+            ..Default::default()
+        };
+        let result = crate::boxen("Hello", Some(options)).unwrap();
+        assert_eq!(result, ".-----.\n|Hello|\n'-----'");
+        assert_eq!(crate::unbox(&result).as_deref(), Some("Hello"));
+    }
+
     #[test]
     fn test_border_style_visibility() {
         assert!(!BorderStyle::None.is_visible());
@@ -255,6 +312,7 @@ mod tests {
         assert_eq!(BorderStyle::SingleDouble.name(), "singleDouble");
         assert_eq!(BorderStyle::DoubleSingle.name(), "doubleSingle");
         assert_eq!(BorderStyle::Classic.name(), "classic");
+        assert_eq!(BorderStyle::ClassicRounded.name(), "classicRounded");
         assert_eq!(BorderStyle::Custom(BorderChars::single()).name(), "custom");
     }
 
@@ -322,8 +380,9 @@ mod tests {
         assert!(styles.contains(&"round"));
         assert!(styles.contains(&"bold"));
         assert!(styles.contains(&"classic"));
+        assert!(styles.contains(&"classicRounded"));
         assert!(styles.contains(&"none"));
-        assert_eq!(styles.len(), 8);
+        assert_eq!(styles.len(), 9);
     }
 
     #[test]
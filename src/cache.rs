@@ -0,0 +1,243 @@
+//! # Render Cache
+//!
+//! Memoizes rendered boxes for UIs that redraw identical boxes repeatedly.
+//! Only available when the `cache` feature is enabled.
+//!
+//! Entries are keyed by a hash of the text and options, and the full inputs are
+//! compared on lookup so a hash collision can never return the wrong box.
+//!
+//! Rendered output also depends on the terminal size, which boxen resolves once
+//! per process, and on any `Width::Dynamic`/`Height::Dynamic` closures. Closures
+//! are identified by the `Arc` they live in, so clone one options value rather
+//! than building a new closure per frame if you want cache hits.
+//!
+//! ```rust
+//! use ::boxen::{BoxenOptions, cache::CachedRenderer};
+//!
+//! let renderer = CachedRenderer::new(16);
+//! let options = BoxenOptions::default();
+//!
+//! let first = renderer.render("Status: OK", &options).unwrap();
+//! let second = renderer.render("Status: OK", &options).unwrap();
+//! assert_eq!(first, second);
+//! assert_eq!(renderer.stats().hits, 1);
+//! ```
+
+use crate::error::BoxenResult;
+use crate::options::BoxenOptions;
+use ahash::AHasher;
+use lru::LruCache;
+use std::hash::{Hash, Hasher};
+use std::num::NonZeroUsize;
+use std::sync::{Mutex, PoisonError};
+
+/// Cache statistics for monitoring hit rates
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct RenderCacheStats {
+    /// Number of renders served from the cache
+    pub hits: usize,
+    /// Number of renders that ran the full pipeline
+    pub misses: usize,
+}
+
+/// A cached render together with the inputs that produced it
+struct CacheEntry {
+    text: String,
+    options: BoxenOptions,
+    output: String,
+}
+
+struct RenderCache {
+    entries: LruCache<u64, CacheEntry>,
+    stats: RenderCacheStats,
+}
+
+/// An LRU cache in front of [`crate::boxen`]
+///
+/// The renderer is `Send + Sync`, so a single instance can be shared between
+/// threads behind an `Arc`.
+pub struct CachedRenderer {
+    cache: Mutex<RenderCache>,
+}
+
+impl CachedRenderer {
+    /// Create a renderer that keeps up to `capacity` rendered boxes
+    ///
+    /// A capacity of zero is treated as one.
+    #[must_use]
+    pub fn new(capacity: usize) -> Self {
+        Self {
+            cache: Mutex::new(RenderCache {
+                entries: LruCache::new(NonZeroUsize::new(capacity).unwrap_or(NonZeroUsize::MIN)),
+                stats: RenderCacheStats::default(),
+            }),
+        }
+    }
+
+    /// Render a box, reusing a previous result for identical inputs
+    ///
+    /// # Errors
+    ///
+    /// Returns the same errors as [`crate::boxen`]. Failed renders are not cached.
+    pub fn render(&self, text: &str, options: &BoxenOptions) -> BoxenResult<String> {
+        let key = Self::key(text, options);
+
+        {
+            let mut cache = self.lock();
+            let hit = cache
+                .entries
+                .get(&key)
+                .filter(|entry| entry.text == text && entry.options == *options)
+                .map(|entry| entry.output.clone());
+            if let Some(output) = hit {
+                cache.stats.hits += 1;
+                return Ok(output);
+            }
+            cache.stats.misses += 1;
+        }
+
+        // Render outside the lock so slow renders don't block other threads
+        let output = crate::boxen(text, Some(options.clone()))?;
+        self.lock().entries.put(
+            key,
+            CacheEntry {
+                text: text.to_string(),
+                options: options.clone(),
+                output: output.clone(),
+            },
+        );
+
+        Ok(output)
+    }
+
+    /// Number of boxes currently cached
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.lock().entries.len()
+    }
+
+    /// Whether the cache is empty
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.lock().entries.is_empty()
+    }
+
+    /// Maximum number of boxes kept
+    #[must_use]
+    pub fn capacity(&self) -> usize {
+        self.lock().entries.cap().get()
+    }
+
+    /// Hit and miss counts since creation or the last [`clear`](Self::clear)
+    #[must_use]
+    pub fn stats(&self) -> RenderCacheStats {
+        self.lock().stats.clone()
+    }
+
+    /// Remove all cached boxes and reset statistics
+    pub fn clear(&self) {
+        let mut cache = self.lock();
+        cache.entries.clear();
+        cache.stats = RenderCacheStats::default();
+    }
+
+    fn lock(&self) -> std::sync::MutexGuard<'_, RenderCache> {
+        // The cache holds no invariants a panicking render could break
+        self.cache.lock().unwrap_or_else(PoisonError::into_inner)
+    }
+
+    fn key(text: &str, options: &BoxenOptions) -> u64 {
+        let mut hasher = AHasher::default();
+        text.hash(&mut hasher);
+        options.hash(&mut hasher);
+        hasher.finish()
+    }
+}
+
+impl std::fmt::Debug for CachedRenderer {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("CachedRenderer")
+            .field("len", &self.len())
+            .field("capacity", &self.capacity())
+            .field("stats", &self.stats())
+            .finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::options::BorderStyle;
+
+    #[test]
+    fn test_cache_hit_returns_identical_output() {
+        let renderer = CachedRenderer::new(4);
+        let options = BoxenOptions::default();
+
+        let first = renderer.render("Hello", &options).unwrap();
+        let second = renderer.render("Hello", &options).unwrap();
+
+        assert_eq!(first, second);
+        assert_eq!(first, crate::boxen("Hello", Some(options)).unwrap());
+        assert_eq!(renderer.stats(), RenderCacheStats { hits: 1, misses: 1 });
+    }
+
+    #[test]
+    fn test_different_options_miss() {
+        let renderer = CachedRenderer::new(4);
+        let single = BoxenOptions::default();
+        let double = BoxenOptions {
+            border_style: BorderStyle::Double,
+            ..Default::default()
+        };
+
+        let a = renderer.render("Hello", &single).unwrap();
+        let b = renderer.render("Hello", &double).unwrap();
+
+        assert_ne!(a, b);
+        assert_eq!(renderer.stats().misses, 2);
+        assert_eq!(renderer.len(), 2);
+    }
+
+    #[test]
+    fn test_capacity_eviction() {
+        let renderer = CachedRenderer::new(2);
+        let options = BoxenOptions::default();
+
+        renderer.render("a", &options).unwrap();
+        renderer.render("b", &options).unwrap();
+        renderer.render("c", &options).unwrap();
+        assert_eq!(renderer.len(), 2);
+
+        // "a" was least recently used and has been evicted
+        renderer.render("a", &options).unwrap();
+        assert_eq!(renderer.stats().misses, 4);
+
+        // "c" is still cached
+        renderer.render("c", &options).unwrap();
+        assert_eq!(renderer.stats().hits, 1);
+    }
+
+    #[test]
+    fn test_errors_are_not_cached() {
+        let renderer = CachedRenderer::new(2);
+        let options = BoxenOptions {
+            width: Some(crate::options::Width::Fixed(1)),
+            ..Default::default()
+        };
+
+        assert!(renderer.render("Hello", &options).is_err());
+        assert!(renderer.is_empty());
+    }
+
+    #[test]
+    fn test_clear() {
+        let renderer = CachedRenderer::new(2);
+        renderer.render("a", &BoxenOptions::default()).unwrap();
+        renderer.clear();
+
+        assert!(renderer.is_empty());
+        assert_eq!(renderer.stats(), RenderCacheStats::default());
+        assert_eq!(renderer.capacity(), 2);
+    }
+}
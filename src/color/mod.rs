@@ -197,6 +197,7 @@ use crate::ErrorRecommendation;
 use crate::error::{BoxenError, BoxenResult};
 use crate::options::Color;
 use colored::{ColoredString, Colorize};
+use std::fmt::Write;
 
 /// Parse and validate a color specification
 ///
@@ -212,9 +213,70 @@ pub fn parse_color(color: &Color) -> BoxenResult<colored::Color> {
             g: *g,
             b: *b,
         }),
+        Color::Auto => Err(BoxenError::invalid_color(
+            "Automatic color has not been resolved from content".to_string(),
+            "auto".to_string(),
+            vec![ErrorRecommendation::suggestion_only(
+                "Unresolved automatic color".to_string(),
+                "Render through boxen() or resolve it first with auto_color(text)".to_string(),
+            )],
+        )),
     }
 }
 
+/// Derive a deterministic color from text, used to resolve [`Color::Auto`]
+///
+/// The visible text (ANSI sequences stripped) is hashed to a hue, and the
+/// color is taken from a fixed saturation and lightness band so every result
+/// is readable on both light and dark terminals. The hash is stable across
+/// runs and platforms, so the same message always gets the same color.
+///
+/// # Examples
+///
+/// ```rust
+/// use ::boxen::{Color, color::auto_color};
+///
+/// assert_eq!(auto_color("disk full"), auto_color("disk full"));
+/// assert!(matches!(auto_color("disk full"), Color::Rgb(..)));
+/// ```
+#[must_use]
+pub fn auto_color(text: &str) -> Color {
+    // FNV-1a, chosen for stability rather than std's unspecified hasher
+    let hash = crate::text::strip_ansi_codes(text)
+        .bytes()
+        .fold(0xcbf2_9ce4_8422_2325_u64, |hash, byte| {
+            (hash ^ u64::from(byte)).wrapping_mul(0x0100_0000_01b3)
+        });
+
+    #[allow(clippy::cast_precision_loss)]
+    let hue = (hash % 360) as f64;
+    let (r, g, b) = hsl_to_rgb(hue, 0.65, 0.6);
+    Color::Rgb(r, g, b)
+}
+
+/// Convert an HSL color (hue in degrees, saturation and lightness in 0..=1) to RGB
+#[allow(
+    clippy::cast_possible_truncation,
+    clippy::cast_sign_loss,
+    clippy::many_single_char_names
+)]
+fn hsl_to_rgb(hue: f64, saturation: f64, lightness: f64) -> (u8, u8, u8) {
+    let chroma = (1.0 - (2.0 * lightness - 1.0).abs()) * saturation;
+    let sector = hue / 60.0;
+    let x = chroma * (1.0 - (sector % 2.0 - 1.0).abs());
+    let (r, g, b) = match sector as u32 {
+        0 => (chroma, x, 0.0),
+        1 => (x, chroma, 0.0),
+        2 => (0.0, chroma, x),
+        3 => (0.0, x, chroma),
+        4 => (x, 0.0, chroma),
+        _ => (chroma, 0.0, x),
+    };
+    let m = lightness - chroma / 2.0;
+    let to_byte = |channel: f64| ((channel + m) * 255.0).round() as u8;
+    (to_byte(r), to_byte(g), to_byte(b))
+}
+
 /// Parse a named color into a `colored::Color`
 ///
 /// # Errors
@@ -398,6 +460,10 @@ pub fn parse_hex_color(hex: &str) -> BoxenResult<colored::Color> {
 ///
 /// Returns an error if the color specification is invalid.
 pub fn validate_color(color: &Color) -> BoxenResult<()> {
+    if *color == Color::Auto {
+        // Resolved from the content at render time
+        return Ok(());
+    }
     parse_color(color).map(|_| ())
 }
 
@@ -446,6 +512,206 @@ pub fn apply_colors(
     Ok(styled)
 }
 
+/// Color each character of `text` along a gradient from `start` to `end`
+///
+/// The first visible character gets `start`, the last gets `end`, and those in
+/// between are linearly interpolated in RGB. Named colors use the standard
+/// xterm palette. Zero-width characters share the color of the character
+/// they follow.
+///
+/// # Errors
+///
+/// Returns an error if either color specification is invalid.
+///
+/// # Examples
+///
+/// ```rust
+/// use ::boxen::{Color, color::gradient_text, text::strip_ansi_codes};
+///
+/// let title = gradient_text("Deploy", &Color::from("#ff0000"), &Color::from("#0000ff")).unwrap();
+/// assert_eq!(strip_ansi_codes(&title), "Deploy");
+/// ```
+pub fn gradient_text(text: &str, start: &Color, end: &Color) -> BoxenResult<String> {
+    gradient_text_on(text, start, end, None, None)
+}
+
+/// [`gradient_text`] with every character also on `background`, colored as
+/// `color` decides (see [`paint`])
+pub(crate) fn gradient_text_on(
+    text: &str,
+    start: &Color,
+    end: &Color,
+    background: Option<&Color>,
+    color: Option<bool>,
+) -> BoxenResult<String> {
+    let background = background.map(parse_color).transpose()?;
+    let (start, end) = (
+        color_to_rgb(parse_color(start)?),
+        color_to_rgb(parse_color(end)?),
+    );
+    let visible = text
+        .chars()
+        .filter(|&ch| crate::text::measurement::char_width_with_selector(ch, None) != Some(0))
+        .count();
+
+    #[allow(
+        clippy::cast_possible_truncation,
+        clippy::cast_precision_loss,
+        clippy::cast_sign_loss
+    )]
+    let blend = |from: u8, to: u8, position: usize| -> u8 {
+        if visible < 2 {
+            return from;
+        }
+        let t = position as f64 / (visible - 1) as f64;
+        (f64::from(from) + (f64::from(to) - f64::from(from)) * t).round() as u8
+    };
+
+    let mut result = String::with_capacity(text.len() * 20);
+    let mut position = 0;
+    let mut cluster = String::new();
+    let mut flush = |cluster: &mut String, position: usize| {
+        if !cluster.is_empty() {
+            let r = blend(start.0, end.0, position);
+            let g = blend(start.1, end.1, position);
+            let b = blend(start.2, end.2, position);
+            let styled = cluster.truecolor(r, g, b);
+            let styled = match background {
+                Some(background) => styled.on_color(background),
+                None => styled,
+            };
+            result.push_str(&paint(&styled, color));
+            cluster.clear();
+        }
+    };
+    for ch in text.chars() {
+        let zero_width = crate::text::measurement::char_width_with_selector(ch, None) == Some(0);
+        if !zero_width && !cluster.is_empty() {
+            flush(&mut cluster, position);
+            position += 1;
+        }
+        cluster.push(ch);
+    }
+    flush(&mut cluster, position);
+
+    Ok(result)
+}
+
+/// RGB components of a parsed color, using the xterm palette for named colors
+fn color_to_rgb(color: colored::Color) -> (u8, u8, u8) {
+    use colored::Color::{
+        AnsiColor, Black, Blue, BrightBlack, BrightBlue, BrightCyan, BrightGreen, BrightMagenta,
+        BrightRed, BrightWhite, BrightYellow, Cyan, Green, Magenta, Red, TrueColor, White, Yellow,
+    };
+    const PALETTE: [(u8, u8, u8); 16] = [
+        (0, 0, 0),
+        (205, 0, 0),
+        (0, 205, 0),
+        (205, 205, 0),
+        (0, 0, 238),
+        (205, 0, 205),
+        (0, 205, 205),
+        (229, 229, 229),
+        (127, 127, 127),
+        (255, 0, 0),
+        (0, 255, 0),
+        (255, 255, 0),
+        (92, 92, 255),
+        (255, 0, 255),
+        (0, 255, 255),
+        (255, 255, 255),
+    ];
+
+    let index = match color {
+        TrueColor { r, g, b } => return (r, g, b),
+        AnsiColor(code @ 16..=231) => {
+            let level = |value: u8| if value == 0 { 0 } else { 55 + value * 40 };
+            let code = code - 16;
+            return (level(code / 36), level(code / 6 % 6), level(code % 6));
+        }
+        AnsiColor(code @ 232..=255) => {
+            let gray = 8 + (code - 232) * 10;
+            return (gray, gray, gray);
+        }
+        AnsiColor(code) => code,
+        Black => 0,
+        Red => 1,
+        Green => 2,
+        Yellow => 3,
+        Blue => 4,
+        Magenta => 5,
+        Cyan => 6,
+        White => 7,
+        BrightBlack => 8,
+        BrightRed => 9,
+        BrightGreen => 10,
+        BrightYellow => 11,
+        BrightBlue => 12,
+        BrightMagenta => 13,
+        BrightCyan => 14,
+        BrightWhite => 15,
+    };
+    PALETTE[usize::from(index)]
+}
+
+/// Relative luminance of a color, from 0.0 (black) to 1.0 (white)
+///
+/// Follows the WCAG definition: sRGB channels are linearized and weighted by
+/// how bright each appears. Named colors use the xterm palette.
+///
+/// # Errors
+///
+/// Returns an error if the color cannot be parsed, including an unresolved
+/// `Color::Auto`.
+///
+/// # Examples
+///
+/// ```rust
+/// use ::boxen::{Color, color::relative_luminance};
+///
+/// assert_eq!(relative_luminance(&Color::Rgb(0, 0, 0)).unwrap(), 0.0);
+/// assert!((relative_luminance(&Color::Hex("#FFF".to_string())).unwrap() - 1.0).abs() < 1e-9);
+/// ```
+pub fn relative_luminance(color: &Color) -> BoxenResult<f64> {
+    let linear = |channel: u8| {
+        let value = f64::from(channel) / 255.0;
+        if value <= 0.040_45 {
+            value / 12.92
+        } else {
+            ((value + 0.055) / 1.055).powf(2.4)
+        }
+    };
+    let (r, g, b) = color_to_rgb(parse_color(color)?);
+    Ok(0.0722_f64.mul_add(linear(b), 0.2126_f64.mul_add(linear(r), 0.7152 * linear(g))))
+}
+
+/// Black or white, whichever has the higher contrast ratio against `color`
+///
+/// # Errors
+///
+/// Returns an error if the color cannot be parsed (see [`relative_luminance`]).
+///
+/// # Examples
+///
+/// ```rust
+/// use ::boxen::{Color, color::contrasting_color};
+///
+/// let on_yellow = contrasting_color(&Color::Named("yellow".to_string())).unwrap();
+/// assert_eq!(on_yellow, Color::Named("black".to_string()));
+/// ```
+pub fn contrasting_color(color: &Color) -> BoxenResult<Color> {
+    let luminance = relative_luminance(color)?;
+    // Contrast ratios are (lighter + 0.05) / (darker + 0.05)
+    let against_white = 1.05 / (luminance + 0.05);
+    let against_black = (luminance + 0.05) / 0.05;
+    let name = if against_white > against_black {
+        "white"
+    } else {
+        "black"
+    };
+    Ok(Color::Named(name.to_string()))
+}
+
 /// Apply dim styling to text (for dim borders)
 #[must_use]
 pub fn apply_dim(text: &str) -> ColoredString {
@@ -475,11 +741,376 @@ pub fn apply_color_with_dim(
     Ok(styled)
 }
 
+/// Format `styled`, with escape sequences only when `color` allows
+///
+/// `None` follows `colored`'s own detection. `Some` decides for this string
+/// alone and produces what `colored` would with its switch forced on or off,
+/// so no process-wide state is changed.
+pub(crate) fn paint(styled: &ColoredString, color: Option<bool>) -> String {
+    use colored::Styles;
+
+    let color = color.unwrap_or_else(|| colored::control::SHOULD_COLORIZE.should_colorize());
+    if !color || styled.is_plain() {
+        return styled.input.clone();
+    }
+
+    // Same codes, in the same order, as `colored`
+    let styles = [
+        (Styles::Bold, "1"),
+        (Styles::Dimmed, "2"),
+        (Styles::Underline, "4"),
+        (Styles::Reversed, "7"),
+        (Styles::Italic, "3"),
+        (Styles::Blink, "5"),
+        (Styles::Hidden, "8"),
+        (Styles::Strikethrough, "9"),
+    ];
+    let mut codes: Vec<std::borrow::Cow<'static, str>> = styles
+        .into_iter()
+        .filter(|&(style, _)| styled.style.contains(style))
+        .map(|(_, code)| code.into())
+        .collect();
+    codes.extend(styled.bgcolor.map(|bg| bg.to_bg_str()));
+    codes.extend(styled.fgcolor.map(|fg| fg.to_fg_str()));
+    let style = format!("\x1b[{}m", codes.join(";"));
+
+    // Resets inside the text would end the style early, so restart it after each
+    let body = styled.input.replace("\x1b[0m", &format!("\x1b[0m{style}"));
+    format!("{style}{body}\x1b[0m")
+}
+
+/// Decide whether output written to a target should keep its colors
+///
+/// The first rule that applies wins:
+/// 1. `force_color`, when set (e.g. from [`BoxenOptions::force_color`](crate::BoxenOptions))
+/// 2. `NO_COLOR` set to a non-empty value disables color
+/// 3. `FORCE_COLOR` or `CLICOLOR_FORCE` set to anything but `0` enables color
+///    (`FORCE_COLOR=0` disables it)
+/// 4. Otherwise color is used only when the target `is_terminal`
+///
+/// # Examples
+///
+/// ```rust
+/// use ::boxen::color::should_use_color;
+///
+/// assert!(should_use_color(Some(true), false));
+/// assert!(!should_use_color(Some(false), true));
+/// ```
+#[must_use]
+pub fn should_use_color(force_color: Option<bool>, is_terminal: bool) -> bool {
+    resolve_color_choice(force_color, is_terminal, |name| std::env::var(name).ok())
+}
+
+/// [`should_use_color`] with the environment lookup supplied by the caller
+fn resolve_color_choice(
+    force_color: Option<bool>,
+    is_terminal: bool,
+    env: impl Fn(&str) -> Option<String>,
+) -> bool {
+    if let Some(forced) = force_color {
+        return forced;
+    }
+    if env("NO_COLOR").is_some_and(|value| !value.is_empty()) {
+        return false;
+    }
+    for name in ["FORCE_COLOR", "CLICOLOR_FORCE"] {
+        if let Some(value) = env(name) {
+            return value != "0";
+        }
+    }
+    is_terminal
+}
+
+/// Replace full SGR resets (`\x1b[0m` or `\x1b[m`) with the off codes for
+/// just the attributes turned on since the previous reset
+///
+/// A reset with nothing active to turn off is dropped. Styling that was in
+/// effect before `text`, such as the color of an enclosing log line, is left
+/// untouched.
+///
+/// # Examples
+///
+/// ```rust
+/// use ::boxen::color::targeted_resets;
+///
+/// assert_eq!(targeted_resets("\x1b[1;31mA\x1b[0m"), "\x1b[1;31mA\x1b[22;39m");
+/// assert_eq!(targeted_resets("\x1b[44mB\x1b[0m"), "\x1b[44mB\x1b[49m");
+/// ```
+#[must_use]
+pub fn targeted_resets(text: &str) -> String {
+    // Off code for each attribute, in the order they are emitted
+    const OFF_CODES: [&str; 9] = ["22", "23", "24", "25", "27", "28", "29", "39", "49"];
+
+    let mut active = [false; OFF_CODES.len()];
+    let mut result = String::with_capacity(text.len());
+    let mut rest = text;
+
+    while let Some(start) = rest.find("\x1b[") {
+        result.push_str(&rest[..start]);
+        let sequence = &rest[start..];
+        let Some(end) = sequence[2..]
+            .find(|ch: char| !(ch.is_ascii_digit() || ch == ';'))
+            .map(|offset| offset + 2)
+            .filter(|&end| sequence[end..].starts_with('m'))
+        else {
+            // Not an SGR sequence: copy the introducer and keep scanning
+            result.push_str(&sequence[..2]);
+            rest = &sequence[2..];
+            continue;
+        };
+
+        let params: Vec<&str> = sequence[2..end].split(';').collect();
+        if params.iter().all(|param| param.is_empty() || *param == "0") {
+            let off: Vec<&str> = OFF_CODES
+                .iter()
+                .zip(&active)
+                .filter(|&(_, &on)| on)
+                .map(|(code, _)| *code)
+                .collect();
+            if !off.is_empty() {
+                let _ = write!(result, "\x1b[{}m", off.join(";"));
+            }
+            active = [false; OFF_CODES.len()];
+        } else {
+            let mut index = 0;
+            while index < params.len() {
+                let code: u16 = params[index].parse().unwrap_or(0);
+                let slot = match code {
+                    1 | 2 | 22 => Some(0),
+                    3 | 23 => Some(1),
+                    4 | 24 => Some(2),
+                    5 | 25 => Some(3),
+                    7 | 27 => Some(4),
+                    8 | 28 => Some(5),
+                    9 | 29 => Some(6),
+                    30..=39 | 90..=97 => Some(7),
+                    40..=49 | 100..=107 => Some(8),
+                    _ => None,
+                };
+                if let Some(slot) = slot {
+                    active[slot] = !(22..=29).contains(&code) && code != 39 && code != 49;
+                }
+                // Extended colors carry their arguments as further parameters
+                if code == 38 || code == 48 {
+                    index += match params.get(index + 1) {
+                        Some(&"5") => 2,
+                        Some(&"2") => 4,
+                        _ => 0,
+                    };
+                }
+                index += 1;
+            }
+            result.push_str(&sequence[..=end]);
+        }
+        rest = &sequence[end + 1..];
+    }
+    result.push_str(rest);
+
+    result
+}
+
+/// Color selected byte ranges of `text`, leaving the rest unstyled
+///
+/// Intended for highlighting matches before boxing: the result can be passed
+/// straight to [`crate::boxen`], which ignores the escape sequences when
+/// measuring. Ranges are half-open `(start, end)` byte offsets; overlapping or
+/// touching ranges are merged, offsets past the end are clamped, and offsets
+/// inside a multi-byte character are widened to cover the whole character.
+///
+/// # Errors
+///
+/// Returns an error if the color specification is invalid.
+///
+/// # Examples
+///
+/// ```rust
+/// use ::boxen::{Color, color::highlight_ranges, text::text_width};
+///
+/// let text = "error: file not found";
+/// let highlighted = highlight_ranges(text, &[(0, 5)], &Color::from("red")).unwrap();
+///
+/// assert!(highlighted.ends_with(": file not found"));
+/// assert_eq!(text_width(&highlighted), text_width(text));
+/// ```
+pub fn highlight_ranges(
+    text: &str,
+    ranges: &[(usize, usize)],
+    color: &Color,
+) -> BoxenResult<String> {
+    let parsed_color = parse_color(color)?;
+
+    let mut spans: Vec<(usize, usize)> = ranges
+        .iter()
+        .map(|&(start, end)| {
+            let mut start = start.min(text.len());
+            let mut end = end.min(text.len());
+            while !text.is_char_boundary(start) {
+                start -= 1;
+            }
+            while !text.is_char_boundary(end) {
+                end += 1;
+            }
+            (start, end)
+        })
+        .filter(|(start, end)| start < end)
+        .collect();
+    spans.sort_unstable();
+
+    let mut merged: Vec<(usize, usize)> = Vec::with_capacity(spans.len());
+    for (start, end) in spans {
+        match merged.last_mut() {
+            Some(last) if start <= last.1 => last.1 = last.1.max(end),
+            _ => merged.push((start, end)),
+        }
+    }
+
+    let mut result = String::with_capacity(text.len() + merged.len() * 10);
+    let mut position = 0;
+    for (start, end) in merged {
+        result.push_str(&text[position..start]);
+        result.push_str(&text[start..end].color(parsed_color).to_string());
+        position = end;
+    }
+    result.push_str(&text[position..]);
+
+    Ok(result)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
     use crate::options::Color;
 
+    #[test]
+    fn test_contrasting_color() {
+        let white = Color::Named("white".to_string());
+        let black = Color::Named("black".to_string());
+        for dark in [
+            Color::Rgb(0, 0, 0),
+            Color::Named("blue".to_string()),
+            Color::Hex("#1E3A8A".to_string()),
+        ] {
+            assert_eq!(contrasting_color(&dark).unwrap(), white, "{dark:?}");
+        }
+        for light in [
+            Color::Rgb(255, 255, 255),
+            Color::Named("yellow".to_string()),
+            Color::Hex("#FDE68A".to_string()),
+        ] {
+            assert_eq!(contrasting_color(&light).unwrap(), black, "{light:?}");
+        }
+
+        // Green reads far brighter than blue at the same channel value
+        let green = relative_luminance(&Color::Rgb(0, 200, 0)).unwrap();
+        let blue = relative_luminance(&Color::Rgb(0, 0, 200)).unwrap();
+        assert!(green > blue * 5.0);
+        assert!(contrasting_color(&Color::Auto).is_err());
+    }
+
+    #[test]
+    fn test_paint_matches_colored() {
+        colored::control::set_override(true);
+        let samples = [
+            "plain".normal(),
+            "red".red(),
+            "dim on blue".dimmed().on_blue(),
+            "rgb".truecolor(1, 2, 3).bold().underline().italic(),
+            "inner \x1b[31mred\x1b[0m reset".on_green().dimmed(),
+        ];
+        for styled in &samples {
+            assert_eq!(paint(styled, Some(true)), styled.to_string());
+            assert_eq!(paint(styled, None), styled.to_string());
+            assert_eq!(paint(styled, Some(false)), styled.input);
+        }
+    }
+
+    #[test]
+    fn test_gradient_text_named_endpoints() {
+        colored::control::set_override(true);
+        let red = Color::Named("red".to_string());
+        let blue = Color::Named("blue".to_string());
+        let result = gradient_text("abc", &red, &blue).unwrap();
+        assert!(result.starts_with(&"a".truecolor(205, 0, 0).to_string()));
+        assert!(result.ends_with(&"c".truecolor(0, 0, 238).to_string()));
+
+        // A single character takes the start color; combining marks stay attached
+        assert_eq!(
+            gradient_text("e\u{301}", &red, &blue).unwrap(),
+            "e\u{301}".truecolor(205, 0, 0).to_string()
+        );
+        assert_eq!(color_to_rgb(colored::Color::AnsiColor(196)), (255, 0, 0));
+        assert_eq!(
+            color_to_rgb(colored::Color::AnsiColor(244)),
+            (128, 128, 128)
+        );
+    }
+
+    #[test]
+    fn test_targeted_resets() {
+        assert_eq!(targeted_resets("plain"), "plain");
+        assert_eq!(
+            targeted_resets("\x1b[41;37mx\x1b[0m y"),
+            "\x1b[41;37mx\x1b[39;49m y"
+        );
+        assert_eq!(
+            targeted_resets("\x1b[38;2;1;2;3mx\x1b[0m"),
+            "\x1b[38;2;1;2;3mx\x1b[39m"
+        );
+        assert_eq!(targeted_resets("\x1b[2;4mx\x1b[m"), "\x1b[2;4mx\x1b[22;24m");
+        // Nothing left active: the reset is dropped rather than clearing ambient styles
+        assert_eq!(
+            targeted_resets("\x1b[31mx\x1b[39m\x1b[0m"),
+            "\x1b[31mx\x1b[39m"
+        );
+        // Other escape sequences pass through
+        assert_eq!(targeted_resets("\x1b[2Kx\x1b["), "\x1b[2Kx\x1b[");
+    }
+
+    #[test]
+    fn test_resolve_color_choice_precedence() {
+        let env = |vars: &'static [(&'static str, &'static str)]| {
+            move |name: &str| {
+                vars.iter()
+                    .find(|(key, _)| *key == name)
+                    .map(|(_, value)| (*value).to_string())
+            }
+        };
+
+        // Target detection when nothing else is set
+        assert!(resolve_color_choice(None, true, env(&[])));
+        assert!(!resolve_color_choice(None, false, env(&[])));
+
+        // NO_COLOR beats the terminal and FORCE_COLOR, but not the option
+        let no_color = env(&[("NO_COLOR", "1"), ("FORCE_COLOR", "1")]);
+        assert!(!resolve_color_choice(None, true, no_color));
+        assert!(resolve_color_choice(Some(true), true, no_color));
+        assert!(resolve_color_choice(None, true, env(&[("NO_COLOR", "")])));
+
+        // FORCE_COLOR and CLICOLOR_FORCE enable color for non-terminals
+        assert!(resolve_color_choice(
+            None,
+            false,
+            env(&[("FORCE_COLOR", "1")])
+        ));
+        assert!(resolve_color_choice(
+            None,
+            false,
+            env(&[("CLICOLOR_FORCE", "1")])
+        ));
+        assert!(!resolve_color_choice(
+            None,
+            true,
+            env(&[("FORCE_COLOR", "0")])
+        ));
+
+        // The option overrides everything
+        assert!(!resolve_color_choice(
+            Some(false),
+            true,
+            env(&[("FORCE_COLOR", "1")])
+        ));
+    }
+
     #[test]
     fn test_parse_named_colors_basic() {
         assert!(parse_named_color("red").is_ok());
@@ -839,4 +1470,91 @@ mod tests {
             );
         }
     }
+
+    #[test]
+    fn test_highlight_ranges_merges_overlaps() {
+        let red = Color::Named("red".to_string());
+        let text = "find the needle in the haystack";
+
+        let highlighted = highlight_ranges(text, &[(9, 13), (11, 15), (0, 4)], &red).unwrap();
+        let expected = format!("{} the {} in the haystack", "find".red(), "needle".red());
+        assert_eq!(highlighted, expected);
+        assert_eq!(crate::text::strip_ansi_codes(&highlighted), text);
+    }
+
+    #[test]
+    fn test_highlight_ranges_clamps_offsets() {
+        let red = Color::Named("red".to_string());
+
+        assert_eq!(highlight_ranges("plain", &[], &red).unwrap(), "plain");
+        assert_eq!(
+            highlight_ranges("plain", &[(3, 3), (9, 12)], &red).unwrap(),
+            "plain"
+        );
+        assert_eq!(
+            highlight_ranges("日本語", &[(1, 4)], &red).unwrap(),
+            format!("{}語", "日本".red())
+        );
+        assert!(highlight_ranges("text", &[(0, 1)], &Color::Named("nope".to_string())).is_err());
+    }
+
+    #[test]
+    fn test_highlighted_text_boxes_to_visible_width() {
+        let text = "match here and match there";
+        let highlighted = highlight_ranges(
+            text,
+            &[(0, 5), (15, 20)],
+            &Color::Hex("#ffcc00".to_string()),
+        )
+        .unwrap();
+
+        let boxed = crate::boxen(&highlighted, None).unwrap();
+        let plain = crate::boxen(text, None).unwrap();
+
+        assert_ne!(boxed, plain);
+        assert_eq!(crate::text::strip_ansi_codes(&boxed), plain);
+        for line in boxed.lines() {
+            assert_eq!(crate::text::text_width(line), text.len() + 2);
+        }
+    }
+
+    #[test]
+    fn test_auto_color_is_deterministic() {
+        assert_eq!(auto_color("same message"), auto_color("same message"));
+        assert_eq!(
+            auto_color("\x1b[31msame message\x1b[0m"),
+            auto_color("same message")
+        );
+
+        let colors: std::collections::HashSet<_> = ["alpha", "beta", "gamma", "delta", "epsilon"]
+            .iter()
+            .map(|text| auto_color(text))
+            .collect();
+        assert!(colors.len() >= 4);
+    }
+
+    #[test]
+    fn test_auto_color_stays_in_readable_range() {
+        for text in ["", "a", "hello world", "日本語", "error: timeout"] {
+            let Color::Rgb(r, g, b) = auto_color(text) else {
+                panic!("auto_color must resolve to RGB");
+            };
+            let (max, min) = (r.max(g).max(b), r.min(g).min(b));
+            assert!(max >= 200 && min <= 100, "{text:?} -> {r},{g},{b}");
+        }
+    }
+
+    #[test]
+    fn test_hsl_to_rgb() {
+        assert_eq!(hsl_to_rgb(0.0, 1.0, 0.5), (255, 0, 0));
+        assert_eq!(hsl_to_rgb(120.0, 1.0, 0.5), (0, 255, 0));
+        assert_eq!(hsl_to_rgb(240.0, 1.0, 0.5), (0, 0, 255));
+        assert_eq!(hsl_to_rgb(0.0, 0.0, 1.0), (255, 255, 255));
+    }
+
+    #[test]
+    fn test_auto_color_validation() {
+        assert!(validate_color(&Color::Auto).is_ok());
+        assert!(parse_color(&Color::Auto).is_err());
+    }
 }
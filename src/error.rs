@@ -74,6 +74,11 @@
 //! );
 //! ```
 //!
+//! Recommendations produced by configuration validation may also carry
+//! `fixed_options`, a complete `BoxenOptions` with the fix already applied, for
+//! programmatic recovery. Fixes accumulate across the errors of one check, so
+//! the last error's `fixed_options` resolves them all.
+//!
 //! ## Validation System
 //!
 //! The module includes comprehensive input validation utilities:
@@ -164,6 +169,7 @@
 //! All error types are thread-safe and can be safely passed between threads
 //! or used in concurrent validation operations.
 
+use crate::options::BoxenOptions;
 use thiserror::Error;
 
 /// Recommendation for fixing a configuration error
@@ -175,6 +181,10 @@ pub struct ErrorRecommendation {
     pub suggestion: String,
     /// Optional code snippet that can automatically fix the issue
     pub auto_fix: Option<String>,
+    /// Optional copy of the checked options with this fix applied, along with
+    /// the fixes of any errors reported before it by the same check, so the
+    /// last one is ready to render in place of the originals
+    pub fixed_options: Option<BoxenOptions>,
 }
 
 /// Errors that can occur when creating or rendering boxes
@@ -423,6 +433,7 @@ impl ErrorRecommendation {
             issue,
             suggestion,
             auto_fix,
+            fixed_options: None,
         }
     }
 
@@ -433,6 +444,7 @@ impl ErrorRecommendation {
             issue,
             suggestion,
             auto_fix: Some(auto_fix),
+            fixed_options: None,
         }
     }
 
@@ -443,8 +455,17 @@ impl ErrorRecommendation {
             issue,
             suggestion,
             auto_fix: None,
+            fixed_options: None,
         }
     }
+
+    /// Attach options that resolve the issue when rendered in place of the
+    /// checked ones
+    #[must_use]
+    pub fn with_fixed_options(mut self, options: BoxenOptions) -> Self {
+        self.fixed_options = Some(options);
+        self
+    }
 }
 
 /// Result type alias for boxen operations
@@ -679,7 +700,8 @@ pub mod validation {
     ///
     /// Returns `BoxenError::InputValidationError` if:
     /// - Title exceeds 200 characters (may be truncated or cause layout issues)
-    /// - Title contains invalid control characters (except tabs)
+    /// - Title contains invalid control characters (except tabs and the
+    ///   escape that starts ANSI styling)
     pub fn validate_title(title: &str) -> BoxenResult<()> {
         if title.len() > 200 {
             return Err(BoxenError::input_validation_error(
@@ -700,15 +722,232 @@ pub mod validation {
             ));
         }
 
-        // Check for control characters in title
-        if title.chars().any(|c| c.is_control() && c != '\t') {
+        // Pre-styled titles may carry colors and hyperlinks; any other control
+        // sequence, or an escape that starts none, could rewrite the terminal
+        let has_control = crate::text::ansi::tokenize(title)
+            .into_iter()
+            .any(|token| match token {
+                crate::text::ansi::AnsiToken::Text(text) => {
+                    text.chars().any(|c| c.is_control() && c != '\t')
+                }
+                crate::text::ansi::AnsiToken::Csi(_) => true,
+                _ => false,
+            });
+        if has_control {
             return Err(BoxenError::input_validation_error(
                 "Title contains invalid control characters".to_string(),
                 "title".to_string(),
                 title.to_string(),
                 vec![ErrorRecommendation::suggestion_only(
                     "Control characters".to_string(),
-                    "Titles should not contain control characters (except tabs)".to_string(),
+                    "Titles should not contain control characters (except tabs, colors, and hyperlinks)"
+                        .to_string(),
+                )],
+            ));
+        }
+
+        Ok(())
+    }
+
+    /// Validate title connector glyphs
+    ///
+    /// # Errors
+    ///
+    /// Returns `BoxenError::InputValidationError` if either connector is
+    /// whitespace or a control character.
+    pub fn validate_title_connectors(left: char, right: char) -> BoxenResult<()> {
+        for (name, ch) in [("left", left), ("right", right)] {
+            if ch.is_whitespace() || ch.is_control() {
+                return Err(BoxenError::input_validation_error(
+                    format!("Title connector '{name}' must be a visible character"),
+                    "title_connectors".to_string(),
+                    format!("{ch:?}"),
+                    vec![ErrorRecommendation::with_auto_fix(
+                        "Invisible connector".to_string(),
+                        "Use box-drawing glyphs that join into the top border".to_string(),
+                        ".title_connectors('┤', '├')".to_string(),
+                    )],
+                ));
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Validate the glyph drawn on the bottom border of truncated content
+    ///
+    /// # Errors
+    ///
+    /// Returns `BoxenError::InputValidationError` if `indicator` is not a
+    /// visible, single-column character.
+    pub fn validate_truncation_indicator(indicator: char) -> BoxenResult<()> {
+        let visible = !indicator.is_whitespace() && !indicator.is_control();
+        if visible && crate::text::text_width(indicator.encode_utf8(&mut [0; 4])) == 1 {
+            return Ok(());
+        }
+
+        Err(BoxenError::input_validation_error(
+            "Truncation indicator must be a visible, single-column character".to_string(),
+            "truncation_indicator".to_string(),
+            format!("{indicator:?}"),
+            vec![ErrorRecommendation::with_auto_fix(
+                "Unsuitable indicator".to_string(),
+                "Use a narrow glyph that replaces one border character".to_string(),
+                ".truncation_indicator('▼')".to_string(),
+            )],
+        ))
+    }
+
+    /// Check the input text against [`BoxenOptions::max_input_bytes`](crate::BoxenOptions)
+    ///
+    /// # Errors
+    ///
+    /// Returns `BoxenError::ConfigurationError` if `text` is longer than the limit.
+    pub fn validate_input_size(
+        text: &str,
+        options: &crate::options::BoxenOptions,
+    ) -> BoxenResult<()> {
+        match options.max_input_bytes {
+            Some(limit) if text.len() > limit => Err(BoxenError::configuration_error(
+                format!(
+                    "Input of {} bytes exceeds the {limit} byte limit",
+                    text.len()
+                ),
+                vec![ErrorRecommendation::suggestion_only(
+                    "Input too large".to_string(),
+                    "Truncate the text before rendering or raise max_input_bytes".to_string(),
+                )],
+            )),
+            _ => Ok(()),
+        }
+    }
+
+    /// Check the input text against [`BoxenOptions::error_on_empty`](crate::BoxenOptions)
+    ///
+    /// # Errors
+    ///
+    /// Returns `BoxenError::ConfigurationError` if the option is set and
+    /// `text` is empty or contains only whitespace.
+    pub fn validate_non_empty(
+        text: &str,
+        options: &crate::options::BoxenOptions,
+    ) -> BoxenResult<()> {
+        if options.error_on_empty && text.trim().is_empty() {
+            return Err(BoxenError::configuration_error(
+                "Input is empty or contains only whitespace".to_string(),
+                vec![ErrorRecommendation::suggestion_only(
+                    "Empty input".to_string(),
+                    "Skip rendering when there is nothing to show, or disable error_on_empty"
+                        .to_string(),
+                )],
+            ));
+        }
+        Ok(())
+    }
+
+    /// Check a laid-out box against [`BoxenOptions::max_total_cells`](crate::BoxenOptions)
+    ///
+    /// # Errors
+    ///
+    /// Returns `BoxenError::ConfigurationError` if `width * height` is larger
+    /// than the limit.
+    pub fn validate_total_cells(
+        width: usize,
+        height: usize,
+        options: &crate::options::BoxenOptions,
+    ) -> BoxenResult<()> {
+        let cells = width.saturating_mul(height);
+        match options.max_total_cells {
+            Some(limit) if cells > limit => Err(BoxenError::configuration_error(
+                format!("Box of {width}x{height} ({cells} cells) exceeds the {limit} cell limit"),
+                vec![ErrorRecommendation::suggestion_only(
+                    "Output too large".to_string(),
+                    "Limit the width, height, or text length, or raise max_total_cells".to_string(),
+                )],
+            )),
+            _ => Ok(()),
+        }
+    }
+
+    /// Validate that the title can be drawn at its configured position
+    ///
+    /// # Errors
+    ///
+    /// Returns `BoxenError::ConfigurationError` if a `TitlePosition::Left`
+    /// title has no visible border to sit on, the border is thicker than one
+    /// character, or the title contains characters that are not exactly one
+    /// column wide.
+    pub fn validate_title_position(options: &crate::options::BoxenOptions) -> BoxenResult<()> {
+        if options.title.is_none() || options.title_position != crate::options::TitlePosition::Left
+        {
+            return Ok(());
+        }
+
+        if !options.border_style.is_visible() {
+            return Err(BoxenError::configuration_error(
+                "A left-side title needs a visible border".to_string(),
+                vec![ErrorRecommendation::with_auto_fix(
+                    "No border for the title".to_string(),
+                    "Choose a border style, or draw the title in or above the top border"
+                        .to_string(),
+                    ".title_position(TitlePosition::Border)".to_string(),
+                )],
+            ));
+        }
+
+        if options.border_thickness > 1 {
+            return Err(BoxenError::configuration_error(
+                "A left-side title cannot be drawn on a thick border".to_string(),
+                vec![ErrorRecommendation::with_auto_fix(
+                    "Thick border".to_string(),
+                    "Vertical titles replace a single border column".to_string(),
+                    ".border_thickness(1)".to_string(),
+                )],
+            ));
+        }
+
+        let title = options.title.as_deref().unwrap_or_default();
+        if let Some(ch) = title
+            .chars()
+            .find(|&ch| crate::text::text_width(ch.encode_utf8(&mut [0; 4])) != 1)
+        {
+            return Err(BoxenError::configuration_error(
+                format!("A left-side title cannot contain {ch:?}, which is not one column wide"),
+                vec![
+                    ErrorRecommendation::suggestion_only(
+                        "Wide or zero-width character".to_string(),
+                        "Vertical titles draw one single-width character per row".to_string(),
+                    ),
+                    ErrorRecommendation::with_auto_fix(
+                        "Use the top border".to_string(),
+                        "Titles in the top border support any characters".to_string(),
+                        ".title_position(TitlePosition::Border)".to_string(),
+                    ),
+                ],
+            ));
+        }
+
+        Ok(())
+    }
+
+    /// Validate single-line decoration text such as a line prefix, suffix, or caption
+    ///
+    /// # Errors
+    ///
+    /// Returns `BoxenError::InputValidationError` if the affix contains a line
+    /// break or other control character (other than the ANSI escape), which
+    /// would break the box edges.
+    pub fn validate_line_affix(affix: &str, field_name: &str) -> BoxenResult<()> {
+        // Escape is allowed so affixes can carry their own ANSI styling
+        if affix.chars().any(|c| c.is_control() && c != '\x1b') {
+            return Err(BoxenError::input_validation_error(
+                format!("{field_name} contains control characters"),
+                field_name.to_string(),
+                format!("{affix:?}"),
+                vec![ErrorRecommendation::suggestion_only(
+                    "Control characters".to_string(),
+                    "Line prefixes, suffixes, and captions must be single-line printable text"
+                        .to_string(),
                 )],
             ));
         }
@@ -726,6 +965,8 @@ pub mod validation {
     /// - Margin validation fails (see `validate_spacing`)
     /// - Dimension validation fails (see `validate_dimensions`)
     /// - Title validation fails (see `validate_title`)
+    /// - Title connectors are invalid (see `validate_title_connectors`)
+    /// - A line prefix, suffix, or caption is invalid (see `validate_line_affix`)
     /// - Border color is invalid (not a valid color name or hex code)
     /// - Background color is invalid (not a valid color name or hex code)
     pub fn validate_all_options(
@@ -755,6 +996,29 @@ pub mod validation {
             validate_title(title)?;
         }
 
+        if let Some((left, right)) = options.title_connectors {
+            validate_title_connectors(left, right)?;
+        }
+
+        if let Some(indicator) = options.truncation_indicator {
+            validate_truncation_indicator(indicator)?;
+        }
+
+        if let Some(ref prefix) = options.line_prefix {
+            validate_line_affix(prefix, "line_prefix")?;
+        }
+        if let Some(ref suffix) = options.line_suffix {
+            validate_line_affix(suffix, "line_suffix")?;
+        }
+        if let Some(ref caption) = options.caption {
+            // Tabs are fine in a caption that will be expanded
+            let caption = match options.tab_width {
+                Some(tab_width) => crate::text::expand_tabs(caption, tab_width),
+                None => std::borrow::Cow::Borrowed(caption.as_str()),
+            };
+            validate_line_affix(&caption, "caption")?;
+        }
+
         // Validate colors if present
         if let Some(ref color) = options.border_color {
             crate::color::validate_color(color).map_err(|_e| {
@@ -800,6 +1064,28 @@ pub mod validation {
             })?;
         }
 
+        if let Some(ref color) = options.text_color {
+            crate::color::validate_color(color).map_err(|_e| {
+                BoxenError::input_validation_error(
+                    "Invalid text color".to_string(),
+                    "text_color".to_string(),
+                    format!("{color:?}"),
+                    vec![
+                        ErrorRecommendation::suggestion_only(
+                            "Invalid color".to_string(),
+                            "Use a valid color name (red, blue, etc.) or hex code (#FF0000)"
+                                .to_string(),
+                        ),
+                        ErrorRecommendation::with_auto_fix(
+                            "Use valid color".to_string(),
+                            "Try using a standard color name".to_string(),
+                            ".text_color(\"black\")".to_string(),
+                        ),
+                    ],
+                )
+            })?;
+        }
+
         Ok(())
     }
 }
@@ -2,7 +2,9 @@
 #[cfg(test)]
 mod tests {
     use crate::error::{BoxenError, ErrorRecommendation, validation};
-    use crate::options::{BorderStyle, BoxenBuilder, BoxenOptions, Color, Height, Spacing, Width};
+    use crate::options::{
+        BorderStyle, BoxenBuilder, BoxenOptions, Color, Height, Spacing, TitlePosition, Width,
+    };
     use crate::validation::{recovery, validate_configuration};
 
     #[test]
@@ -195,6 +197,39 @@ mod tests {
         assert!(result.is_ok());
     }
 
+    #[test]
+    fn test_validate_title_allows_ansi_styling() {
+        assert!(validation::validate_title("\x1b[32mGreen\x1b[0m").is_ok());
+        assert!(validation::validate_title("\x1b[32mGreen\x07").is_err());
+        assert!(
+            validation::validate_title("\x1b]8;;https://example.com\x1b\\Link\x1b]8;;\x1b\\")
+                .is_ok()
+        );
+    }
+
+    #[test]
+    fn test_validate_title_rejects_terminal_control_sequences() {
+        for title in ["\x1b[2J", "\x1bc", "\x1b[2J\x1bcX", "Up\x1b[1A", "\x1b[31"] {
+            assert!(
+                matches!(
+                    validation::validate_title(title),
+                    Err(BoxenError::InputValidationError { .. })
+                ),
+                "{title:?}"
+            );
+        }
+        assert!(
+            crate::boxen(
+                "x",
+                Some(crate::BoxenOptions {
+                    title: Some("\x1b[2J\x1bcX".to_string()),
+                    ..Default::default()
+                })
+            )
+            .is_err()
+        );
+    }
+
     #[test]
     fn test_validate_title_too_long() {
         let long_title = "a".repeat(250);
@@ -221,6 +256,122 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_validate_title_connectors() {
+        assert!(validation::validate_title_connectors('┤', '├').is_ok());
+
+        let result = validation::validate_title_connectors('┤', ' ');
+        if let Err(BoxenError::InputValidationError { field, .. }) = result {
+            assert_eq!(field, "title_connectors");
+        } else {
+            panic!("Expected InputValidationError");
+        }
+    }
+
+    #[test]
+    fn test_validate_line_affix() {
+        assert!(validation::validate_line_affix("> ", "line_prefix").is_ok());
+        assert!(validation::validate_line_affix("\x1b[2m│\x1b[0m ", "line_prefix").is_ok());
+
+        let result = validation::validate_line_affix("a\nb", "line_suffix");
+        if let Err(BoxenError::InputValidationError { field, .. }) = result {
+            assert_eq!(field, "line_suffix");
+        } else {
+            panic!("Expected InputValidationError");
+        }
+    }
+
+    #[test]
+    fn test_validate_title_position_left() {
+        let options = BoxenBuilder::new()
+            .title("Log")
+            .title_position(TitlePosition::Left)
+            .build();
+        assert!(validation::validate_title_position(&options).is_ok());
+
+        let unsupported = [
+            BoxenBuilder::new()
+                .title("Log")
+                .border_style(BorderStyle::None),
+            BoxenBuilder::new().title("Log").border_thickness(2),
+            BoxenBuilder::new().title("日志"),
+        ];
+        for builder in unsupported {
+            let options = builder.title_position(TitlePosition::Left).build();
+            let result = validation::validate_title_position(&options);
+            if let Err(BoxenError::ConfigurationError {
+                recommendations, ..
+            }) = result
+            {
+                assert!(!recommendations.is_empty());
+            } else {
+                panic!("Expected ConfigurationError, got {result:?}");
+            }
+
+            // Rendering reports the same error rather than ignoring the title
+            let rendered = crate::boxen("Hi", Some(options));
+            assert!(matches!(
+                rendered,
+                Err(BoxenError::ConfigurationError { .. })
+            ));
+        }
+    }
+
+    #[test]
+    fn test_validate_truncation_indicator() {
+        assert!(validation::validate_truncation_indicator('▼').is_ok());
+        for indicator in [' ', '\t', '表'] {
+            let options = BoxenOptions {
+                height: Some(Height::Fixed(3)),
+                truncation_indicator: Some(indicator),
+                ..Default::default()
+            };
+            let result = crate::boxen("a\nb", Some(options));
+            assert!(
+                matches!(result, Err(BoxenError::RenderingError { .. })),
+                "{indicator:?}"
+            );
+            assert!(validation::validate_truncation_indicator(indicator).is_err());
+        }
+    }
+
+    #[test]
+    fn test_validate_non_empty() {
+        let options = BoxenOptions::default();
+        assert!(validation::validate_non_empty("", &options).is_ok());
+
+        let options = BoxenOptions {
+            error_on_empty: true,
+            ..Default::default()
+        };
+        assert!(validation::validate_non_empty("x", &options).is_ok());
+        let result = crate::boxen(" \n ", Some(options));
+        assert!(matches!(result, Err(BoxenError::ConfigurationError { .. })));
+    }
+
+    #[test]
+    fn test_size_guards_in_boxen() {
+        let options = BoxenOptions {
+            max_input_bytes: Some(8),
+            ..Default::default()
+        };
+        assert!(validation::validate_input_size("12345678", &options).is_ok());
+        let result = crate::boxen("123456789", Some(options));
+        assert!(matches!(result, Err(BoxenError::ConfigurationError { .. })));
+
+        let options = BoxenOptions {
+            max_total_cells: Some(100),
+            ..Default::default()
+        };
+        assert!(crate::boxen("short", Some(options.clone())).is_ok());
+        let result = crate::boxen("line\n".repeat(20), Some(options));
+        if let Err(BoxenError::ConfigurationError { message, .. }) = result {
+            assert!(message.contains("cell limit"));
+        } else {
+            panic!("Expected ConfigurationError");
+        }
+    }
+
     #[test]
     fn test_validate_title_with_tab() {
         let title_with_tab = "Title\twith\ttab";
@@ -0,0 +1,99 @@
+//! # Layout Measurement
+//!
+//! Helpers for reasoning about how rendered output lands on a terminal screen.
+//!
+//! A rendered box wider than the terminal is soft-wrapped by the terminal
+//! itself, so it occupies more physical rows than it has lines. Use
+//! [`visual_rows`] to reserve the right amount of space, for example before
+//! redrawing a box in place.
+//!
+//! ```rust
+//! use ::boxen::layout::visual_rows;
+//!
+//! let boxed = ::boxen::boxen("Hello", None).unwrap();
+//! assert_eq!(visual_rows(&boxed, 80), 3);
+//! ```
+
+use crate::text::measurement::{EMOJI_PRESENTATION_SELECTOR, char_width_with_selector};
+use crate::text::strip_ansi_codes;
+
+/// Count the physical terminal rows `box_str` occupies at `terminal_width`
+///
+/// Each line starts a new row and wraps whenever the next character would not
+/// fit, so a wide character that straddles the last column moves to the next
+/// row as terminals do. ANSI escape sequences take no space. A
+/// `terminal_width` of zero disables soft-wrapping and counts lines.
+///
+/// # Examples
+///
+/// ```rust
+/// use ::boxen::layout::visual_rows;
+///
+/// assert_eq!(visual_rows(&"─".repeat(120), 80), 2);
+/// assert_eq!(visual_rows("one\ntwo", 80), 2);
+/// ```
+#[must_use]
+pub fn visual_rows(box_str: &str, terminal_width: usize) -> usize {
+    let plain = strip_ansi_codes(box_str);
+    if terminal_width == 0 {
+        return plain.split('\n').count();
+    }
+
+    plain
+        .split('\n')
+        .map(|line| line_rows(line, terminal_width))
+        .sum()
+}
+
+/// Rows taken by a single line of plain text
+fn line_rows(line: &str, terminal_width: usize) -> usize {
+    let mut rows = 1;
+    let mut column = 0;
+    let mut chars = line.chars().peekable();
+    while let Some(ch) = chars.next() {
+        let width = char_width_with_selector(ch, chars.peek().copied()).unwrap_or(0);
+        chars.next_if_eq(&EMOJI_PRESENTATION_SELECTOR);
+        if column + width > terminal_width && column > 0 {
+            rows += 1;
+            column = 0;
+        }
+        column += width;
+    }
+    rows
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_wide_line_wraps_to_two_rows() {
+        let line = "x".repeat(120);
+        assert_eq!(visual_rows(&line, 80), 2);
+        assert_eq!(visual_rows(&"x".repeat(160), 80), 2);
+        assert_eq!(visual_rows(&"x".repeat(161), 80), 3);
+    }
+
+    #[test]
+    fn test_rendered_box_rows() {
+        let options = crate::BoxenOptions {
+            width: Some(crate::Width::Fixed(120)),
+            ignore_terminal_bounds: true,
+            ..Default::default()
+        };
+        let boxed = crate::boxen("Hello", Some(options)).unwrap();
+
+        assert_eq!(boxed.lines().count(), 3);
+        assert_eq!(visual_rows(&boxed, 80), 6);
+        assert_eq!(visual_rows(&boxed, 120), 3);
+    }
+
+    #[test]
+    fn test_ansi_empty_lines_and_wide_chars() {
+        assert_eq!(visual_rows("\x1b[31mred\x1b[0m\n\nend", 3), 3);
+        assert_eq!(visual_rows("", 80), 1);
+        // The third wide character cannot start in column 5 of 5
+        assert_eq!(visual_rows("日本語", 5), 2);
+        assert_eq!(visual_rows("abc\ndef", 0), 2);
+    }
+}
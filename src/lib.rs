@@ -128,9 +128,14 @@
 //! - **ANSI Aware**: Properly handles ANSI escape sequences
 
 pub mod borders;
+#[cfg(feature = "cache")]
+pub mod cache;
 pub mod color;
 pub mod error;
+pub mod layout;
 pub mod memory;
+#[cfg(feature = "metrics")]
+pub mod metrics;
 pub mod options;
 pub mod render;
 pub mod terminal;
@@ -143,15 +148,25 @@ mod error_tests;
 // Re-export main types and functions for public API
 pub use error::{BoxenError, BoxenResult, ErrorRecommendation};
 pub use options::{
-    BorderChars, BorderStyle, BoxenBuilder, BoxenOptions, Color, DimensionConstraints, Float,
-    FullscreenMode, Height, LayoutDimensions, Spacing, TextAlignment, TitleAlignment, Width,
+    BorderChars, BorderStyle, BoxenBuilder, BoxenOptions, Color, DimensionConstraints, Edge,
+    FieldDiff, Float, FullscreenMode, Height, LayoutDimensions, LineEnding, NoWrapOverflow,
+    NormalizationForm, Pointer, ResetMode, ScrollbarState, Spacing, TextAlignment, TitleAlignment,
+    TitlePosition, Uniform, VerticalWhitespace, Width,
+};
+pub use render::{
+    Edges, RowKind, SPINNER_FRAMES, SPINNER_FRAMES_ASCII, boxen, boxen_annotated, boxen_bytes,
+    boxen_line_iter, boxen_with_width_fn, eprint_boxen, group, join_vertical, paginate_horizontal,
+    print_boxen, render_edges, render_placeholder, spinner_box, unbox, write_boxen,
 };
-pub use render::boxen;
 pub use validation::{
-    MinimumDimensions, ValidationResult, auto_adjust_options, calculate_minimum_dimensions,
-    suggest_optimal_dimensions, validate_configuration,
+    MinimumDimensions, ValidationResult, assert_rectangular, auto_adjust_options,
+    calculate_minimum_dimensions, height_at_width, suggest_optimal_dimensions,
+    validate_configuration, width_for_aspect_ratio, width_for_line_count,
 };
 
+#[cfg(feature = "metrics")]
+pub use metrics::{RenderMetrics, boxen_timed};
+
 // Re-export terminal utilities
 pub use terminal::{get_terminal_height, get_terminal_size, get_terminal_width};
 
@@ -0,0 +1,168 @@
+//! # Render Metrics
+//!
+//! Per-phase timings and size counters for investigating render performance.
+//! Only available when the `metrics` feature is enabled, so ordinary renders
+//! never read the clock.
+//!
+//! ```rust
+//! use ::boxen::boxen_timed;
+//!
+//! let (rendered, metrics) = boxen_timed("Status: OK", None).unwrap();
+//! assert!(rendered.contains("Status: OK"));
+//! assert!(metrics.phases_total() <= metrics.total);
+//! ```
+
+use crate::error::BoxenResult;
+use crate::options::BoxenOptions;
+use crate::render::{RenderObserver, boxen_observed};
+use crate::text::measurement::Measure;
+use std::time::{Duration, Instant};
+
+/// Timings and counters collected from a single render
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct RenderMetrics {
+    /// Time spent validating options and resolving automatic colors
+    pub validation: Duration,
+    /// Time spent normalizing, wrapping, and aligning content
+    pub content_processing: Duration,
+    /// Time spent calculating layout dimensions
+    pub layout: Duration,
+    /// Time spent drawing borders, padding, and margins
+    pub rendering: Duration,
+    /// Wall time of the whole render
+    pub total: Duration,
+    /// Lines added by wrapping, beyond the lines present in the input
+    pub lines_wrapped: usize,
+    /// Number of lines in the rendered output
+    pub output_lines: usize,
+    /// Rough count of bytes allocated for processed content and output
+    pub bytes_allocated_estimate: usize,
+}
+
+impl RenderMetrics {
+    /// Sum of the individual phase durations
+    #[must_use]
+    pub fn phases_total(&self) -> Duration {
+        self.validation + self.content_processing + self.layout + self.rendering
+    }
+}
+
+/// Observer that stamps the end of each phase
+struct PhaseTimer {
+    metrics: RenderMetrics,
+    start: Instant,
+    last: Instant,
+}
+
+impl PhaseTimer {
+    fn new() -> Self {
+        let now = Instant::now();
+        Self {
+            metrics: RenderMetrics::default(),
+            start: now,
+            last: now,
+        }
+    }
+
+    /// Time since the previous phase ended
+    fn lap(&mut self) -> Duration {
+        let now = Instant::now();
+        let elapsed = now - self.last;
+        self.last = now;
+        elapsed
+    }
+}
+
+impl RenderObserver for PhaseTimer {
+    fn validated(&mut self) {
+        self.metrics.validation = self.lap();
+    }
+
+    fn processed(&mut self, text: &str, lines: &[String]) {
+        self.metrics.content_processing = self.lap();
+        self.metrics.lines_wrapped = lines.len().saturating_sub(text.lines().count());
+        self.metrics.bytes_allocated_estimate += lines.iter().map(String::capacity).sum::<usize>();
+    }
+
+    fn laid_out(&mut self) {
+        self.metrics.layout = self.lap();
+    }
+
+    fn rendered(&mut self, output: &str) {
+        self.metrics.rendering = self.lap();
+        self.metrics.output_lines = output.lines().count();
+        self.metrics.bytes_allocated_estimate += output.len();
+    }
+}
+
+/// Render a box like [`boxen`](crate::boxen) and report how long each phase took
+///
+/// # Errors
+///
+/// Returns the same errors as [`boxen`](crate::boxen).
+///
+/// # Examples
+///
+/// ```rust
+/// use ::boxen::{BoxenOptions, Width, boxen_timed};
+///
+/// let options = BoxenOptions {
+///     width: Some(Width::Fixed(12)),
+///     ..Default::default()
+/// };
+/// let (_, metrics) = boxen_timed("one two three four", Some(options)).unwrap();
+/// assert!(metrics.lines_wrapped > 0);
+/// ```
+pub fn boxen_timed<S: AsRef<str>>(
+    text: S,
+    options: Option<BoxenOptions>,
+) -> BoxenResult<(String, RenderMetrics)> {
+    let mut timer = PhaseTimer::new();
+    let rendered = boxen_observed(text.as_ref(), options, &mut timer, Measure::default())?;
+    timer.metrics.total = timer.start.elapsed();
+    Ok((rendered, timer.metrics))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::options::Width;
+
+    #[test]
+    fn test_phases_sum_to_total() {
+        let text = "word ".repeat(200);
+        let (_, metrics) = boxen_timed(&text, None).unwrap();
+
+        let phases = metrics.phases_total();
+        assert!(phases <= metrics.total);
+        // Only the bookkeeping after the last phase is unaccounted for
+        assert!(metrics.total - phases < Duration::from_millis(50));
+        assert!(metrics.content_processing > Duration::ZERO);
+        assert!(metrics.rendering > Duration::ZERO);
+    }
+
+    #[test]
+    fn test_counts_are_plausible() {
+        let options = BoxenOptions {
+            width: Some(Width::Fixed(12)),
+            ..Default::default()
+        };
+        let (rendered, metrics) = boxen_timed("one two three four\nfive", Some(options)).unwrap();
+
+        // "one two three four" wraps to "one two" / "three four" in 10 columns
+        assert_eq!(metrics.lines_wrapped, 1);
+        assert_eq!(metrics.output_lines, rendered.lines().count());
+        assert_eq!(metrics.output_lines, 5);
+        assert!(metrics.bytes_allocated_estimate >= rendered.len());
+    }
+
+    #[test]
+    fn test_errors_match_boxen() {
+        let options = BoxenOptions {
+            width: Some(Width::Fixed(0)),
+            ..Default::default()
+        };
+        assert!(boxen_timed("Hello", Some(options.clone())).is_err());
+        assert!(crate::boxen("Hello", Some(options)).is_err());
+    }
+}
@@ -196,7 +196,9 @@
 //! or used in concurrent rendering operations.
 
 use crate::error::{BoxenError, BoxenResult};
-use crate::terminal::{calculate_border_width, get_terminal_height, get_terminal_width};
+use crate::terminal::{
+    calculate_border_width_with_thickness, get_terminal_height, get_terminal_width,
+};
 
 /// Width specification for box sizing.
 ///
@@ -216,6 +218,21 @@ use crate::terminal::{calculate_border_width, get_terminal_height, get_terminal_
 /// // Or using Into trait
 /// let fixed2 = builder().width(Width::from(50));
 /// ```
+///
+/// # Equality
+///
+/// Functions can't be compared by behavior, so `Dynamic` widths are equal
+/// only when they share the same function: a clone equals its original, but
+/// two separately created functions never compare equal, even with the same
+/// body. Hashing follows the same identity.
+///
+/// ```rust
+/// use ::boxen::Width;
+///
+/// let width = Width::from(|available: usize| available / 2);
+/// assert_eq!(width, width.clone());
+/// assert_ne!(width, Width::from(|available: usize| available / 2));
+/// ```
 pub enum Width {
     /// Fixed width in columns
     Fixed(usize),
@@ -236,13 +253,25 @@ impl PartialEq for Width {
     fn eq(&self, other: &Self) -> bool {
         match (self, other) {
             (Self::Fixed(a), Self::Fixed(b)) => a == b,
-            // Dynamic functions can't be compared for equality
-            (Self::Dynamic(_), Self::Dynamic(_)) => false,
+            // Dynamic functions can't be compared by behavior, only by identity
+            (Self::Dynamic(a), Self::Dynamic(b)) => std::sync::Arc::ptr_eq(a, b),
             _ => false,
         }
     }
 }
 
+impl Eq for Width {}
+
+impl std::hash::Hash for Width {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        std::mem::discriminant(self).hash(state);
+        match self {
+            Self::Fixed(w) => w.hash(state),
+            Self::Dynamic(f) => std::sync::Arc::as_ptr(f).cast::<()>().hash(state),
+        }
+    }
+}
+
 impl Width {
     /// Create a fixed width
     #[must_use]
@@ -308,6 +337,12 @@ where
 /// // Dynamic height based on available space
 /// let dynamic = builder().height(|available: usize| available.min(30));
 /// ```
+///
+/// # Equality
+///
+/// As with [`Width`], `Dynamic` heights are equal only when they share the
+/// same function, such as a clone and its original; separately created
+/// functions never compare equal. Hashing follows the same identity.
 pub enum Height {
     /// Fixed height in rows
     Fixed(usize),
@@ -328,13 +363,25 @@ impl PartialEq for Height {
     fn eq(&self, other: &Self) -> bool {
         match (self, other) {
             (Self::Fixed(a), Self::Fixed(b)) => a == b,
-            // Dynamic functions can't be compared for equality
-            (Self::Dynamic(_), Self::Dynamic(_)) => false,
+            // Dynamic functions can't be compared by behavior, only by identity
+            (Self::Dynamic(a), Self::Dynamic(b)) => std::sync::Arc::ptr_eq(a, b),
             _ => false,
         }
     }
 }
 
+impl Eq for Height {}
+
+impl std::hash::Hash for Height {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        std::mem::discriminant(self).hash(state);
+        match self {
+            Self::Fixed(h) => h.hash(state),
+            Self::Dynamic(f) => std::sync::Arc::as_ptr(f).cast::<()>().hash(state),
+        }
+    }
+}
+
 impl Height {
     /// Create a fixed height
     #[must_use]
@@ -426,67 +473,316 @@ where
 /// # Field Documentation
 ///
 /// - `border_style`: The style of border to draw around the box
+/// - `border_thickness`: How many characters wide each side border is drawn
+/// - `bevel`: How many fill cells are blanked at each end of the top and bottom borders
+/// - `inner_border`: Optional second border drawn one cell inside the main border
+/// - `inner_border_color`: Optional color for the inner border
 /// - `padding`: Internal spacing between the border and content
 /// - `margin`: External spacing around the entire box
 /// - `text_alignment`: How to align text within the box
+/// - `alignment_marker`: Optional delimiter for per-line alignment markers
+/// - `numeric_align`: Whether numeric lines are right-aligned regardless of `text_alignment`
+/// - `wrap`: Whether long lines are wrapped to the content width
+/// - `overflow_no_wrap`: How over-wide lines are handled when `wrap` is off
+/// - `pre_wrapped`: Whether the text is already wrapped, one row per input line
 /// - `title`: Optional title to display in the top border
 /// - `title_alignment`: How to align the title within the top border
+/// - `title_position`: Whether the title sits in the top border or above the box
+/// - `title_connectors`: Optional glyphs drawn immediately before and after the title
+/// - `title_margin`: Border characters kept between the title and the fill on each side
+/// - `title_require_fill`: Whether the title must leave border fill next to both corners
+/// - `title_full_width`: Whether the background color spans the whole titled top border
+/// - `align_content_to_title`: Whether to indent content to start under the title
 /// - `float`: How to position the box within the terminal
 /// - `width`: Optional fixed width for the box
+/// - `max_width`: Optional cap on the total box width, including margins
+/// - `content_width`: Optional wrap width for the text, narrower than the box
 /// - `height`: Optional fixed height for the box
 /// - `border_color`: Optional color for the border
 /// - `background_color`: Optional background color for the content area
+/// - `text_color`: Optional foreground color for the content text
+/// - `title_gradient`: Optional start and end colors blended across the title
 /// - `dim_border`: Whether to render the border with reduced intensity
+/// - `dim_content`: Whether to render the content with reduced intensity
+/// - `inset_shadow`: Whether to shade the padding like a recessed bevel
+/// - `force_color`: Optional override for color detection
+/// - `reset_mode`: Whether styles end with a full reset or only their own off codes
+/// - `line_ending`: The separator written between rendered rows
+/// - `mirror`: Whether the box is laid out right to left for right-origin terminals
 /// - `fullscreen`: Optional fullscreen mode configuration
-#[derive(Debug, Clone)]
+/// - `ascii_only`: Whether to downgrade non-ASCII text to ASCII equivalents
+/// - `line_prefix`: Optional text placed before every content line
+/// - `line_suffix`: Optional text placed after every content line
+/// - `caption`: Optional line printed below the box, outside borders and margins
+/// - `caption_alignment`: How to align the caption within the box width
+/// - `tile_horizontal`: Whether to repeat the box across the terminal width
+/// - `ignore_terminal_bounds`: Whether to allow boxes larger than the terminal
+/// - `clamp_to_terminal`: Whether to shrink over-wide boxes to the terminal width
+/// - `collapse_margin_to_edge`: Whether to drop margins on sides that touch the terminal edge
+/// - `max_input_bytes`: Optional limit on the size of the input text
+/// - `max_total_cells`: Optional limit on the rendered width times height
+/// - `error_on_empty`: Whether empty or whitespace-only input is rejected
+/// - `preserve_blank_edges`: Whether to keep blank lines at the content edges
+/// - `tab_width`: Optional tab stop width for expanding tabs in the content, title, and caption
+/// - `columns`: Whether tabs in the content separate aligned columns
+/// - `collapse_whitespace`: Whether to squeeze runs of inner spaces and tabs
+/// - `vertical_whitespace`: How vertical tabs and form feeds in the content are handled
+/// - `normalize`: Optional Unicode normalization applied to the content
+/// - `scrollbar`: Optional scroll position drawn as a thumb on the right border
+/// - `pointer`: Optional callout pointer drawn on the top or bottom border
+/// - `truncation_indicator`: Optional glyph marking a bottom border when content was cut off
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub struct BoxenOptions {
     /// The visual style of the border (Single, Double, Rounded, etc.)
     pub border_style: BorderStyle,
+    /// Number of times the left and right border characters are repeated,
+    /// for a heavier look on fonts that draw box lines thinly. The top and
+    /// bottom borders widen to match. `0` is treated as `1`.
+    pub border_thickness: usize,
+    /// Number of cells left blank at each end of the top and bottom border
+    /// fill, next to the corners, for a stepped bevel. The box keeps its
+    /// width; at most half of the fill is blanked.
+    pub bevel: usize,
+    /// Optional accent border drawn one cell inside the main border. It takes
+    /// one cell of padding on each side (sides without padding leave the two
+    /// frames touching); the rest of the padding surrounds the content.
+    pub inner_border: Option<BorderStyle>,
+    /// Optional color for the inner border characters
+    pub inner_border_color: Option<Color>,
     /// Internal spacing between the border and content
     pub padding: Spacing,
     /// External spacing around the entire box
     pub margin: Spacing,
     /// How to align text within the box content area
     pub text_alignment: TextAlignment,
-    /// Optional title to display in the top border
+    /// Optional delimiter enabling per-line alignment markers. With
+    /// `Some('\x01')`, a line starting with `\x01C\x01` is centered and one
+    /// starting with `\x01R\x01` (or `\x01L\x01`) is right (or left) aligned,
+    /// overriding `text_alignment`. Markers are removed before rendering.
+    pub alignment_marker: Option<char>,
+    /// Whether lines that look like numbers (e.g. `-1,234.50`) are right-aligned
+    /// so their digits line up, whatever `text_alignment` says
+    pub numeric_align: bool,
+    /// Whether lines longer than the content width are wrapped (default `true`)
+    pub wrap: bool,
+    /// Handling of over-wide lines when `wrap` is `false` or the text is
+    /// `pre_wrapped`
+    pub overflow_no_wrap: NoWrapOverflow,
+    /// Whether the text is already wrapped, so every input line is one row and
+    /// is never re-wrapped, whatever `wrap` says (default `false`)
+    pub pre_wrapped: bool,
+    /// Optional title to display in the top border. A title that carries its
+    /// own ANSI styling is drawn as given, without `title_color` or the
+    /// border color.
     pub title: Option<String>,
     /// How to align the title within the top border
     pub title_alignment: TitleAlignment,
+    /// Where the title is drawn: embedded in the top border, or on its own
+    /// line above the box
+    pub title_position: TitlePosition,
+    /// Optional glyphs drawn immediately before and after the title in the top
+    /// border, such as `('┤', '├')` for a tabbed look. Ignored without a border.
+    pub title_connectors: Option<(char, char)>,
+    /// Number of horizontal border characters kept to the left and right of
+    /// the title (outside any connectors). Dropped when the border is too narrow.
+    pub title_margin: (usize, usize),
+    /// Whether to keep at least one border fill character between the title
+    /// and each corner, truncating a title that would otherwise fill the border
+    pub title_require_fill: bool,
+    /// Whether a titled top border is drawn as a header bar, with
+    /// `background_color` behind the title, fill, and both corners
+    pub title_full_width: bool,
+    /// Whether to indent content so its left edge sits under the first visible
+    /// title character. Centered and right-aligned titles need a fixed width.
+    pub align_content_to_title: bool,
     /// How to position the box within the terminal width
     pub float: Float,
     /// Optional width specification (fixed or dynamic)
     pub width: Option<Width>,
+    /// Optional upper bound on the total box width, including margins. Content
+    /// wraps and the title ellipsizes to stay within it.
+    pub max_width: Option<usize>,
+    /// Optional reading measure: text wraps at this many columns while the box
+    /// keeps its full width, and alignment uses the whole content area
+    pub content_width: Option<usize>,
     /// Optional height specification (fixed or dynamic)
     pub height: Option<Height>,
     /// Optional color for the border characters
     pub border_color: Option<Color>,
     /// Optional background color for the content area
     pub background_color: Option<Color>,
+    /// Optional foreground color for the content text
+    pub text_color: Option<Color>,
     /// Optional color for the title text
     pub title_color: Option<Color>,
+    /// Optional start and end colors blended character by character across
+    /// the title in the top border, overriding `title_color`
+    pub title_gradient: Option<(Color, Color)>,
     /// Whether to render the border with reduced intensity
     pub dim_border: bool,
+    /// Whether to render the content text with reduced intensity
+    pub dim_content: bool,
+    /// Whether the padding is shaded like an inset bevel: a dark background
+    /// on the top and left padding, a light one on the bottom and right.
+    /// Ignored without a border.
+    pub inset_shadow: bool,
+    /// Overrides color detection. `Some` makes [`crate::boxen`] always or never
+    /// emit its own colors; `None` leaves the choice to `colored`. In
+    /// [`crate::write_boxen`] and [`crate::print_boxen`], `None` keeps colors
+    /// only for terminal targets, honouring `NO_COLOR` and `FORCE_COLOR`.
+    pub force_color: Option<bool>,
+    /// How styled spans are closed. [`ResetMode::Targeted`] keeps the
+    /// surrounding terminal styling intact when the box is embedded in other
+    /// colored output.
+    pub reset_mode: ResetMode,
+    /// Separator written between rendered rows, and after the box by the
+    /// write helpers
+    pub line_ending: LineEnding,
+    /// Whether the box is mirrored horizontally for terminals that place the
+    /// origin on the right: corners and side borders, padding, margins, and
+    /// alignments swap sides. Text keeps its logical order, and pointer
+    /// offsets still count from the left corner.
+    pub mirror: bool,
     /// Optional fullscreen mode configuration
     pub fullscreen: Option<FullscreenMode>,
+    /// Whether to downgrade non-ASCII content, title, caption, and line affix
+    /// characters to ASCII equivalents (or `?`) before rendering. Borders
+    /// with non-ASCII glyphs are drawn as `BorderStyle::Classic`, and the
+    /// pointer, scrollbar thumb, truncation indicator, and spinner use ASCII
+    /// stand-ins.
+    pub ascii_only: bool,
+    /// Optional text placed before every content line, such as `"> "` for
+    /// quotes or `"• "` for bullets. Its width is reserved before wrapping.
+    pub line_prefix: Option<String>,
+    /// Optional text placed after every content line. Its width is reserved
+    /// before wrapping.
+    pub line_suffix: Option<String>,
+    /// Optional caption printed as the last line, below the bottom margin and
+    /// outside the border. Styled with `title_color` when set.
+    pub caption: Option<String>,
+    /// How to align the caption within the box width
+    pub caption_alignment: TitleAlignment,
+    /// Whether to repeat the rendered box side by side across the terminal
+    /// width, trimming the last copy. Float is ignored while tiling.
+    pub tile_horizontal: bool,
+    /// Whether to skip the checks that reject boxes wider or taller than the
+    /// terminal, for output written to files or pipes. Margins of a
+    /// left-floated box are then kept exactly; otherwise they shrink rather
+    /// than push the box past the terminal edge.
+    pub ignore_terminal_bounds: bool,
+    /// Whether a box wider than the terminal is silently narrowed, re-wrapping
+    /// its content, instead of failing the render
+    pub clamp_to_terminal: bool,
+    /// Whether margins are dropped on the sides where the box already sits
+    /// against the terminal edge, for a box that is the only output: the top
+    /// margin, plus the left margin of a left-floated box or the right margin
+    /// of a right-floated one
+    pub collapse_margin_to_edge: bool,
+    /// Optional upper bound on the input text size in bytes, for rendering
+    /// untrusted text. Larger input is rejected before any processing.
+    pub max_input_bytes: Option<usize>,
+    /// Optional upper bound on the rendered box area (total width times total
+    /// height, margins included). Checked after layout, before the output is
+    /// allocated.
+    pub max_total_cells: Option<usize>,
+    /// Whether empty or whitespace-only input fails the render instead of
+    /// producing an empty box
+    pub error_on_empty: bool,
+    /// Whether to keep leading and trailing blank content lines exactly as
+    /// written. Without it a final newline does not produce an empty row.
+    pub preserve_blank_edges: bool,
+    /// Whether runs of spaces and tabs inside a line are replaced by a single
+    /// space before wrapping. Leading indentation is kept as written.
+    pub collapse_whitespace: bool,
+    /// Optional tab stop width. Tabs in the content, title, and caption are
+    /// expanded to spaces, counting columns from the start of each line or
+    /// label. `None` leaves tabs in place, measured as one column.
+    pub tab_width: Option<usize>,
+    /// Whether tabs in the content separate columns. Each column starts two
+    /// cells past the widest cell before it, padded with spaces, and content
+    /// with columns is not wrapped. Lines without a tab are left as they are.
+    /// `collapse_whitespace` undoes the padding.
+    pub columns: bool,
+    /// How vertical tab (`\x0b`) and form feed (`\x0c`) characters in the
+    /// content are handled. By default each one starts a new line.
+    pub vertical_whitespace: VerticalWhitespace,
+    /// Unicode normalization applied to the content before wrapping and
+    /// measuring. `None` skips normalization entirely.
+    pub normalize: Option<NormalizationForm>,
+    /// Optional scroll position shown as a `█` thumb replacing part of the
+    /// right border. Ignored without a border.
+    pub scrollbar: Option<ScrollbarState>,
+    /// Optional pointer glyph replacing one top or bottom border character,
+    /// for callout bubbles. A top pointer is not drawn when there is a title.
+    pub pointer: Option<Pointer>,
+    /// Optional glyph drawn over the middle of the bottom border when `height`
+    /// cut off part of the content, such as `▼`. Must be one column wide.
+    pub truncation_indicator: Option<char>,
 }
 
 impl Default for BoxenOptions {
     fn default() -> Self {
         Self {
             border_style: BorderStyle::Single,
+            border_thickness: 1,
+            bevel: 0,
+            inner_border: None,
+            inner_border_color: None,
             padding: Spacing::default(),
             margin: Spacing::default(),
             text_alignment: TextAlignment::Left,
+            alignment_marker: None,
+            numeric_align: false,
+            wrap: true,
+            overflow_no_wrap: NoWrapOverflow::Clip,
+            pre_wrapped: false,
             title: None,
             title_alignment: TitleAlignment::Left,
+            title_position: TitlePosition::Border,
+            title_connectors: None,
+            title_margin: (0, 0),
+            title_require_fill: false,
+            title_full_width: false,
+            align_content_to_title: false,
             float: Float::Left,
             width: None,
+            max_width: None,
+            content_width: None,
             height: None,
             border_color: None,
             background_color: None,
+            text_color: None,
             title_color: None,
+            title_gradient: None,
             dim_border: false,
+            dim_content: false,
+            inset_shadow: false,
+            force_color: None,
+            reset_mode: ResetMode::Full,
+            line_ending: LineEnding::Lf,
+            mirror: false,
             fullscreen: None,
+            ascii_only: false,
+            line_prefix: None,
+            line_suffix: None,
+            caption: None,
+            caption_alignment: TitleAlignment::Left,
+            tile_horizontal: false,
+            ignore_terminal_bounds: false,
+            clamp_to_terminal: false,
+            collapse_margin_to_edge: false,
+            max_input_bytes: None,
+            max_total_cells: None,
+            error_on_empty: false,
+            preserve_blank_edges: false,
+            collapse_whitespace: false,
+            tab_width: None,
+            columns: false,
+            vertical_whitespace: VerticalWhitespace::LineBreak,
+            normalize: None,
+            scrollbar: None,
+            pointer: None,
+            truncation_indicator: None,
         }
     }
 }
@@ -544,7 +840,7 @@ impl Default for BoxenOptions {
 /// ┗━━━━━┛
 /// ```
 #[non_exhaustive]
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub enum BorderStyle {
     /// No border - content only
     None,
@@ -562,12 +858,17 @@ pub enum BorderStyle {
     DoubleSingle,
     /// Classic ASCII-style border using +, -, |
     Classic,
+    /// ASCII border with rounded-looking `.` top and `'` bottom corners
+    ClassicRounded,
     /// Custom border using specified characters
     Custom(BorderChars),
+    /// Border characters registered under this name with
+    /// [`register_style`](crate::borders::register_style), resolved when drawn
+    Registered(String),
 }
 
 /// Border character set for custom borders
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub struct BorderChars {
     /// Character for the top-left corner of the border
     pub top_left: char,
@@ -631,7 +932,7 @@ pub struct BorderChars {
 /// When created from a single `usize` value, this struct follows the TypeScript
 /// boxen behavior of creating asymmetric spacing with 3x horizontal padding
 /// to account for typical terminal character aspect ratios.
-#[derive(Debug, Default, Clone, Copy)]
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
 pub struct Spacing {
     /// Top spacing
     pub top: usize,
@@ -650,6 +951,7 @@ impl From<usize> for Spacing {
     /// which may be surprising. Consider using explicit constructors instead:
     /// - `Spacing::terminal_balanced(value)` - For terminal-aware spacing (same as this)
     /// - `Spacing::uniform(value)` - For equal spacing on all sides
+    /// - `Uniform(value)` - Equal spacing wherever an `Into<Spacing>` is accepted
     ///
     /// Due to terminal character aspect ratios (characters are ~2x taller than wide),
     /// horizontal spacing is automatically scaled 3x to appear visually balanced:
@@ -687,9 +989,33 @@ impl From<(usize, usize, usize, usize)> for Spacing {
     }
 }
 
+/// The same spacing on every side, without the 3x horizontal multiplier
+///
+/// `From<usize>` for [`Spacing`] triples the left and right values to match
+/// the TypeScript library. Wrap the value in `Uniform` to get it literally.
+///
+/// # Examples
+///
+/// ```rust
+/// use ::boxen::{Spacing, Uniform, builder};
+///
+/// assert_eq!(Spacing::from(Uniform(2)), Spacing::uniform(2));
+///
+/// let result = builder().padding(Uniform(1)).render("Hi").unwrap();
+/// assert_eq!(result.lines().nth(2).unwrap(), "│ Hi │");
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Uniform(pub usize);
+
+impl From<Uniform> for Spacing {
+    fn from(Uniform(value): Uniform) -> Self {
+        Self::uniform(value)
+    }
+}
+
 /// Text alignment within the box
 #[non_exhaustive]
-#[derive(Debug, Clone, Copy)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub enum TextAlignment {
     /// Align text to the left side of the box
     Left,
@@ -701,7 +1027,7 @@ pub enum TextAlignment {
 
 /// Title alignment within the top border
 #[non_exhaustive]
-#[derive(Debug, Clone, Copy)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub enum TitleAlignment {
     /// Align title to the left side of the top border
     Left,
@@ -711,9 +1037,25 @@ pub enum TitleAlignment {
     Right,
 }
 
+/// Where the title is drawn relative to the box frame
+#[non_exhaustive]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum TitlePosition {
+    /// Embedded in the top border (default)
+    Border,
+    /// On a separate line above the box and its top margin, aligned within
+    /// the box width like a caption
+    Above,
+    /// Written down the left border, one character per row between the
+    /// corners, in the border color. `title_alignment` places it at the top,
+    /// middle, or bottom, and it is cut off when taller than the box. Needs a
+    /// visible border one character thick and single-width title characters.
+    Left,
+}
+
 /// Box positioning relative to terminal
 #[non_exhaustive]
-#[derive(Debug, Clone, Copy)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub enum Float {
     /// Position box on the left side of the terminal
     Left,
@@ -723,9 +1065,185 @@ pub enum Float {
     Right,
 }
 
+/// What to do with a line wider than the box when wrapping is disabled
+#[non_exhaustive]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum NoWrapOverflow {
+    /// Fail the render with a text processing error
+    Error,
+    /// Cut the line at the content width, keeping ANSI sequences intact
+    Clip,
+    /// Let the line run past the right border, dropping padding and border on that row
+    Overflow,
+}
+
+/// Handling of vertical tab (`\x0b`) and form feed (`\x0c`) in the content
+///
+/// Text copied from documents or terminals sometimes carries these page and
+/// line controls, which terminals render inconsistently and which would
+/// otherwise be measured as part of a line.
+#[non_exhaustive]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum VerticalWhitespace {
+    /// Replace each one with a line break
+    LineBreak,
+    /// Remove them
+    Strip,
+    /// Pass them through unchanged
+    Keep,
+}
+
+/// How the escape sequences that end a styled span are written
+#[non_exhaustive]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ResetMode {
+    /// End each span with `\x1b[0m`, clearing every attribute
+    Full,
+    /// End each span with only the off codes for what it turned on, such as
+    /// `39` for a foreground color or `49` for a background
+    Targeted,
+}
+
+/// Line separator used in rendered output
+#[non_exhaustive]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum LineEnding {
+    /// `\n`
+    Lf,
+    /// `\r\n`, as expected by Windows text files
+    CrLf,
+}
+
+impl LineEnding {
+    /// The separator characters
+    #[must_use]
+    pub const fn as_str(self) -> &'static str {
+        match self {
+            Self::Lf => "\n",
+            Self::CrLf => "\r\n",
+        }
+    }
+}
+
+/// Position of a scrollable viewport, drawn as a thumb on the right border
+///
+/// `total` is the number of lines in the full document, `visible` the number
+/// shown in the box, and `offset` the index of the first visible line.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct ScrollbarState {
+    /// Index of the first visible line
+    pub offset: usize,
+    /// Total number of lines in the scrollable document
+    pub total: usize,
+    /// Number of lines visible at once
+    pub visible: usize,
+}
+
+impl ScrollbarState {
+    /// Create a scrollbar state
+    #[must_use]
+    pub fn new(offset: usize, total: usize, visible: usize) -> Self {
+        Self {
+            offset,
+            total,
+            visible,
+        }
+    }
+
+    /// Rows of a track `track_len` rows tall that the thumb covers
+    ///
+    /// The thumb is at least one row tall and sized in proportion to the
+    /// visible fraction; its position is proportional to the scroll offset.
+    /// An empty document yields an empty range (no thumb).
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use ::boxen::ScrollbarState;
+    ///
+    /// assert_eq!(ScrollbarState::new(0, 10, 3).thumb_range(3), 0..1);
+    /// assert_eq!(ScrollbarState::new(7, 10, 3).thumb_range(3), 2..3);
+    /// assert_eq!(ScrollbarState::new(0, 5, 10).thumb_range(4), 0..4);
+    /// ```
+    #[must_use]
+    pub fn thumb_range(&self, track_len: usize) -> std::ops::Range<usize> {
+        if self.total == 0 || track_len == 0 {
+            return 0..0;
+        }
+        if self.visible >= self.total {
+            return 0..track_len;
+        }
+
+        let thumb_len =
+            ((track_len * self.visible + self.total / 2) / self.total).clamp(1, track_len);
+        let max_offset = self.total - self.visible;
+        let travel = track_len - thumb_len;
+        let start = (self.offset.min(max_offset) * travel + max_offset / 2) / max_offset;
+        start..start + thumb_len
+    }
+}
+
+/// Horizontal border edge a [`Pointer`] is drawn on
+#[non_exhaustive]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Edge {
+    /// The top border, pointing up with `▲`
+    Top,
+    /// The bottom border, pointing down with `▼`
+    Bottom,
+}
+
+/// A speech-bubble style pointer replacing one border fill character
+///
+/// `offset` counts fill characters from the left corner (0 is the first
+/// character after the corner) and is clamped to the border width.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Pointer {
+    /// Border edge the pointer is drawn on
+    pub edge: Edge,
+    /// Column within the border fill, starting after the corner
+    pub offset: usize,
+}
+
+impl Pointer {
+    /// Create a pointer on `edge` at `offset`
+    #[must_use]
+    pub fn new(edge: Edge, offset: usize) -> Self {
+        Self { edge, offset }
+    }
+
+    /// Glyph drawn for this pointer
+    #[must_use]
+    pub fn glyph(&self) -> char {
+        match self.edge {
+            Edge::Top => '▲',
+            Edge::Bottom => '▼',
+        }
+    }
+
+    /// Glyph drawn for this pointer when `ascii_only` is set
+    #[must_use]
+    pub fn ascii_glyph(&self) -> char {
+        match self.edge {
+            Edge::Top => '^',
+            Edge::Bottom => 'v',
+        }
+    }
+}
+
+/// Unicode normalization form applied to content before measuring
+#[non_exhaustive]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum NormalizationForm {
+    /// Canonical composition (`e` + combining acute becomes `é`)
+    Nfc,
+    /// Canonical decomposition (`é` becomes `e` + combining acute)
+    Nfd,
+}
+
 /// Color specification for borders and backgrounds
 #[non_exhaustive]
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub enum Color {
     /// Named color (e.g., "red", "blue", "green")
     Named(String),
@@ -733,6 +1251,9 @@ pub enum Color {
     Hex(String),
     /// RGB color values (red, green, blue components 0-255)
     Rgb(u8, u8, u8),
+    /// Color derived from the box content when rendering, so boxes with the
+    /// same text always share a color (see [`crate::color::auto_color`])
+    Auto,
 }
 
 /// Fullscreen mode configuration
@@ -745,6 +1266,31 @@ pub enum FullscreenMode {
     Custom(fn(usize, usize) -> (usize, usize)),
 }
 
+impl PartialEq for FullscreenMode {
+    fn eq(&self, other: &Self) -> bool {
+        match (self, other) {
+            (Self::Auto, Self::Auto) => true,
+            // Function pointers are compared by address, which identifies the same function
+            (Self::Custom(a), Self::Custom(b)) => std::ptr::fn_addr_eq(*a, *b),
+            _ => false,
+        }
+    }
+}
+
+impl Eq for FullscreenMode {}
+
+impl std::hash::Hash for FullscreenMode {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        std::mem::discriminant(self).hash(state);
+        if let Self::Custom(func) = self {
+            (*func as usize).hash(state);
+        }
+    }
+}
+
+/// Text rewrite applied by [`BoxenBuilder::transform`]
+type TextTransform = Box<dyn Fn(&str) -> String + Send + Sync>;
+
 /// Builder pattern for creating `BoxenOptions` with a fluent interface.
 ///
 /// The `BoxenBuilder` provides a convenient and type-safe way to configure box styling
@@ -852,6 +1398,11 @@ pub enum FullscreenMode {
 /// ```
 pub struct BoxenBuilder {
     options: BoxenOptions,
+    aspect_ratio: Option<f32>,
+    square: bool,
+    margin_pct: Option<[u8; 4]>,
+    width_like: Option<usize>,
+    transform: Option<TextTransform>,
 }
 
 impl BoxenBuilder {
@@ -869,6 +1420,44 @@ impl BoxenBuilder {
     pub fn new() -> Self {
         Self {
             options: BoxenOptions::default(),
+            aspect_ratio: None,
+            square: false,
+            margin_pct: None,
+            width_like: None,
+            transform: None,
+        }
+    }
+
+    /// Create a builder that starts from existing options.
+    ///
+    /// Useful for adjusting a stored or deserialized configuration with a few
+    /// builder calls instead of rebuilding it from defaults.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use ::boxen::{BorderStyle, BoxenBuilder, BoxenOptions};
+    ///
+    /// let stored = BoxenOptions {
+    ///     padding: 1.into(),
+    ///     ..Default::default()
+    /// };
+    ///
+    /// let result = BoxenBuilder::from_options(stored)
+    ///     .border_style(BorderStyle::Round)
+    ///     .render("Hello")
+    ///     .unwrap();
+    /// assert!(result.starts_with('╭'));
+    /// ```
+    #[must_use]
+    pub fn from_options(options: BoxenOptions) -> Self {
+        Self {
+            options,
+            aspect_ratio: None,
+            square: false,
+            margin_pct: None,
+            width_like: None,
+            transform: None,
         }
     }
 
@@ -894,6 +1483,69 @@ impl BoxenBuilder {
         self
     }
 
+    /// Draw the left and right borders `thickness` characters wide
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use ::boxen::builder;
+    ///
+    /// let result = builder().border_thickness(2).render("Hi").unwrap();
+    /// assert_eq!(result, "┌────┐\n││Hi││\n└────┘");
+    /// ```
+    #[must_use]
+    pub fn border_thickness(mut self, thickness: usize) -> Self {
+        self.options.border_thickness = thickness;
+        self
+    }
+
+    /// Blank `cells` of the top and bottom border fill next to each corner
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use ::boxen::builder;
+    ///
+    /// let result = builder().bevel(1).render("Hello").unwrap();
+    /// assert_eq!(result, "┌ ─── ┐\n│Hello│\n└ ─── ┘");
+    /// ```
+    #[must_use]
+    pub fn bevel(mut self, cells: usize) -> Self {
+        self.options.bevel = cells;
+        self
+    }
+
+    /// Draw a second, accent border one cell inside the main border
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use ::boxen::{builder, BorderStyle};
+    ///
+    /// let result = builder()
+    ///     .border_style(BorderStyle::Double)
+    ///     .inner_border(BorderStyle::Single)
+    ///     .padding(1)
+    ///     .render("Hi")
+    ///     .unwrap();
+    /// assert_eq!(
+    ///     result,
+    ///     "╔══════════╗\n║          ║\n║ ┌──────┐ ║\n║ │  Hi  │ ║\n║ └──────┘ ║\n║          ║\n╚══════════╝"
+    /// );
+    /// ```
+    #[must_use]
+    pub fn inner_border(mut self, style: BorderStyle) -> Self {
+        self.options.inner_border = Some(style);
+        self
+    }
+
+    /// Set the color of the inner border
+    #[must_use]
+    pub fn inner_border_color<C: Into<Color>>(mut self, color: C) -> Self {
+        self.options.inner_border_color = Some(color.into());
+        self
+    }
+
     /// Set padding around the text content.
     ///
     /// Padding is the space between the text and the border. Accepts various formats:
@@ -939,350 +1591,1625 @@ impl BoxenBuilder {
     #[must_use]
     pub fn margin<T: Into<Spacing>>(mut self, margin: T) -> Self {
         self.options.margin = margin.into();
+        self.margin_pct = None;
         self
     }
 
-    /// Set text alignment
+    /// Set margins as percentages of the terminal size, in `[top, right,
+    /// bottom, left]` order
+    ///
+    /// Left and right are fractions of the terminal width, top and bottom of
+    /// its height. They are resolved to cells by [`build`](Self::build) and
+    /// [`render`](Self::render), then scaled down if needed so the border,
+    /// padding and at least one content cell still fit. Values above 100 are
+    /// treated as 100.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use ::boxen::builder;
+    ///
+    /// let options = builder().margin_pct(0, 10, 0, 10).build();
+    /// assert_eq!(options.margin.left, options.margin.right);
+    /// assert_eq!(options.margin.top, 0);
+    /// ```
     #[must_use]
-    pub fn text_alignment(mut self, alignment: TextAlignment) -> Self {
-        self.options.text_alignment = alignment;
+    pub fn margin_pct(mut self, top: u8, right: u8, bottom: u8, left: u8) -> Self {
+        self.margin_pct = Some([top, right, bottom, left]);
         self
     }
 
-    /// Set title text
+    /// Set text alignment
     #[must_use]
-    pub fn title<S: Into<String>>(mut self, title: S) -> Self {
-        self.options.title = Some(title.into());
+    pub fn text_alignment(mut self, alignment: TextAlignment) -> Self {
+        self.options.text_alignment = alignment;
         self
     }
 
-    /// Set title alignment
+    /// Enable per-line alignment markers delimited by `delimiter`
+    ///
+    /// Lines beginning with `{delimiter}L{delimiter}`, `{delimiter}C{delimiter}`,
+    /// or `{delimiter}R{delimiter}` are aligned left, center, or right; other
+    /// lines use the box's text alignment.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use ::boxen::builder;
+    ///
+    /// let result = builder()
+    ///     .alignment_marker('\x01')
+    ///     .width(12)
+    ///     .render("Name: boxen\n\x01C\x01v0.4")
+    ///     .unwrap();
+    /// assert!(result.contains("│   v0.4   │"));
+    /// ```
     #[must_use]
-    pub fn title_alignment(mut self, alignment: TitleAlignment) -> Self {
-        self.options.title_alignment = alignment;
+    pub fn alignment_marker(mut self, delimiter: char) -> Self {
+        self.options.alignment_marker = Some(delimiter);
         self
     }
 
-    /// Set title color
+    /// Right-align lines that look like numbers, leaving other lines to the
+    /// box's text alignment
     ///
     /// # Examples
     ///
     /// ```rust
-    /// use ::boxen::builder;
+    /// use ::boxen::{builder, TextAlignment};
     ///
     /// let result = builder()
-    ///     .title("Status")
-    ///     .title_color("red")
-    ///     .border_color("blue")
-    ///     .render("Content")
+    ///     .numeric_align(true)
+    ///     .text_alignment(TextAlignment::Left)
+    ///     .render("Total\n12.50\n3.00")
     ///     .unwrap();
+    /// assert!(result.contains("│Total│"));
+    /// assert!(result.contains("│ 3.00│"));
     /// ```
     #[must_use]
-    pub fn title_color<C: Into<Color>>(mut self, color: C) -> Self {
-        self.options.title_color = Some(color.into());
+    pub fn numeric_align(mut self, enabled: bool) -> Self {
+        self.options.numeric_align = enabled;
         self
     }
 
-    /// Set float positioning
+    /// Enable or disable wrapping of long lines
     #[must_use]
-    pub fn float(mut self, float: Float) -> Self {
-        self.options.float = float;
+    pub fn wrap(mut self, wrap: bool) -> Self {
+        self.options.wrap = wrap;
         self
     }
 
-    /// Set box width (fixed or dynamic)
+    /// Mark the text as already wrapped, so every input line becomes exactly
+    /// one row
     ///
-    /// Accepts either a fixed width value or a closure for dynamic sizing.
+    /// Lines are only measured and aligned, whatever [`wrap`](Self::wrap)
+    /// says, and a line wider than the box follows
+    /// [`overflow_no_wrap`](Self::overflow_no_wrap).
     ///
     /// # Examples
     ///
     /// ```rust
     /// use ::boxen::builder;
     ///
-    /// // Fixed width
-    /// let result = builder().width(50).render("Hello").unwrap();
-    ///
-    /// // Dynamic width: use 80% of available space, minimum 30
     /// let result = builder()
-    ///     .width(|available: usize| (available * 4 / 5).max(30))
-    ///     .render("Hello")
+    ///     .width(12)
+    ///     .pre_wrapped(true)
+    ///     .render("one two\nthree")
     ///     .unwrap();
+    /// assert!(result.contains("│one two   │"));
     /// ```
     #[must_use]
-    pub fn width<W: Into<Width>>(mut self, width: W) -> Self {
-        self.options.width = Some(width.into());
+    pub fn pre_wrapped(mut self, pre_wrapped: bool) -> Self {
+        self.options.pre_wrapped = pre_wrapped;
         self
     }
 
-    /// Set box height (fixed or dynamic)
-    ///
-    /// Accepts either a fixed height value or a closure for dynamic sizing.
+    /// Choose how over-wide lines are handled when wrapping is disabled
     ///
     /// # Examples
     ///
     /// ```rust
-    /// use ::boxen::builder;
-    ///
-    /// // Fixed height
-    /// let result = builder().height(20).render("Hello").unwrap();
+    /// use ::boxen::{builder, NoWrapOverflow};
     ///
-    /// // Dynamic height: use 50% of available space, minimum 10
     /// let result = builder()
-    ///     .height(|available: usize| (available / 2).max(10))
-    ///     .render("Hello")
+    ///     .width(8)
+    ///     .wrap(false)
+    ///     .overflow_no_wrap(NoWrapOverflow::Clip)
+    ///     .render("truncated line")
     ///     .unwrap();
+    /// assert!(result.contains("│trunca│"));
     /// ```
     #[must_use]
-    pub fn height<H: Into<Height>>(mut self, height: H) -> Self {
-        self.options.height = Some(height.into());
+    pub fn overflow_no_wrap(mut self, mode: NoWrapOverflow) -> Self {
+        self.options.overflow_no_wrap = mode;
         self
     }
 
-    /// Set border color
+    /// Set title text
     #[must_use]
-    pub fn border_color<C: Into<Color>>(mut self, color: C) -> Self {
-        self.options.border_color = Some(color.into());
+    pub fn title<S: Into<String>>(mut self, title: S) -> Self {
+        self.options.title = Some(title.into());
         self
     }
 
-    /// Set background color
+    /// Set title text from any `Display` value, such as a number or path
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use ::boxen::builder;
+    ///
+    /// let options = builder().title_display(42).build();
+    /// assert_eq!(options.title.as_deref(), Some("42"));
+    /// ```
     #[must_use]
-    pub fn background_color<C: Into<Color>>(mut self, color: C) -> Self {
-        self.options.background_color = Some(color.into());
+    pub fn title_display<D: std::fmt::Display>(mut self, title: D) -> Self {
+        self.options.title = Some(title.to_string());
         self
     }
 
-    /// Enable dim border
+    /// Set title alignment
     #[must_use]
-    pub fn dim_border(mut self, dim: bool) -> Self {
-        self.options.dim_border = dim;
+    pub fn title_alignment(mut self, alignment: TitleAlignment) -> Self {
+        self.options.title_alignment = alignment;
         self
     }
 
+    /// Set where the title is drawn
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use ::boxen::{builder, TitlePosition};
+    ///
+    /// let result = builder()
+    ///     .title("Notes")
+    ///     .title_position(TitlePosition::Above)
+    ///     .width(9)
+    ///     .render("Hello")
+    ///     .unwrap();
+    /// assert_eq!(result, "Notes    \n┌───────┐\n│Hello  │\n└───────┘");
+    /// ```
+    #[must_use]
+    pub fn title_position(mut self, position: TitlePosition) -> Self {
+        self.options.title_position = position;
+        self
+    }
+
+    /// Set connector glyphs drawn immediately before and after the title
+    ///
+    /// The connectors are counted in the title width and take the border styling.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use ::boxen::builder;
+    ///
+    /// let result = builder()
+    ///     .title(" Title ")
+    ///     .title_connectors('┤', '├')
+    ///     .width(20)
+    ///     .render("Content")
+    ///     .unwrap();
+    /// assert!(result.starts_with("┌┤ Title ├"));
+    /// ```
+    #[must_use]
+    pub fn title_connectors(mut self, left: char, right: char) -> Self {
+        self.options.title_connectors = Some((left, right));
+        self
+    }
+
+    /// Keep `left` and `right` horizontal border characters between the title
+    /// and the rest of the top border
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use ::boxen::builder;
+    ///
+    /// let result = builder()
+    ///     .title("Hi")
+    ///     .title_margin(2, 2)
+    ///     .width(12)
+    ///     .render("x")
+    ///     .unwrap();
+    /// assert!(result.starts_with("┌──Hi──────┐"));
+    /// ```
+    #[must_use]
+    pub fn title_margin(mut self, left: usize, right: usize) -> Self {
+        self.options.title_margin = (left, right);
+        self
+    }
+
+    /// Keep at least one border fill character on each side of the title
+    ///
+    /// Without this, a title as wide as the box replaces the entire top border
+    /// fill and touches both corners.
+    #[must_use]
+    pub fn title_require_fill(mut self, require: bool) -> Self {
+        self.options.title_require_fill = require;
+        self
+    }
+
+    /// Draw the titled top border as a full-width header bar
+    ///
+    /// The box's background color is painted behind the whole top border row,
+    /// corners included, instead of only the content area. Has no effect
+    /// without a title and a background color.
+    #[must_use]
+    pub fn title_full_width(mut self, full_width: bool) -> Self {
+        self.options.title_full_width = full_width;
+        self
+    }
+
+    /// Indent content so it starts in the same column as the title text
+    ///
+    /// The indent is added on top of the left padding. Centered and right-aligned
+    /// titles only take effect when the box width is fixed.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use ::boxen::builder;
+    ///
+    /// let result = builder()
+    ///     .title(" Card ")
+    ///     .title_connectors('┤', '├')
+    ///     .align_content_to_title(true)
+    ///     .render("Body")
+    ///     .unwrap();
+    /// let lines: Vec<&str> = result.lines().collect();
+    /// assert_eq!(lines[1], "│  Body│");
+    /// ```
+    #[must_use]
+    pub fn align_content_to_title(mut self, align: bool) -> Self {
+        self.options.align_content_to_title = align;
+        self
+    }
+
+    /// Set title color
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use ::boxen::builder;
+    ///
+    /// let result = builder()
+    ///     .title("Status")
+    ///     .title_color("red")
+    ///     .border_color("blue")
+    ///     .render("Content")
+    ///     .unwrap();
+    /// ```
+    #[must_use]
+    pub fn title_color<C: Into<Color>>(mut self, color: C) -> Self {
+        self.options.title_color = Some(color.into());
+        self
+    }
+
+    /// Blend the title from `start` to `end`, one color per character
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use ::boxen::{builder, text::strip_ansi_codes};
+    ///
+    /// let result = builder()
+    ///     .title("Build")
+    ///     .title_gradient("#ff0000", "#0000ff")
+    ///     .width(9)
+    ///     .render("ok")
+    ///     .unwrap();
+    /// assert!(strip_ansi_codes(&result).starts_with("┌Build──┐"));
+    /// ```
+    #[must_use]
+    pub fn title_gradient<S: Into<Color>, E: Into<Color>>(mut self, start: S, end: E) -> Self {
+        self.options.title_gradient = Some((start.into(), end.into()));
+        self
+    }
+
+    /// Set float positioning
+    #[must_use]
+    pub fn float(mut self, float: Float) -> Self {
+        self.options.float = float;
+        self
+    }
+
+    /// Set box width (fixed or dynamic)
+    ///
+    /// Accepts either a fixed width value or a closure for dynamic sizing.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use ::boxen::builder;
+    ///
+    /// // Fixed width
+    /// let result = builder().width(50).render("Hello").unwrap();
+    ///
+    /// // Dynamic width: use 80% of available space, minimum 30
+    /// let result = builder()
+    ///     .width(|available: usize| (available * 4 / 5).max(30))
+    ///     .render("Hello")
+    ///     .unwrap();
+    /// ```
+    #[must_use]
+    pub fn width<W: Into<Width>>(mut self, width: W) -> Self {
+        self.options.width = Some(width.into());
+        self.width_like = None;
+        self
+    }
+
+    /// Give the content area the display width of `reference`
+    ///
+    /// The border, padding and margin are added on top when the options are
+    /// built or rendered, so the call can come before or after setting them.
+    /// Useful for matching a box to a header printed above it.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use ::boxen::builder;
+    ///
+    /// let result = builder()
+    ///     .padding(1)
+    ///     .width_like("Header")
+    ///     .render("Hi")
+    ///     .unwrap();
+    /// assert_eq!(result.lines().nth(2).unwrap(), "│   Hi       │");
+    /// ```
+    #[must_use]
+    pub fn width_like(mut self, reference: &str) -> Self {
+        self.width_like = Some(crate::text::text_width(reference));
+        self
+    }
+
+    /// Cap the total box width, including margins
+    ///
+    /// Unlike [`width`](Self::width), narrower content keeps its natural width.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use ::boxen::{builder, text::text_width};
+    ///
+    /// let result = builder()
+    ///     .max_width(12)
+    ///     .title("A rather long title")
+    ///     .render("Some wrapped text")
+    ///     .unwrap();
+    /// assert!(result.lines().all(|line| text_width(line) <= 12));
+    /// assert!(result.lines().next().unwrap().ends_with("…┐"));
+    /// ```
+    #[must_use]
+    pub fn max_width(mut self, max_width: usize) -> Self {
+        self.options.max_width = Some(max_width);
+        self
+    }
+
+    /// Wrap text at `columns` even when the box is wider
+    ///
+    /// Combined with a fixed width and centered text this gives a full-width
+    /// card with a comfortable line length.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use ::boxen::{builder, TextAlignment};
+    ///
+    /// let result = builder()
+    ///     .width(20)
+    ///     .content_width(7)
+    ///     .text_alignment(TextAlignment::Center)
+    ///     .render("wrap me there")
+    ///     .unwrap();
+    /// assert!(result.contains("│     wrap me      │"));
+    /// assert!(result.contains("│      there       │"));
+    /// ```
+    #[must_use]
+    pub fn content_width(mut self, columns: usize) -> Self {
+        self.options.content_width = Some(columns);
+        self
+    }
+
+    /// Fill the terminal width minus `reserve` columns
+    ///
+    /// The terminal width is read when the box is rendered, so the same builder
+    /// adapts to the terminal it runs in. Useful for leaving room for other UI.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use ::boxen::{builder, get_terminal_width, text::text_width};
+    ///
+    /// let result = builder().width_fill(4).render("Hello").unwrap();
+    /// let expected = get_terminal_width().saturating_sub(4);
+    /// assert!(result.lines().all(|line| text_width(line) == expected));
+    /// ```
+    #[must_use]
+    pub fn width_fill(self, reserve: usize) -> Self {
+        self.width(Width::from_fn(move |terminal_width| {
+            terminal_width.saturating_sub(reserve)
+        }))
+    }
+
+    /// Fit the box into the columns left of the terminal after `current_column`
+    ///
+    /// For output printed inline after a prompt: the box is left-floated and
+    /// as wide as [`width_fill`](Self::width_fill) with `current_column`
+    /// reserved.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use ::boxen::{builder, get_terminal_width, text::text_width};
+    ///
+    /// let result = builder().fit_remaining(20).render("Hello").unwrap();
+    /// let expected = get_terminal_width().saturating_sub(20);
+    /// assert!(result.lines().all(|line| text_width(line) == expected));
+    /// ```
+    #[must_use]
+    pub fn fit_remaining(self, current_column: usize) -> Self {
+        self.width_fill(current_column).float(Float::Left)
+    }
+
+    /// Set box height (fixed or dynamic)
+    ///
+    /// Accepts either a fixed height value or a closure for dynamic sizing.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use ::boxen::builder;
+    ///
+    /// // Fixed height
+    /// let result = builder().height(20).render("Hello").unwrap();
+    ///
+    /// // Dynamic height: use 50% of available space, minimum 10
+    /// let result = builder()
+    ///     .height(|available: usize| (available / 2).max(10))
+    ///     .render("Hello")
+    ///     .unwrap();
+    /// ```
+    #[must_use]
+    pub fn height<H: Into<Height>>(mut self, height: H) -> Self {
+        self.options.height = Some(height.into());
+        self
+    }
+
+    /// Set border color
+    #[must_use]
+    pub fn border_color<C: Into<Color>>(mut self, color: C) -> Self {
+        self.options.border_color = Some(color.into());
+        self
+    }
+
+    /// Set background color
+    #[must_use]
+    pub fn background_color<C: Into<Color>>(mut self, color: C) -> Self {
+        self.options.background_color = Some(color.into());
+        self
+    }
+
+    /// Set the foreground color of the content text
+    #[must_use]
+    pub fn text_color<C: Into<Color>>(mut self, color: C) -> Self {
+        self.options.text_color = Some(color.into());
+        self
+    }
+
+    /// Enable dim border
+    #[must_use]
+    pub fn dim_border(mut self, dim: bool) -> Self {
+        self.options.dim_border = dim;
+        self
+    }
+
+    /// Enable dim content
+    #[must_use]
+    pub fn dim_content(mut self, dim: bool) -> Self {
+        self.options.dim_content = dim;
+        self
+    }
+
+    /// Shade the padding so the content looks recessed into the box
+    #[must_use]
+    pub fn inset_shadow(mut self, inset: bool) -> Self {
+        self.options.inset_shadow = inset;
+        self
+    }
+
+    /// Always (`true`) or never (`false`) color the box, regardless of the
+    /// target or environment
+    #[must_use]
+    pub fn force_color(mut self, force: bool) -> Self {
+        self.options.force_color = Some(force);
+        self
+    }
+
+    /// Choose how styled spans are closed
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use ::boxen::{ResetMode, builder};
+    ///
+    /// colored::control::set_override(true);
+    /// let result = builder()
+    ///     .border_color("red")
+    ///     .reset_mode(ResetMode::Targeted)
+    ///     .render("Hi")
+    ///     .unwrap();
+    /// assert!(result.starts_with("\x1b[31m┌──┐\x1b[39m"));
+    /// ```
+    #[must_use]
+    pub fn reset_mode(mut self, mode: ResetMode) -> Self {
+        self.options.reset_mode = mode;
+        self
+    }
+
+    /// Choose the separator written between rendered rows
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use ::boxen::{LineEnding, builder};
+    ///
+    /// let result = builder().line_ending(LineEnding::CrLf).render("Hi").unwrap();
+    /// assert_eq!(result, "┌──┐\r\n│Hi│\r\n└──┘");
+    /// ```
+    #[must_use]
+    pub fn line_ending(mut self, ending: LineEnding) -> Self {
+        self.options.line_ending = ending;
+        self
+    }
+
+    /// Mirror the box horizontally for terminals with a right-side origin
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use ::boxen::builder;
+    ///
+    /// let result = builder().padding((0, 0, 0, 2)).mirror(true).render("Hi").unwrap();
+    /// assert_eq!(result, "┐────┌\n│Hi  │\n┘────└");
+    /// ```
+    #[must_use]
+    pub fn mirror(mut self, mirror: bool) -> Self {
+        self.options.mirror = mirror;
+        self
+    }
+
+    /// Dim both the border and the content, for a muted "disabled" look
+    ///
+    /// Shorthand for `.dim_border(true).dim_content(true)`.
+    #[must_use]
+    pub fn dim_all(self) -> Self {
+        self.dim_border(true).dim_content(true)
+    }
+
     /// Set fullscreen mode
     #[must_use]
-    pub fn fullscreen(mut self, mode: FullscreenMode) -> Self {
-        self.options.fullscreen = Some(mode);
+    pub fn fullscreen(mut self, mode: FullscreenMode) -> Self {
+        self.options.fullscreen = Some(mode);
+        self
+    }
+
+    /// Build the final options
+    ///
+    /// Sizing that depends on the text, [`aspect_ratio`](Self::aspect_ratio)
+    /// and [`square`](Self::square), is only applied by [`render`](Self::render)
+    /// and related methods; the returned options leave it out.
+    #[must_use]
+    pub fn build(mut self) -> BoxenOptions {
+        if let Some(pct) = self.margin_pct {
+            self.options = Self::resolve_margin_pct(self.options, pct);
+        }
+        if let Some(columns) = self.width_like {
+            self.options = Self::resolve_width_like(self.options, columns);
+        }
+        self.options
+    }
+
+    /// Build and render box with the given text.
+    ///
+    /// This is the final method in the builder chain that validates the configuration
+    /// and renders the box. All validation is performed at this stage.
+    ///
+    /// # Arguments
+    ///
+    /// * `text` - The text content to render in the box
+    ///
+    /// # Returns
+    ///
+    /// Returns `Result<String, BoxenError>` with the rendered box or detailed error information.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use ::boxen::{builder, BorderStyle};
+    ///
+    /// let result = builder()
+    ///     .border_style(BorderStyle::Round)
+    ///     .padding(1)
+    ///     .render("Hello, World!")
+    ///     .unwrap();
+    ///
+    /// println!("{}", result);
+    /// ```
+    ///
+    /// # Error Handling
+    ///
+    /// ```rust
+    /// use ::boxen::builder;
+    ///
+    /// let result = builder()
+    ///     .width(5)     // Too small
+    ///     .padding(10)  // Too large
+    ///     .render("Hello");
+    ///
+    /// match result {
+    ///     Ok(output) => println!("{}", output),
+    ///     Err(e) => {
+    ///         println!("Error: {}", e);
+    ///         for rec in e.recommendations() {
+    ///             println!("Try: {}", rec.suggestion);
+    ///         }
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns `BoxenError::InputValidationError` if:
+    /// - Text or configuration parameters fail validation (see `boxen` function for details)
+    ///
+    /// Returns `BoxenError::InvalidDimensions` if:
+    /// - Configuration constraints cannot be satisfied
+    ///
+    /// Returns `BoxenError::ConfigurationError` if:
+    /// - Configuration validation fails
+    ///
+    /// Returns errors from the underlying `boxen` function for rendering failures.
+    pub fn render<S: AsRef<str>>(self, text: S) -> BoxenResult<String> {
+        let transformed = self.transform_text(text.as_ref());
+        let text_ref = transformed.as_deref().unwrap_or(text.as_ref());
+        let options = self.resolve_options(text_ref)?;
+        crate::boxen(text_ref, Some(options))
+    }
+
+    /// Number of columns the content of `text` will be wrapped to
+    ///
+    /// This is the content width left once the width, `max_width`,
+    /// `content_width`, percentage margins, borders, padding and line affixes
+    /// are resolved, exactly as [`render`](Self::render) would. Without
+    /// wrapping it is the width long lines are clipped to. With an inner
+    /// border it is the width of the area holding the nested frame.
+    ///
+    /// # Errors
+    ///
+    /// Returns the same errors as [`render`](Self::render).
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use ::boxen::builder;
+    ///
+    /// let builder = builder().width(20).padding(1);
+    /// // 20 columns minus 2 for the border and 6 for the padding
+    /// assert_eq!(builder.content_width_for("Hello").unwrap(), 12);
+    /// ```
+    pub fn content_width_for(&self, text: &str) -> BoxenResult<usize> {
+        let transformed = self.transform_text(text);
+        let text = transformed.as_deref().unwrap_or(text);
+        let options = self.resolve_options(text)?;
+        crate::render::content_wrap_width(text, options)
+    }
+
+    /// The text after the builder's transform, if one is set
+    fn transform_text(&self, text: &str) -> Option<String> {
+        self.transform.as_ref().map(|transform| transform(text))
+    }
+
+    /// The options [`render`](Self::render) draws `text` with, after
+    /// applying the text-dependent settings and validating them
+    fn resolve_options(&self, text: &str) -> BoxenResult<BoxenOptions> {
+        crate::error::validation::validate_input_size(text, &self.options)?;
+
+        let mut options = self.options.clone();
+        if let Some(pct) = self.margin_pct {
+            options = Self::resolve_margin_pct(options, pct);
+        }
+        if let Some(columns) = self.width_like {
+            options = Self::resolve_width_like(options, columns);
+        }
+        if let Some(ratio) = self.aspect_ratio {
+            options = Self::fit_aspect_ratio(text, options, ratio)?;
+        }
+        if self.square {
+            options = Self::fit_square(text, options)?;
+        }
+
+        // Comprehensive input validation
+        crate::error::validation::validate_all_options(text, &options)?;
+
+        // Validate configuration constraints
+        options.validate_constraints()?;
+
+        Ok(options)
+    }
+
+    /// Fix the total width so the content area is `columns` wide
+    fn resolve_width_like(mut options: BoxenOptions, columns: usize) -> BoxenOptions {
+        let border_width =
+            calculate_border_width_with_thickness(&options.border_style, options.border_thickness);
+        options.width = Some(Width::Fixed(
+            columns + border_width + options.padding.horizontal() + options.margin.horizontal(),
+        ));
+        options
+    }
+
+    /// Turn `[top, right, bottom, left]` terminal percentages into margin cells
+    fn resolve_margin_pct(mut options: BoxenOptions, pct: [u8; 4]) -> BoxenOptions {
+        let [top, right, bottom, left] = pct.map(|value| usize::from(value.min(100)));
+        let terminal_width = get_terminal_width();
+        let terminal_height = get_terminal_height().unwrap_or(0);
+
+        // Shrink a pair of margins proportionally until it fits in `available`
+        let fit = |first: usize, second: usize, available: usize| {
+            let total = first + second;
+            if total <= available {
+                (first, second)
+            } else {
+                (first * available / total, second * available / total)
+            }
+        };
+
+        let border_width =
+            calculate_border_width_with_thickness(&options.border_style, options.border_thickness);
+        let (left, right) = fit(
+            terminal_width * left / 100,
+            terminal_width * right / 100,
+            terminal_width.saturating_sub(border_width + options.padding.horizontal() + 1),
+        );
+        let border_height = if options.border_style.is_visible() {
+            2
+        } else {
+            0
+        };
+        let (top, bottom) = fit(
+            terminal_height * top / 100,
+            terminal_height * bottom / 100,
+            terminal_height.saturating_sub(border_height + options.padding.vertical() + 1),
+        );
+
+        options.margin = Spacing {
+            top,
+            right,
+            bottom,
+            left,
+        };
+        options
+    }
+
+    /// Size `options` so the box for `text` approximates `ratio` columns per row
+    fn fit_aspect_ratio(
+        text: &str,
+        mut options: BoxenOptions,
+        ratio: f32,
+    ) -> BoxenResult<BoxenOptions> {
+        if !ratio.is_finite() || ratio <= 0.0 {
+            return Err(BoxenError::input_validation_error(
+                format!("Aspect ratio {ratio} must be a positive number"),
+                "aspect_ratio".to_string(),
+                ratio.to_string(),
+                vec![crate::error::ErrorRecommendation::suggestion_only(
+                    "Invalid aspect ratio".to_string(),
+                    "Use a ratio of columns to rows such as 3.0".to_string(),
+                )],
+            ));
+        }
+
+        let width = crate::validation::width_for_aspect_ratio(text, &options, ratio);
+        options.width = Some(Width::Fixed(width));
+        options.height = None;
+
+        // Grow the height towards the ratio, never below the natural height and
+        // never past the terminal
+        let margins = options.margin.vertical();
+        let natural_rows = crate::validation::height_at_width(text, &options, width)? - margins;
+        let box_width = width - options.margin.horizontal();
+        let target_rows = (box_width as f32 / ratio).round() as usize;
+        let max_rows = crate::terminal::get_terminal_height()
+            .map_or(usize::MAX, |rows| rows.saturating_sub(margins));
+        let rows = target_rows.min(max_rows);
+        if rows > natural_rows {
+            options.height = Some(Height::Fixed(rows + margins));
+        }
+
+        Ok(options)
+    }
+
+    /// Pad `options` so the box for `text` is as many cells wide as it is tall
+    fn fit_square(text: &str, mut options: BoxenOptions) -> BoxenResult<BoxenOptions> {
+        // Measure the frame alone, without anything drawn around it
+        let mut probe = options.clone();
+        probe.margin = Spacing::default();
+        probe.float = Float::Left;
+        probe.tile_horizontal = false;
+        probe.caption = None;
+        if probe.title_position == TitlePosition::Above {
+            probe.title = None;
+        }
+        let rendered = crate::boxen(text, Some(probe))?;
+        let height = rendered.lines().count();
+        let width = rendered
+            .lines()
+            .map(crate::text::text_width)
+            .max()
+            .unwrap_or(0);
+
+        if width > height {
+            let extra = width - height;
+            if options.height.is_some() {
+                options.height = Some(Height::Fixed(width + options.margin.vertical()));
+            } else {
+                options.padding.top += extra / 2;
+                options.padding.bottom += extra - extra / 2;
+            }
+        } else if height > width {
+            // Widening through padding keeps the wrap width, and so the height
+            let extra = height - width;
+            options.padding.left += extra / 2;
+            options.padding.right += extra - extra / 2;
+            if options.width.is_some() {
+                options.width = Some(Width::Fixed(height + options.margin.horizontal()));
+            }
+        }
+
+        Ok(options)
+    }
+
+    /// Validate the current builder configuration without building
+    ///
+    /// # Errors
+    ///
+    /// Returns `BoxenError::InvalidDimensions` if:
+    /// - Width or height constraints cannot be satisfied
+    /// - Dimensions are too small for borders and padding
+    ///
+    /// Returns `BoxenError::TerminalSizeError` if:
+    /// - Terminal size cannot be detected when required
+    ///
+    /// Returns `BoxenError::ConfigurationError` if:
+    /// - Configuration options conflict with each other
+    #[must_use = "validation result should be checked"]
+    pub fn validate(&self) -> BoxenResult<()> {
+        self.options.validate_constraints()
+    }
+
+    /// Validate configuration with intelligent recommendations
+    #[must_use]
+    pub fn validate_with_suggestions(&self, text: &str) -> crate::validation::ValidationResult {
+        crate::validation::validate_configuration(text, &self.options)
+    }
+
+    /// Calculate minimum dimensions required for the given text
+    #[must_use]
+    pub fn minimum_dimensions(&self, text: &str) -> crate::validation::MinimumDimensions {
+        crate::validation::calculate_minimum_dimensions(text, &self.options)
+    }
+
+    /// Suggest optimal dimensions for the given text
+    #[must_use]
+    pub fn suggest_dimensions(&self, text: &str) -> (usize, usize) {
+        crate::validation::suggest_optimal_dimensions(text, &self.options)
+    }
+
+    /// Auto-adjust configuration to fix common issues
+    #[must_use]
+    pub fn auto_adjust(mut self, text: &str) -> Self {
+        self.options = crate::validation::auto_adjust_options(text, self.options);
+        self
+    }
+
+    /// Render with auto-adjustment if the current configuration fails
+    ///
+    /// # Errors
+    ///
+    /// Returns errors from the underlying `render` function if auto-adjustment cannot fix the configuration.
+    /// This method attempts smart recovery before failing, so errors indicate unrecoverable issues.
+    pub fn render_or_adjust<S: AsRef<str>>(mut self, text: S) -> BoxenResult<String> {
+        // Transform up front so validation and recovery see the final text
+        let transformed = self
+            .transform
+            .take()
+            .map(|transform| transform(text.as_ref()));
+        let text_ref = transformed.as_deref().unwrap_or(text.as_ref());
+
+        // Try comprehensive validation first
+        if let Ok(()) = crate::error::validation::validate_all_options(text_ref, &self.options) {
+            // Input validation passed, try configuration validation
+            let validation = crate::validation::validate_configuration(text_ref, &self.options);
+            if validation.is_valid {
+                // Configuration is valid, proceed with normal render
+                self.render(text_ref)
+            } else {
+                // Auto-adjust and try again
+                self.options = crate::validation::recovery::smart_recovery(text_ref, self.options);
+                self.render(text_ref)
+            }
+        } else {
+            // Input validation failed, try smart recovery
+            self.options = crate::validation::recovery::smart_recovery(text_ref, self.options);
+            self.render(text_ref)
+        }
+    }
+
+    /// Get detailed validation information with recommendations
+    #[must_use]
+    pub fn check_configuration(&self, text: &str) -> String {
+        use std::fmt::Write;
+        let validation = self.validate_with_suggestions(text);
+        let min_dims = self.minimum_dimensions(text);
+        let (opt_width, opt_height) = self.suggest_dimensions(text);
+
+        let mut message = format!("Configuration Analysis for text: {text:?}\n");
+        let _ = writeln!(
+            message,
+            "Minimum required: {}×{}",
+            min_dims.width, min_dims.height
+        );
+        let _ = writeln!(message, "Suggested optimal: {opt_width}×{opt_height}");
+
+        if validation.is_valid {
+            message.push_str("✓ Configuration is valid\n");
+        } else {
+            message.push_str("✗ Configuration has errors:\n");
+            for error in &validation.errors {
+                let _ = writeln!(message, "  - {}", error.detailed_message());
+            }
+        }
+
+        if !validation.warnings.is_empty() {
+            message.push_str("⚠ Warnings:\n");
+            for warning in &validation.warnings {
+                let _ = writeln!(message, "  - {}: {}", warning.issue, warning.suggestion);
+            }
+        }
+
+        message
+    }
+
+    /// Convenience method to set both padding and margin to the same value
+    #[must_use]
+    pub fn spacing<T: Into<Spacing>>(mut self, spacing: T) -> Self {
+        let spacing_value = spacing.into();
+        self.options.padding = spacing_value;
+        self.options.margin = spacing_value;
+        self.margin_pct = None;
+        self
+    }
+
+    /// Convenience method to set both border and background color
+    #[must_use]
+    pub fn colors<C1: Into<Color>, C2: Into<Color>>(mut self, border: C1, background: C2) -> Self {
+        self.options.border_color = Some(border.into());
+        self.options.background_color = Some(background.into());
+        self
+    }
+
+    /// Color the border and content area with `base`, and the text with
+    /// whichever of black or white contrasts best with it
+    ///
+    /// The text color is chosen from the relative luminance of `base` (see
+    /// [`crate::color::contrasting_color`]). When `base` cannot be resolved
+    /// to RGB, such as `Color::Auto` or an unknown name, only the border
+    /// color is set.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use ::boxen::{Color, builder};
+    ///
+    /// let options = builder().auto_contrast(Color::Hex("#1e3a8a".to_string())).build();
+    /// assert_eq!(options.text_color, Some(Color::Named("white".to_string())));
+    /// assert_eq!(options.background_color, options.border_color);
+    /// ```
+    #[must_use]
+    pub fn auto_contrast<C: Into<Color>>(mut self, base: C) -> Self {
+        let base = base.into();
+        if let Ok(text) = crate::color::contrasting_color(&base) {
+            self.options.text_color = Some(text);
+            self.options.background_color = Some(base.clone());
+        }
+        self.options.border_color = Some(base);
         self
     }
 
-    /// Build the final options
+    /// Convenience method to set both width and height
     #[must_use]
-    pub fn build(self) -> BoxenOptions {
-        self.options
+    pub fn size(mut self, width: usize, height: usize) -> Self {
+        self.options.width = Some(Width::Fixed(width));
+        self.options.height = Some(Height::Fixed(height));
+        self
     }
 
-    /// Build and render box with the given text.
+    /// Convenience method to center align both text and title
+    #[must_use]
+    pub fn center_all(mut self) -> Self {
+        self.options.text_alignment = TextAlignment::Center;
+        self.options.title_alignment = TitleAlignment::Center;
+        self.options.float = Float::Center;
+        self
+    }
+
+    /// Align both the text and the title the same way, leaving float unchanged
     ///
-    /// This is the final method in the builder chain that validates the configuration
-    /// and renders the box. All validation is performed at this stage.
+    /// # Examples
     ///
-    /// # Arguments
+    /// ```rust
+    /// use ::boxen::{TextAlignment, builder};
     ///
-    /// * `text` - The text content to render in the box
+    /// let result = builder()
+    ///     .align(TextAlignment::Right)
+    ///     .title("T")
+    ///     .width(8)
+    ///     .render("ab")
+    ///     .unwrap();
+    /// assert_eq!(result, "┌─────T┐\n│    ab│\n└──────┘");
+    /// ```
+    #[must_use]
+    pub fn align(mut self, alignment: TextAlignment) -> Self {
+        self.options.text_alignment = alignment;
+        self.options.title_alignment = match alignment {
+            TextAlignment::Left => TitleAlignment::Left,
+            TextAlignment::Center => TitleAlignment::Center,
+            TextAlignment::Right => TitleAlignment::Right,
+        };
+        self
+    }
+
+    /// Center the box horizontally in the terminal
     ///
-    /// # Returns
+    /// Sets `Float::Center` and clears the left and right margins so the
+    /// position depends only on the terminal width. Vertical margins and text
+    /// alignment are left as they are.
+    #[must_use]
+    pub fn center_horizontal(mut self) -> Self {
+        self.options.float = Float::Center;
+        self.options.margin.left = 0;
+        self.options.margin.right = 0;
+        if let Some([_, right, _, left]) = &mut self.margin_pct {
+            (*right, *left) = (0, 0);
+        }
+        self
+    }
+
+    /// Size the box to approximate a width-to-height ratio, in terminal cells
     ///
-    /// Returns `Result<String, BoxenError>` with the rendered box or detailed error information.
+    /// The ratio depends on the text, so it is applied by [`render`](Self::render):
+    /// a wrap width is chosen with
+    /// [`width_for_aspect_ratio`](crate::validation::width_for_aspect_ratio) and
+    /// the height is then grown towards the ratio as far as the terminal allows.
+    /// Any width or height set on the builder is replaced. [`build`](Self::build)
+    /// returns the options without this sizing.
     ///
     /// # Examples
     ///
     /// ```rust
-    /// use ::boxen::{builder, BorderStyle};
+    /// use ::boxen::builder;
     ///
-    /// let result = builder()
-    ///     .border_style(BorderStyle::Round)
-    ///     .padding(1)
-    ///     .render("Hello, World!")
+    /// let card = builder()
+    ///     .aspect_ratio(4.0)
+    ///     .render("Uniform cards keep the same shape whatever their text")
     ///     .unwrap();
+    /// let height = card.lines().count();
+    /// let width = ::boxen::text::text_width(card.lines().next().unwrap());
+    /// assert!((width as f32 / height as f32 - 4.0).abs() < 1.0);
+    /// ```
+    #[must_use]
+    pub fn aspect_ratio(mut self, ratio: f32) -> Self {
+        self.aspect_ratio = Some(ratio);
+        self
+    }
+
+    /// Make the box exactly as many cells wide as it is tall
     ///
-    /// println!("{}", result);
+    /// Applied by [`render`](Self::render): the box is measured at its natural
+    /// size, then padded vertically or horizontally (or its fixed width or
+    /// height raised) until the two match. Margins, a title above the box, and
+    /// the caption are not counted. Terminal cells are usually about twice as
+    /// tall as they are wide, so a box that is square in cells looks like a
+    /// tall rectangle on screen.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use ::boxen::builder;
+    ///
+    /// let icon = builder().square().render("OK").unwrap();
+    /// assert_eq!(icon, "┌──┐\n│OK│\n│  │\n└──┘");
     /// ```
+    #[must_use]
+    pub fn square(mut self) -> Self {
+        self.square = true;
+        self
+    }
+
+    /// Rewrite the text before it is processed, e.g. for variable substitution
     ///
-    /// # Error Handling
+    /// The closure runs once in [`render`](Self::render) ahead of wrapping,
+    /// alignment and any sizing that depends on the text. A later call replaces
+    /// the earlier transform. It is not part of the [`BoxenOptions`] returned by
+    /// [`build`](Self::build).
+    ///
+    /// # Examples
     ///
     /// ```rust
     /// use ::boxen::builder;
     ///
     /// let result = builder()
-    ///     .width(5)     // Too small
-    ///     .padding(10)  // Too large
-    ///     .render("Hello");
+    ///     .transform(|text| text.replace("$USER", "ada"))
+    ///     .render("Hello, $USER")
+    ///     .unwrap();
+    /// assert!(result.contains("Hello, ada"));
+    /// ```
+    #[must_use]
+    pub fn transform(mut self, f: impl Fn(&str) -> String + Send + Sync + 'static) -> Self {
+        self.transform = Some(Box::new(f));
+        self
+    }
+
+    /// Set a caption printed below the box, outside the border and margins
     ///
-    /// match result {
-    ///     Ok(output) => println!("{}", output),
-    ///     Err(e) => {
-    ///         println!("Error: {}", e);
-    ///         for rec in e.recommendations() {
-    ///             println!("Try: {}", rec.suggestion);
-    ///         }
-    ///     }
-    /// }
+    /// The caption is aligned within the box width using `caption_alignment`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use ::boxen::builder;
+    ///
+    /// let result = builder().caption("Figure 1").render("Diagram").unwrap();
+    /// assert_eq!(result.lines().last(), Some("Figure 1 "));
     /// ```
+    #[must_use]
+    pub fn caption<S: Into<String>>(mut self, caption: S) -> Self {
+        self.options.caption = Some(caption.into());
+        self
+    }
+
+    /// Align the title and the caption the same way
     ///
-    /// # Errors
+    /// Sets both `title_alignment` and `caption_alignment`, for a title above
+    /// and a caption below that mirror each other.
     ///
-    /// Returns `BoxenError::InputValidationError` if:
-    /// - Text or configuration parameters fail validation (see `boxen` function for details)
+    /// # Examples
     ///
-    /// Returns `BoxenError::InvalidDimensions` if:
-    /// - Configuration constraints cannot be satisfied
+    /// ```rust
+    /// use ::boxen::{builder, TitleAlignment};
     ///
-    /// Returns `BoxenError::ConfigurationError` if:
-    /// - Configuration validation fails
+    /// let result = builder()
+    ///     .title("Top")
+    ///     .caption("Bottom")
+    ///     .caption_alignment(TitleAlignment::Right)
+    ///     .width(12)
+    ///     .render("Body")
+    ///     .unwrap();
+    /// assert!(result.starts_with("┌───────Top┐"));
+    /// assert_eq!(result.lines().last(), Some("      Bottom"));
+    /// ```
+    #[must_use]
+    pub fn caption_alignment(mut self, alignment: TitleAlignment) -> Self {
+        self.options.title_alignment = alignment;
+        self.options.caption_alignment = alignment;
+        self
+    }
+
+    /// Repeat the box across the full terminal width, e.g. for separators
     ///
-    /// Returns errors from the underlying `boxen` function for rendering failures.
-    pub fn render<S: AsRef<str>>(self, text: S) -> BoxenResult<String> {
-        let text_ref = text.as_ref();
+    /// The box is rendered once and each line is tiled, with the final partial
+    /// copy trimmed at a display-cell boundary.
+    #[must_use]
+    pub fn tile_horizontal(mut self, tile: bool) -> Self {
+        self.options.tile_horizontal = tile;
+        self
+    }
 
-        // Comprehensive input validation
-        crate::error::validation::validate_all_options(text_ref, &self.options)?;
+    /// Allow boxes larger than the terminal, e.g. when writing to a file
+    ///
+    /// Explicit widths and heights are still honored; only the comparison
+    /// against the detected terminal size is skipped.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use ::boxen::builder;
+    ///
+    /// let wide = builder()
+    ///     .width(500)
+    ///     .ignore_terminal_bounds(true)
+    ///     .render("Report")
+    ///     .unwrap();
+    /// assert_eq!(wide.lines().next().unwrap().chars().count(), 500);
+    /// ```
+    #[must_use]
+    pub fn ignore_terminal_bounds(mut self, ignore: bool) -> Self {
+        self.options.ignore_terminal_bounds = ignore;
+        self
+    }
 
-        // Validate configuration constraints
-        self.options.validate_constraints()?;
+    /// Narrow boxes that would overflow the terminal instead of failing
+    ///
+    /// Unlike [`auto_adjust`](Self::auto_adjust), this happens silently while
+    /// rendering; the content is re-wrapped to the narrower width.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use ::boxen::{builder, get_terminal_width, text::text_width};
+    ///
+    /// let result = builder()
+    ///     .width(get_terminal_width() + 40)
+    ///     .clamp_to_terminal(true)
+    ///     .render("Fits anyway")
+    ///     .unwrap();
+    /// assert!(result.lines().all(|line| text_width(line) <= get_terminal_width()));
+    /// ```
+    #[must_use]
+    pub fn clamp_to_terminal(mut self, clamp: bool) -> Self {
+        self.options.clamp_to_terminal = clamp;
+        self
+    }
 
-        crate::boxen(text_ref, Some(self.options))
+    /// Drop margins on the sides where the box touches the terminal edge
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use ::boxen::builder;
+    ///
+    /// let result = builder()
+    ///     .margin(1)
+    ///     .collapse_margin_to_edge(true)
+    ///     .render("Hi")
+    ///     .unwrap();
+    /// assert!(result.starts_with("┌──┐"));
+    /// ```
+    #[must_use]
+    pub fn collapse_margin_to_edge(mut self, collapse: bool) -> Self {
+        self.options.collapse_margin_to_edge = collapse;
+        self
     }
 
-    /// Validate the current builder configuration without building
+    /// Reject input text longer than `bytes`
     ///
-    /// # Errors
+    /// # Examples
     ///
-    /// Returns `BoxenError::InvalidDimensions` if:
-    /// - Width or height constraints cannot be satisfied
-    /// - Dimensions are too small for borders and padding
+    /// ```rust
+    /// use ::boxen::{BoxenError, builder};
     ///
-    /// Returns `BoxenError::TerminalSizeError` if:
-    /// - Terminal size cannot be detected when required
+    /// let result = builder().max_input_bytes(16).render("x".repeat(100));
+    /// assert!(matches!(result, Err(BoxenError::ConfigurationError { .. })));
+    /// ```
+    #[must_use]
+    pub fn max_input_bytes(mut self, bytes: usize) -> Self {
+        self.options.max_input_bytes = Some(bytes);
+        self
+    }
+
+    /// Reject boxes whose total width times total height exceeds `cells`
+    #[must_use]
+    pub fn max_total_cells(mut self, cells: usize) -> Self {
+        self.options.max_total_cells = Some(cells);
+        self
+    }
+
+    /// Reject empty or whitespace-only input instead of rendering an empty box
     ///
-    /// Returns `BoxenError::ConfigurationError` if:
-    /// - Configuration options conflict with each other
-    #[must_use = "validation result should be checked"]
-    pub fn validate(&self) -> BoxenResult<()> {
-        self.options.validate_constraints()
+    /// # Examples
+    ///
+    /// ```rust
+    /// use ::boxen::{BoxenError, builder};
+    ///
+    /// let result = builder().error_on_empty(true).render("  \n ");
+    /// assert!(matches!(result, Err(BoxenError::ConfigurationError { .. })));
+    /// ```
+    #[must_use]
+    pub fn error_on_empty(mut self, error: bool) -> Self {
+        self.options.error_on_empty = error;
+        self
     }
 
-    /// Validate configuration with intelligent recommendations
+    /// Keep blank lines at the start and end of the text, e.g. for poetry
+    /// or preformatted blocks
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use ::boxen::builder;
+    ///
+    /// let result = builder()
+    ///     .preserve_blank_edges(true)
+    ///     .render("\nHello\n")
+    ///     .unwrap();
+    /// assert_eq!(result.lines().count(), 5);
+    /// ```
     #[must_use]
-    pub fn validate_with_suggestions(&self, text: &str) -> crate::validation::ValidationResult {
-        crate::validation::validate_configuration(text, &self.options)
+    pub fn preserve_blank_edges(mut self, preserve: bool) -> Self {
+        self.options.preserve_blank_edges = preserve;
+        self
     }
 
-    /// Calculate minimum dimensions required for the given text
+    /// Squeeze runs of spaces and tabs inside each line to a single space,
+    /// e.g. for messy input copied from other tools
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use ::boxen::builder;
+    ///
+    /// let result = builder()
+    ///     .collapse_whitespace(true)
+    ///     .render("a  \t  b")
+    ///     .unwrap();
+    /// assert!(result.contains("│a b│"));
+    /// ```
     #[must_use]
-    pub fn minimum_dimensions(&self, text: &str) -> crate::validation::MinimumDimensions {
-        crate::validation::calculate_minimum_dimensions(text, &self.options)
+    pub fn collapse_whitespace(mut self, collapse: bool) -> Self {
+        self.options.collapse_whitespace = collapse;
+        self
     }
 
-    /// Suggest optimal dimensions for the given text
+    /// Expand tabs in the content, title, and caption to stops every `width` columns
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use ::boxen::builder;
+    ///
+    /// let result = builder().tab_width(4).title("a\tb").render("x\ty").unwrap();
+    /// assert_eq!(result, "┌a   b┐\n│x   y│\n└─────┘");
+    /// ```
     #[must_use]
-    pub fn suggest_dimensions(&self, text: &str) -> (usize, usize) {
-        crate::validation::suggest_optimal_dimensions(text, &self.options)
+    pub fn tab_width(mut self, width: usize) -> Self {
+        self.options.tab_width = Some(width);
+        self
     }
 
-    /// Auto-adjust configuration to fix common issues
+    /// Align tab-separated content into columns
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use ::boxen::builder;
+    ///
+    /// let result = builder().columns(true).render("name\tboxen\nversion\t1").unwrap();
+    /// assert_eq!(result, "┌──────────────┐\n│name     boxen│\n│version  1    │\n└──────────────┘");
+    /// ```
+    #[must_use]
+    pub fn columns(mut self, columns: bool) -> Self {
+        self.options.columns = columns;
+        self
+    }
+
+    /// Choose how vertical tabs and form feeds in the content are handled
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use ::boxen::{VerticalWhitespace, builder};
+    ///
+    /// let result = builder()
+    ///     .vertical_whitespace(VerticalWhitespace::Strip)
+    ///     .render("page\x0cbreak")
+    ///     .unwrap();
+    /// assert!(result.contains("│pagebreak│"));
+    /// ```
+    #[must_use]
+    pub fn vertical_whitespace(mut self, mode: VerticalWhitespace) -> Self {
+        self.options.vertical_whitespace = mode;
+        self
+    }
+
+    /// Normalize the content to a Unicode normalization form before measuring
+    ///
+    /// Useful when input may mix precomposed and decomposed characters, which
+    /// otherwise wrap and align differently.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use ::boxen::{builder, NormalizationForm};
+    ///
+    /// let result = builder()
+    ///     .normalize(NormalizationForm::Nfc)
+    ///     .render("cafe\u{0301}")
+    ///     .unwrap();
+    /// assert!(result.contains("café"));
+    /// ```
     #[must_use]
-    pub fn auto_adjust(mut self, text: &str) -> Self {
-        self.options = crate::validation::auto_adjust_options(text, self.options);
+    pub fn normalize(mut self, form: NormalizationForm) -> Self {
+        self.options.normalize = Some(form);
         self
     }
 
-    /// Render with auto-adjustment if the current configuration fails
+    /// Show a scrollbar thumb on the right border for a scrollable viewport
     ///
-    /// # Errors
+    /// # Examples
     ///
-    /// Returns errors from the underlying `render` function if auto-adjustment cannot fix the configuration.
-    /// This method attempts smart recovery before failing, so errors indicate unrecoverable issues.
-    pub fn render_or_adjust<S: AsRef<str>>(mut self, text: S) -> BoxenResult<String> {
-        let text_ref = text.as_ref();
-
-        // Try comprehensive validation first
-        if let Ok(()) = crate::error::validation::validate_all_options(text_ref, &self.options) {
-            // Input validation passed, try configuration validation
-            let validation = crate::validation::validate_configuration(text_ref, &self.options);
-            if validation.is_valid {
-                // Configuration is valid, proceed with normal render
-                self.render(text_ref)
-            } else {
-                // Auto-adjust and try again
-                self.options = crate::validation::recovery::smart_recovery(text_ref, self.options);
-                self.render(text_ref)
-            }
-        } else {
-            // Input validation failed, try smart recovery
-            self.options = crate::validation::recovery::smart_recovery(text_ref, self.options);
-            self.render(text_ref)
-        }
+    /// ```rust
+    /// use ::boxen::builder;
+    ///
+    /// // Lines 0-2 of a 10-line document
+    /// let result = builder()
+    ///     .scrollbar(0, 10, 3)
+    ///     .render("one\ntwo\nthree")
+    ///     .unwrap();
+    /// assert!(result.lines().nth(1).unwrap().ends_with('█'));
+    /// ```
+    #[must_use]
+    pub fn scrollbar(mut self, offset: usize, total: usize, visible: usize) -> Self {
+        self.options.scrollbar = Some(ScrollbarState::new(offset, total, visible));
+        self
     }
 
-    /// Get detailed validation information with recommendations
+    /// Draw a callout pointer on the top or bottom border
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use ::boxen::{builder, Edge};
+    ///
+    /// let result = builder()
+    ///     .pointer(Edge::Bottom, 2)
+    ///     .render("Hello")
+    ///     .unwrap();
+    /// assert!(result.ends_with("└──▼──┘"));
+    /// ```
     #[must_use]
-    pub fn check_configuration(&self, text: &str) -> String {
-        use std::fmt::Write;
-        let validation = self.validate_with_suggestions(text);
-        let min_dims = self.minimum_dimensions(text);
-        let (opt_width, opt_height) = self.suggest_dimensions(text);
-
-        let mut message = format!("Configuration Analysis for text: {text:?}\n");
-        let _ = writeln!(
-            message,
-            "Minimum required: {}×{}",
-            min_dims.width, min_dims.height
-        );
-        let _ = writeln!(message, "Suggested optimal: {opt_width}×{opt_height}");
-
-        if validation.is_valid {
-            message.push_str("✓ Configuration is valid\n");
-        } else {
-            message.push_str("✗ Configuration has errors:\n");
-            for error in &validation.errors {
-                let _ = writeln!(message, "  - {}", error.detailed_message());
-            }
-        }
-
-        if !validation.warnings.is_empty() {
-            message.push_str("⚠ Warnings:\n");
-            for warning in &validation.warnings {
-                let _ = writeln!(message, "  - {}: {}", warning.issue, warning.suggestion);
-            }
-        }
-
-        message
+    pub fn pointer(mut self, edge: Edge, offset: usize) -> Self {
+        self.options.pointer = Some(Pointer::new(edge, offset));
+        self
     }
 
-    /// Convenience method to set both padding and margin to the same value
+    /// Mark the middle of the bottom border with `indicator` when the height
+    /// cuts off part of the content
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use ::boxen::builder;
+    ///
+    /// let result = builder()
+    ///     .height(3)
+    ///     .truncation_indicator('▼')
+    ///     .render("one\ntwo\nthree")
+    ///     .unwrap();
+    /// assert_eq!(result, "┌─────┐\n│one  │\n└──▼──┘");
+    /// ```
     #[must_use]
-    pub fn spacing<T: Into<Spacing>>(mut self, spacing: T) -> Self {
-        let spacing_value = spacing.into();
-        self.options.padding = spacing_value;
-        self.options.margin = spacing_value;
+    pub fn truncation_indicator(mut self, indicator: char) -> Self {
+        self.options.truncation_indicator = Some(indicator);
         self
     }
 
-    /// Convenience method to set both border and background color
+    /// Set text placed before every content line
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use ::boxen::builder;
+    ///
+    /// let result = builder().line_prefix("> ").render("Quoted").unwrap();
+    /// assert!(result.contains("│> Quoted│"));
+    /// ```
     #[must_use]
-    pub fn colors<C1: Into<Color>, C2: Into<Color>>(mut self, border: C1, background: C2) -> Self {
-        self.options.border_color = Some(border.into());
-        self.options.background_color = Some(background.into());
+    pub fn line_prefix<S: Into<String>>(mut self, prefix: S) -> Self {
+        self.options.line_prefix = Some(prefix.into());
         self
     }
 
-    /// Convenience method to set both width and height
+    /// Set text placed after every content line
     #[must_use]
-    pub fn size(mut self, width: usize, height: usize) -> Self {
-        self.options.width = Some(Width::Fixed(width));
-        self.options.height = Some(Height::Fixed(height));
+    pub fn line_suffix<S: Into<String>>(mut self, suffix: S) -> Self {
+        self.options.line_suffix = Some(suffix.into());
         self
     }
 
-    /// Convenience method to center align both text and title
+    /// Convenience method for terminals and logs that cannot render Unicode
+    ///
+    /// Switches to `BorderStyle::Classic` and downgrades non-ASCII content and
+    /// title characters: `…` becomes `...`, smart quotes and accented letters are
+    /// transliterated, and anything else (including wide glyphs) becomes `?`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use ::boxen::builder;
+    ///
+    /// let result = builder().ascii().render("Café → 日本…").unwrap();
+    /// assert!(result.is_ascii());
+    /// ```
     #[must_use]
-    pub fn center_all(mut self) -> Self {
-        self.options.text_alignment = TextAlignment::Center;
-        self.options.title_alignment = TitleAlignment::Center;
-        self.options.float = Float::Center;
+    pub fn ascii(mut self) -> Self {
+        self.options.border_style = BorderStyle::Classic;
+        self.options.ascii_only = true;
         self
     }
+
+    /// Names accepted by [`preset`](Self::preset)
+    pub const PRESETS: [&'static str; 5] = ["info", "warning", "error", "success", "banner"];
+
+    /// Apply a named style preset, for configuration files and CLI flags
+    ///
+    /// | Preset    | Style                                               |
+    /// | --------- | --------------------------------------------------- |
+    /// | `info`    | Blue round border, padding 1                        |
+    /// | `warning` | Yellow single border, padding 1                     |
+    /// | `error`   | Red double border, padding 1                        |
+    /// | `success` | Green round border, padding 1                       |
+    /// | `banner`  | Double border, centered text and title, padding 3×1 |
+    ///
+    /// Padding is given as horizontal×vertical. Only the options listed are
+    /// changed, so later builder calls can still override them. Names are matched case-insensitively.
+    ///
+    /// # Errors
+    ///
+    /// Returns `BoxenError::InputValidationError` for an unknown preset name,
+    /// with a recommendation listing the valid names.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use ::boxen::builder;
+    ///
+    /// let result = builder()
+    ///     .preset("warning")
+    ///     .unwrap()
+    ///     .title("Disk space")
+    ///     .render("Less than 1 GB left")
+    ///     .unwrap();
+    /// assert!(result.contains("Less than 1 GB left"));
+    ///
+    /// assert!(builder().preset("fancy").is_err());
+    /// ```
+    pub fn preset(self, name: &str) -> BoxenResult<Self> {
+        Ok(match name.to_ascii_lowercase().as_str() {
+            "info" => self
+                .border_style(BorderStyle::Round)
+                .border_color("blue")
+                .padding(1),
+            "warning" => self
+                .border_style(BorderStyle::Single)
+                .border_color("yellow")
+                .padding(1),
+            "error" => self
+                .border_style(BorderStyle::Double)
+                .border_color("red")
+                .padding(1),
+            "success" => self
+                .border_style(BorderStyle::Round)
+                .border_color("green")
+                .padding(1),
+            "banner" => self
+                .border_style(BorderStyle::Double)
+                .text_alignment(TextAlignment::Center)
+                .title_alignment(TitleAlignment::Center)
+                .padding((3, 1)),
+            _ => {
+                return Err(BoxenError::input_validation_error(
+                    format!("Unknown preset '{name}'"),
+                    "preset".to_string(),
+                    name.to_string(),
+                    vec![crate::error::ErrorRecommendation::suggestion_only(
+                        "Unknown preset".to_string(),
+                        format!("Use one of: {}", Self::PRESETS.join(", ")),
+                    )],
+                ));
+            }
+        })
+    }
 }
 
 impl Default for BoxenBuilder {
@@ -1473,6 +3400,15 @@ impl Color {
     }
 }
 
+impl std::str::FromStr for Color {
+    type Err = crate::error::BoxenError;
+
+    /// Parse and validate a color name or hex code (see [`Color::validated`])
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::validated(s)
+    }
+}
+
 // Note: We cannot implement TryFrom<&str> because Rust provides a blanket implementation
 // of TryFrom<U> for any T where U: Into<T>. Since we have From<&str>, we automatically
 // get TryFrom<&str> that never fails. Use Color::validated() for validation instead.
@@ -1521,6 +3457,44 @@ impl From<[usize; 2]> for Spacing {
     }
 }
 
+impl std::str::FromStr for Spacing {
+    type Err = crate::error::BoxenError;
+
+    /// Parse spacing from one, two, or four comma- or space-separated values
+    ///
+    /// - `"1"` behaves like `Spacing::from(1)` (terminal-balanced, 3x horizontal)
+    /// - `"2,1"` is `(horizontal, vertical)` like `Spacing::from((2, 1))`
+    /// - `"1,2,3,4"` is `top, right, bottom, left`
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        use crate::error::{BoxenError, ErrorRecommendation};
+
+        let invalid = || {
+            BoxenError::input_validation_error(
+                format!("Invalid spacing: '{s}'"),
+                "spacing".to_string(),
+                s.to_string(),
+                vec![ErrorRecommendation::suggestion_only(
+                    "Unrecognized spacing format".to_string(),
+                    "Use one value (\"1\"), horizontal and vertical (\"2,1\"), or top, right, bottom, left (\"1,2,1,2\")".to_string(),
+                )],
+            )
+        };
+
+        let values = s
+            .split(|c: char| c == ',' || c.is_whitespace())
+            .filter(|part| !part.is_empty())
+            .map(|part| part.parse::<usize>().map_err(|_| invalid()))
+            .collect::<Result<Vec<_>, _>>()?;
+
+        match values.as_slice() {
+            [all] => Ok(Self::from(*all)),
+            [horizontal, vertical] => Ok(Self::from((*horizontal, *vertical))),
+            [top, right, bottom, left] => Ok(Self::from([*top, *right, *bottom, *left])),
+            _ => Err(invalid()),
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -1574,6 +3548,102 @@ mod tests {
         assert_eq!(spacing.vertical(), 4);
     }
 
+    #[test]
+    fn test_spacing_from_str() {
+        let spacing: Spacing = "1".parse().unwrap();
+        assert_eq!((spacing.top, spacing.right), (1, 3));
+
+        let spacing: Spacing = "2, 1".parse().unwrap();
+        assert_eq!((spacing.top, spacing.right, spacing.left), (1, 2, 2));
+
+        let spacing: Spacing = "1 2 3 4".parse().unwrap();
+        assert_eq!(
+            (spacing.top, spacing.right, spacing.bottom, spacing.left),
+            (1, 2, 3, 4)
+        );
+
+        assert!("1,2,3".parse::<Spacing>().is_err());
+        assert!("wide".parse::<Spacing>().is_err());
+    }
+
+    #[test]
+    fn test_color_from_str() {
+        assert!(matches!("red".parse::<Color>(), Ok(Color::Named(name)) if name == "red"));
+        assert!("#GGGGGG".parse::<Color>().is_err());
+    }
+
+    #[test]
+    fn test_options_eq_and_hash() {
+        use std::collections::hash_map::DefaultHasher;
+        use std::hash::{Hash, Hasher};
+
+        fn hash_of(options: &BoxenOptions) -> u64 {
+            let mut hasher = DefaultHasher::new();
+            options.hash(&mut hasher);
+            hasher.finish()
+        }
+
+        let a = BoxenBuilder::new().title("T").padding(1).build();
+        let b = BoxenBuilder::new().title("T").padding(1).build();
+        assert_eq!(a, b);
+        assert_eq!(hash_of(&a), hash_of(&b));
+        assert_ne!(a, BoxenBuilder::new().title("U").padding(1).build());
+
+        // Dynamic widths compare by closure identity
+        let dynamic = BoxenBuilder::new().width(|w: usize| w / 2).build();
+        assert_eq!(dynamic, dynamic.clone());
+        assert_eq!(hash_of(&dynamic), hash_of(&dynamic.clone()));
+        assert_ne!(dynamic, BoxenBuilder::new().width(|w: usize| w / 2).build());
+    }
+
+    #[test]
+    fn test_options_from_env() {
+        // All variables are exercised in one test so parallel tests never race
+        // on the shared process environment.
+        let vars = [
+            "BOXEN_BORDER_STYLE",
+            "BOXEN_BORDER_COLOR",
+            "BOXEN_BACKGROUND_COLOR",
+            "BOXEN_PADDING",
+            "BOXEN_MARGIN",
+        ];
+        // SAFETY: no other test reads or writes the BOXEN_* variables.
+        unsafe {
+            for var in vars {
+                std::env::remove_var(var);
+            }
+        }
+
+        let options = BoxenOptions::from_env().unwrap();
+        assert_eq!(options.border_style, BorderStyle::Single);
+        assert!(options.border_color.is_none());
+
+        unsafe {
+            std::env::set_var("BOXEN_BORDER_STYLE", "double");
+            std::env::set_var("BOXEN_BORDER_COLOR", "#00FF00");
+            std::env::set_var("BOXEN_PADDING", "2,1");
+            std::env::set_var("BOXEN_MARGIN", "");
+        }
+        let options = BoxenOptions::from_env().unwrap();
+        assert_eq!(options.border_style, BorderStyle::Double);
+        assert!(matches!(options.border_color, Some(Color::Hex(ref hex)) if hex == "#00FF00"));
+        assert_eq!((options.padding.left, options.padding.top), (2, 1));
+        assert!(options.margin.is_empty());
+
+        unsafe {
+            std::env::set_var("BOXEN_BORDER_STYLE", "dotted");
+        }
+        let error = BoxenOptions::from_env().unwrap_err();
+        assert!(matches!(error, BoxenError::ConfigurationError { .. }));
+        assert!(error.to_string().contains("BOXEN_BORDER_STYLE"));
+
+        unsafe {
+            for var in vars {
+                std::env::remove_var(var);
+            }
+        }
+    }
+
     #[test]
     fn test_calculate_constraints_default() {
         let options = BoxenOptions::default();
@@ -1814,6 +3884,69 @@ mod tests {
         matches!(result.unwrap_err(), BoxenError::ConfigurationError { .. });
     }
 
+    #[test]
+    fn test_ignore_terminal_bounds() {
+        let oversized = get_terminal_width() * 3;
+        let mut options = BoxenOptions {
+            width: Some(Width::Fixed(oversized)),
+            ..Default::default()
+        };
+
+        assert!(crate::boxen("Wide", Some(options.clone())).is_err());
+
+        options.ignore_terminal_bounds = true;
+        let result = crate::boxen("Wide", Some(options)).unwrap();
+        for line in result.lines() {
+            assert_eq!(crate::text::text_width(line), oversized);
+        }
+    }
+
+    #[test]
+    fn test_clamp_to_terminal() {
+        let terminal_width = get_terminal_width();
+        let text = "word ".repeat(terminal_width);
+        let mut options = BoxenOptions {
+            width: Some(Width::Fixed(terminal_width * 2)),
+            margin: Spacing::from((3, 0)),
+            padding: Spacing::from(1),
+            ..Default::default()
+        };
+
+        assert!(crate::boxen(&text, Some(options.clone())).is_err());
+
+        options.clamp_to_terminal = true;
+        let result = crate::boxen(&text, Some(options.clone())).unwrap();
+        assert!(result.lines().count() > 3);
+        for line in result.lines() {
+            assert_eq!(crate::text::text_width(line), terminal_width);
+        }
+
+        // The builder's up-front validation sees the clamped width too
+        let result = BoxenBuilder::from_options(options).render("short").unwrap();
+        assert!(
+            result
+                .lines()
+                .all(|line| crate::text::text_width(line) <= terminal_width)
+        );
+    }
+
+    #[test]
+    fn test_ignore_terminal_bounds_in_layout() {
+        let excessive_width = get_terminal_width() + 100;
+        let options = BoxenOptions {
+            width: Some(Width::Fixed(excessive_width + 2)),
+            height: Some(Height::Fixed(200)),
+            ignore_terminal_bounds: true,
+            ..Default::default()
+        };
+
+        let layout = options
+            .calculate_layout_dimensions(excessive_width, 150)
+            .unwrap();
+        assert_eq!(layout.total_width, excessive_width + 2);
+        assert_eq!(layout.total_height, 152);
+    }
+
     #[test]
     fn test_fullscreen_mode_auto() {
         let options = BoxenOptions {
@@ -1920,6 +4053,34 @@ mod tests {
         matches!(result.unwrap_err(), BoxenError::InvalidDimensions { .. });
     }
 
+    #[test]
+    fn test_fullscreen_mode_custom_zero_dimensions() {
+        let options = BoxenOptions {
+            fullscreen: Some(FullscreenMode::Custom(|_, _| (0, 10))),
+            ..Default::default()
+        };
+
+        let error = options.calculate_constraints().unwrap_err();
+        assert!(matches!(error, BoxenError::ConfigurationError { .. }));
+        assert!(
+            error
+                .to_string()
+                .contains("Fullscreen function returned width 0")
+        );
+        assert!(!error.recommendations().is_empty());
+
+        let options = BoxenOptions {
+            fullscreen: Some(FullscreenMode::Custom(|_, _| (40, 0))),
+            ..Default::default()
+        };
+        let error = options.calculate_constraints().unwrap_err();
+        assert!(
+            error
+                .to_string()
+                .contains("Fullscreen function returned height 0")
+        );
+    }
+
     #[test]
     fn test_fullscreen_mode_overrides_width_height() {
         let options = BoxenOptions {
@@ -1959,6 +4120,64 @@ mod tests {
     }
 
     // Builder pattern tests
+    #[test]
+    fn test_builder_from_options() {
+        let options = BoxenOptions {
+            padding: Spacing::from(1),
+            title: Some("Stored".to_string()),
+            text_alignment: TextAlignment::Center,
+            ..Default::default()
+        };
+
+        assert_eq!(BoxenBuilder::from_options(options.clone()).build(), options);
+
+        let result = BoxenBuilder::from_options(options.clone())
+            .border_style(BorderStyle::Double)
+            .render("Hello")
+            .unwrap();
+        let expected = crate::boxen(
+            "Hello",
+            Some(BoxenOptions {
+                border_style: BorderStyle::Double,
+                ..options
+            }),
+        )
+        .unwrap();
+        assert_eq!(result, expected);
+        assert!(result.starts_with("╔") && result.lines().next().unwrap().contains("Stored"));
+    }
+
+    #[test]
+    fn test_builder_dim_all() {
+        let options = BoxenBuilder::new().dim_all().build();
+        assert!(options.dim_border);
+        assert!(options.dim_content);
+
+        let result = BoxenBuilder::new().dim_all().render("Hi").unwrap();
+        let lines: Vec<&str> = result.lines().collect();
+        let dim = "\x1b[2m";
+        assert!(lines[0].starts_with(dim));
+        assert_eq!(lines[1].matches(dim).count(), 3);
+        assert!(lines[1].contains(&format!("{dim}Hi")));
+        assert!(lines[2].starts_with(dim));
+    }
+
+    #[test]
+    fn test_builder_width_fill() {
+        assert_eq!(get_terminal_width(), 80);
+
+        let result = BoxenBuilder::new().width_fill(10).render("Hello").unwrap();
+        for line in result.lines() {
+            assert_eq!(crate::text::text_width(line), 70);
+        }
+
+        let options = BoxenBuilder::new().width_fill(10).margin(2).build();
+        assert_eq!(options.width.unwrap().calculate(80), 70);
+
+        // Reserving more than the terminal width leaves nothing to render into
+        assert!(BoxenBuilder::new().width_fill(200).render("Hello").is_err());
+    }
+
     #[test]
     fn test_builder_new() {
         let builder = BoxenBuilder::new();
@@ -1978,6 +4197,7 @@ mod tests {
         assert!(options.background_color.is_none());
         assert!(!options.dim_border);
         assert!(options.fullscreen.is_none());
+        assert!(!options.ascii_only);
     }
 
     #[test]
@@ -2064,7 +4284,23 @@ mod tests {
             .text_alignment(TextAlignment::Right)
             .build();
 
-        assert!(matches!(options.text_alignment, TextAlignment::Right));
+        assert!(matches!(options.text_alignment, TextAlignment::Right));
+    }
+
+    #[test]
+    fn test_builder_ascii() {
+        let options = BoxenBuilder::new().ascii().build();
+
+        assert_eq!(options.border_style, BorderStyle::Classic);
+        assert!(options.ascii_only);
+
+        let result = BoxenBuilder::new()
+            .ascii()
+            .title("Café")
+            .render("naïve → ok…")
+            .unwrap();
+        assert!(result.is_ascii());
+        assert!(result.contains("naive -> ok..."));
     }
 
     #[test]
@@ -2082,6 +4318,21 @@ mod tests {
         assert_eq!(options.title, Some(title));
     }
 
+    #[test]
+    fn test_builder_title_display() {
+        let options = BoxenBuilder::new().title_display(2024).build();
+        assert_eq!(options.title.as_deref(), Some("2024"));
+
+        let path = std::path::PathBuf::from("src").join("main.rs");
+        let result = BoxenBuilder::new()
+            .title_display(path.display())
+            .width(30)
+            .render("fn main() {}")
+            .unwrap();
+        let top_border = result.lines().next().unwrap();
+        assert!(top_border.contains(&path.display().to_string()));
+    }
+
     #[test]
     fn test_builder_title_alignment() {
         let options = BoxenBuilder::new()
@@ -2372,6 +4623,462 @@ mod tests {
         assert!(matches!(options.float, Float::Center));
     }
 
+    #[test]
+    fn test_builder_align() {
+        let options = BoxenBuilder::new()
+            .float(Float::Right)
+            .align(TextAlignment::Right)
+            .build();
+
+        assert_eq!(options.text_alignment, TextAlignment::Right);
+        assert_eq!(options.title_alignment, TitleAlignment::Right);
+        assert_eq!(options.float, Float::Right);
+
+        let options = BoxenBuilder::new().align(TextAlignment::Center).build();
+        assert_eq!(options.title_alignment, TitleAlignment::Center);
+        assert_eq!(options.float, Float::Left);
+    }
+
+    #[test]
+    fn test_builder_content_width_for() {
+        // An unbreakable run fills every wrapped row to the full width
+        let text = "x".repeat(150);
+        let builders = [
+            BoxenBuilder::new().width(20).padding(1),
+            BoxenBuilder::new().max_width(30),
+            BoxenBuilder::new().width(40).content_width(7),
+            BoxenBuilder::new()
+                .margin_pct(0, 10, 0, 10)
+                .line_prefix("> "),
+            BoxenBuilder::new()
+                .width_like("twelve chars")
+                .border_style(BorderStyle::None),
+            BoxenBuilder::new().transform(|text| text.replace('x', "yy")),
+        ];
+
+        for builder in builders {
+            let expected = builder.content_width_for(&text).unwrap();
+            let rendered = builder.render(&text).unwrap();
+            let first_row = rendered
+                .lines()
+                .find(|line| line.contains(['x', 'y']))
+                .unwrap();
+            assert_eq!(
+                first_row.matches(['x', 'y']).count(),
+                expected,
+                "{rendered}"
+            );
+        }
+
+        assert_eq!(
+            BoxenBuilder::new().width(20).content_width_for("").unwrap(),
+            18
+        );
+        assert!(
+            BoxenBuilder::new()
+                .width(2)
+                .padding(5)
+                .content_width_for("x")
+                .is_err()
+        );
+    }
+
+    #[test]
+    fn test_builder_auto_contrast() {
+        let dark = BoxenBuilder::new().auto_contrast("blue").build();
+        assert_eq!(dark.border_color, Some(Color::Named("blue".to_string())));
+        assert_eq!(dark.background_color, dark.border_color);
+        assert_eq!(dark.text_color, Some(Color::Named("white".to_string())));
+
+        let light = BoxenBuilder::new()
+            .auto_contrast(Color::Rgb(250, 240, 200))
+            .build();
+        assert_eq!(light.text_color, Some(Color::Named("black".to_string())));
+
+        // An unresolved base only colors the border
+        let auto = BoxenBuilder::new().auto_contrast(Color::Auto).build();
+        assert_eq!(auto.border_color, Some(Color::Auto));
+        assert_eq!((auto.text_color, auto.background_color), (None, None));
+    }
+
+    #[test]
+    fn test_builder_fit_remaining() {
+        // Library tests run with an 80 column terminal
+        let result = BoxenBuilder::new()
+            .float(Float::Center)
+            .fit_remaining(20)
+            .render("Hello")
+            .unwrap();
+        let lines: Vec<&str> = result.lines().collect();
+        assert_eq!(lines[0], format!("┌{}┐", "─".repeat(58)));
+        assert!(lines.iter().all(|line| crate::text::text_width(line) == 60));
+
+        let options = BoxenBuilder::new().fit_remaining(100).build();
+        assert_eq!(options.float, Float::Left);
+        assert_eq!(options.width.map(|width| width.calculate(80)), Some(0));
+    }
+
+    #[test]
+    fn test_builder_width_like() {
+        let header = "Some header";
+        let options = BoxenBuilder::new()
+            .width_like(header)
+            .padding(1)
+            .margin(1)
+            .build();
+        assert_eq!(options.width, Some(Width::Fixed(11 + 2 + 6 + 6)));
+
+        let content_width = options.calculate_max_content_width().unwrap();
+        assert_eq!(content_width, crate::text::text_width(header));
+        let layout = options
+            .calculate_layout_dimensions(content_width, 1)
+            .unwrap();
+        assert_eq!(layout.inner_width, 11 + options.padding.horizontal());
+
+        // Wide characters count two columns each
+        let rendered = BoxenBuilder::new().width_like("表头").render("x").unwrap();
+        assert_eq!(rendered, "┌────┐\n│x   │\n└────┘");
+
+        // An explicit width replaces the reference
+        let options = BoxenBuilder::new().width_like(header).width(30).build();
+        assert_eq!(options.width, Some(Width::Fixed(30)));
+    }
+
+    #[test]
+    fn test_builder_center_horizontal() {
+        let options = BoxenBuilder::new()
+            .margin([1, 4, 2, 3])
+            .text_alignment(TextAlignment::Right)
+            .center_horizontal()
+            .build();
+
+        assert_eq!(options.float, Float::Center);
+        assert_eq!(options.margin, Spacing::from([1, 0, 2, 0]));
+        assert_eq!(options.text_alignment, TextAlignment::Right);
+    }
+
+    #[test]
+    fn test_builder_aspect_ratio() {
+        let paragraph = "Boxen draws boxes around terminal text. Cards in a grid look \
+                         best when they share a shape, so the width is chosen to match \
+                         the requested ratio of columns to rows.";
+        let result = BoxenBuilder::new()
+            .padding(1)
+            .aspect_ratio(3.0)
+            .render(paragraph)
+            .unwrap();
+
+        let lines: Vec<&str> = result.lines().collect();
+        let width = crate::text::text_width(lines[0]);
+        assert!(
+            lines
+                .iter()
+                .all(|line| crate::text::text_width(line) == width)
+        );
+        let ratio = width as f32 / lines.len() as f32;
+        assert!((ratio - 3.0).abs() < 0.5, "ratio {ratio}");
+    }
+
+    #[test]
+    fn test_builder_square() {
+        let is_square = |rendered: &str| {
+            let lines: Vec<&str> = rendered.lines().collect();
+            lines
+                .iter()
+                .all(|line| crate::text::text_width(line) == lines.len())
+        };
+
+        let wide = BoxenBuilder::new().square().render("Hi there").unwrap();
+        assert!(is_square(&wide), "{wide}");
+
+        let tall = BoxenBuilder::new().square().render("a\nb\nc\nd").unwrap();
+        assert!(is_square(&tall), "{tall}");
+
+        let padded = BoxenBuilder::new()
+            .padding(1)
+            .title("T")
+            .square()
+            .render("x")
+            .unwrap();
+        assert!(is_square(&padded), "{padded}");
+
+        let fixed = BoxenBuilder::new()
+            .width(12)
+            .square()
+            .render("a\nb\nc\nd\ne\nf\ng\nh\ni\nj\nk\nl\nm")
+            .unwrap();
+        assert!(is_square(&fixed), "{fixed}");
+    }
+
+    #[test]
+    fn test_builder_aspect_ratio_rejects_invalid() {
+        assert!(BoxenBuilder::new().aspect_ratio(0.0).render("x").is_err());
+        assert!(
+            BoxenBuilder::new()
+                .aspect_ratio(f32::NAN)
+                .render("x")
+                .is_err()
+        );
+    }
+
+    #[test]
+    fn test_builder_preset_warning() {
+        let options = BoxenBuilder::new().preset("warning").unwrap().build();
+
+        assert_eq!(
+            options.border_color,
+            Some(Color::Named("yellow".to_string()))
+        );
+        assert_eq!(options.border_style, BorderStyle::Single);
+        assert_eq!(options.padding, Spacing::from(1));
+        assert!(BoxenBuilder::new().preset("Warning").is_ok());
+    }
+
+    #[test]
+    fn test_builder_presets_render() {
+        for name in BoxenBuilder::PRESETS {
+            let result = BoxenBuilder::new().preset(name).unwrap().render("Hello");
+            assert!(result.is_ok(), "preset {name} failed to render");
+        }
+    }
+
+    #[test]
+    fn test_builder_unknown_preset() {
+        let err = BoxenBuilder::new().preset("fancy").err().unwrap();
+        assert!(matches!(err, BoxenError::InputValidationError { .. }));
+        let recommendations = err.recommendations();
+        assert!(
+            recommendations[0]
+                .suggestion
+                .contains("info, warning, error, success, banner")
+        );
+    }
+
+    #[test]
+    fn test_border_thickness_layout() {
+        let options = BoxenBuilder::new().border_thickness(2).width(20).build();
+        let constraints = options.calculate_constraints().unwrap();
+        assert_eq!(constraints.border_width, 4);
+        assert_eq!(options.calculate_max_content_width().unwrap(), 16);
+
+        let layout = options.calculate_layout_dimensions(5, 1).unwrap();
+        assert_eq!(layout.inner_width, 5);
+        assert_eq!(layout.total_width, 5 + 4);
+        assert_eq!(layout.total_height, 3);
+    }
+
+    #[test]
+    fn test_builder_margin_pct() {
+        // The test terminal is 80x24
+        let options = BoxenBuilder::new().margin_pct(0, 10, 0, 10).build();
+        assert_eq!(options.margin, Spacing::from([0, 8, 0, 8]));
+
+        let options = BoxenBuilder::new().margin_pct(25, 0, 50, 5).build();
+        assert_eq!(options.margin, Spacing::from([6, 0, 12, 4]));
+
+        let result = BoxenBuilder::new()
+            .margin_pct(0, 10, 0, 10)
+            .render("Hi")
+            .unwrap();
+        assert!(result.lines().all(|line| line.starts_with("        ┌")
+            || line.starts_with("        │")
+            || line.starts_with("        └")));
+    }
+
+    #[test]
+    fn test_builder_margin_pct_clamps_to_fit() {
+        let options = BoxenBuilder::new()
+            .padding(1)
+            .margin_pct(100, 100, 100, 100)
+            .build();
+        // (80 columns minus borders, padding and one content cell) shared evenly
+        assert_eq!(options.margin, Spacing::from([9, 35, 9, 35]));
+        assert!(BoxenBuilder::from_options(options).render("x").is_ok());
+
+        // A later fixed margin replaces the percentages
+        let options = BoxenBuilder::new()
+            .margin_pct(0, 10, 0, 10)
+            .margin(1)
+            .build();
+        assert_eq!(options.margin, Spacing::from(1));
+    }
+
+    #[test]
+    fn test_builder_with_uniform_spacing() {
+        let options = BoxenBuilder::new()
+            .padding(Uniform(2))
+            .margin(Uniform(1))
+            .build();
+        assert_eq!(options.padding, Spacing::from([2, 2, 2, 2]));
+        assert_eq!(options.margin, Spacing::from([1, 1, 1, 1]));
+
+        // Plain numbers keep the TypeScript-compatible multiplier
+        let options = BoxenBuilder::new().padding(2).build();
+        assert_eq!(options.padding, Spacing::from([2, 6, 2, 6]));
+    }
+
+    #[test]
+    fn test_options_diff() {
+        let base = BoxenOptions::default();
+        assert!(base.diff(&base.clone()).is_empty());
+
+        let double = BoxenOptions {
+            border_style: BorderStyle::Double,
+            ..Default::default()
+        };
+        assert_eq!(
+            base.diff(&double),
+            vec![FieldDiff {
+                field: "border_style",
+                old: "Single".to_string(),
+                new: "Double".to_string(),
+            }]
+        );
+
+        let themed = BoxenBuilder::new()
+            .title("T")
+            .padding(1)
+            .border_color("red")
+            .build();
+        let fields: Vec<&str> = base.diff(&themed).iter().map(|d| d.field).collect();
+        assert_eq!(fields, vec!["padding", "title", "border_color"]);
+    }
+
+    #[test]
+    fn test_builder_transform() {
+        let result = BoxenBuilder::new()
+            .transform(|s| s.to_uppercase())
+            .render("hello\nworld")
+            .unwrap();
+        assert_eq!(result, "┌─────┐\n│HELLO│\n│WORLD│\n└─────┘");
+
+        // The transformed text is what gets wrapped
+        let result = BoxenBuilder::new()
+            .width(8)
+            .transform(|s| s.repeat(2))
+            .render_or_adjust("abc")
+            .unwrap();
+        assert_eq!(result, "┌──────┐\n│abcabc│\n└──────┘");
+    }
+
+    #[test]
+    fn test_builder_pre_wrapped() {
+        let text = "a b c d\ne f\ng h i";
+        let result = BoxenBuilder::new()
+            .width(6)
+            .pre_wrapped(true)
+            .render(text)
+            .unwrap();
+        assert_eq!(result, "┌────┐\n│a b │\n│e f │\n│g h │\n└────┘");
+
+        // Without it the same text is re-wrapped into more rows
+        let rewrapped = BoxenBuilder::new().width(6).render(text).unwrap();
+        assert!(rewrapped.lines().count() > result.lines().count());
+
+        let options = BoxenBuilder::new()
+            .pre_wrapped(true)
+            .overflow_no_wrap(NoWrapOverflow::Error)
+            .width(6);
+        assert!(options.render(text).is_err());
+
+        // Turning it off leaves the `wrap` setting alone
+        let options = BoxenBuilder::new()
+            .wrap(false)
+            .pre_wrapped(true)
+            .pre_wrapped(false)
+            .build();
+        assert!(!options.wrap);
+        assert!(!options.pre_wrapped);
+        assert!(BoxenBuilder::new().pre_wrapped(false).build().wrap);
+    }
+
+    #[test]
+    fn test_builder_caption_alignment() {
+        let options = BoxenBuilder::new()
+            .caption_alignment(TitleAlignment::Center)
+            .build();
+        assert_eq!(options.title_alignment, TitleAlignment::Center);
+        assert_eq!(options.caption_alignment, TitleAlignment::Center);
+    }
+
+    #[test]
+    fn test_builder_inner_border() {
+        let options = BoxenBuilder::new()
+            .inner_border(BorderStyle::Round)
+            .inner_border_color("cyan")
+            .build();
+        assert_eq!(options.inner_border, Some(BorderStyle::Round));
+        assert_eq!(
+            options.inner_border_color,
+            Some(Color::Named("cyan".to_string()))
+        );
+        assert_eq!(BoxenOptions::default().inner_border, None);
+    }
+
+    #[test]
+    fn test_builder_reset_mode() {
+        assert_eq!(BoxenOptions::default().reset_mode, ResetMode::Full);
+        let options = BoxenBuilder::new().reset_mode(ResetMode::Targeted).build();
+        assert_eq!(options.reset_mode, ResetMode::Targeted);
+    }
+
+    #[test]
+    fn test_builder_error_on_empty() {
+        assert!(!BoxenOptions::default().error_on_empty);
+        assert_eq!(BoxenBuilder::new().render("").unwrap(), "┌┐\n││\n└┘");
+
+        for input in ["", " \t", "\n\n"] {
+            let result = BoxenBuilder::new().error_on_empty(true).render(input);
+            match result {
+                Err(BoxenError::ConfigurationError {
+                    recommendations, ..
+                }) => assert!(!recommendations.is_empty()),
+                other => panic!("Expected ConfigurationError, got {other:?}"),
+            }
+        }
+        assert!(
+            BoxenBuilder::new()
+                .error_on_empty(true)
+                .render(" x ")
+                .is_ok()
+        );
+    }
+
+    #[test]
+    fn test_builder_size_guards() {
+        let input = "untrusted ".repeat(50);
+        let result = BoxenBuilder::new().max_input_bytes(64).render(&input);
+        match result {
+            Err(BoxenError::ConfigurationError { message, .. }) => {
+                assert!(message.contains("500 bytes"), "{message}");
+            }
+            other => panic!("Expected ConfigurationError, got {other:?}"),
+        }
+        assert!(
+            BoxenBuilder::new()
+                .max_input_bytes(input.len())
+                .render(&input)
+                .is_ok()
+        );
+
+        // 3 rows of 7 columns
+        let result = BoxenBuilder::new().max_total_cells(20).render("Hello");
+        assert!(matches!(result, Err(BoxenError::ConfigurationError { .. })));
+        assert!(
+            BoxenBuilder::new()
+                .max_total_cells(21)
+                .render("Hello")
+                .is_ok()
+        );
+    }
+
+    #[test]
+    fn test_line_ending_as_str() {
+        assert_eq!(BoxenOptions::default().line_ending, LineEnding::Lf);
+        assert_eq!(LineEnding::Lf.as_str(), "\n");
+        assert_eq!(LineEnding::CrLf.as_str(), "\r\n");
+    }
+
     #[test]
     fn test_builder_with_array_spacing() {
         let options = BoxenBuilder::new()
@@ -2418,6 +5125,18 @@ mod tests {
     }
 }
 
+/// One field that differs between two [`BoxenOptions`], from
+/// [`BoxenOptions::diff`]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FieldDiff {
+    /// Name of the differing field
+    pub field: &'static str,
+    /// `Debug` rendering of the value in `self`
+    pub old: String,
+    /// `Debug` rendering of the value in the other options
+    pub new: String,
+}
+
 /// Dimension constraints for box calculation
 #[derive(Debug, Clone)]
 pub struct DimensionConstraints {
@@ -2499,6 +5218,26 @@ impl Spacing {
         self.top == 0 && self.right == 0 && self.bottom == 0 && self.left == 0
     }
 
+    /// Cap every side at `max_each`, leaving smaller values unchanged.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use ::boxen::Spacing;
+    ///
+    /// let capped = Spacing::from(10).clamp(5);  // 10 vertical, 30 horizontal
+    /// assert_eq!(capped, Spacing::uniform(5));
+    /// ```
+    #[must_use]
+    pub fn clamp(self, max_each: usize) -> Spacing {
+        Self {
+            top: self.top.min(max_each),
+            right: self.right.min(max_each),
+            bottom: self.bottom.min(max_each),
+            left: self.left.min(max_each),
+        }
+    }
+
     /// Create uniform spacing with the same value for all sides.
     ///
     /// # Examples
@@ -2577,6 +5316,173 @@ impl Spacing {
 }
 
 impl BoxenOptions {
+    /// List the fields whose values differ between `self` and `other`
+    ///
+    /// Entries follow the field declaration order, with both values rendered
+    /// through `Debug`. Useful for seeing what a theme or preset changed.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use ::boxen::{BorderStyle, BoxenOptions};
+    ///
+    /// let base = BoxenOptions::default();
+    /// let themed = BoxenOptions {
+    ///     border_style: BorderStyle::Double,
+    ///     ..Default::default()
+    /// };
+    ///
+    /// let diff = base.diff(&themed);
+    /// assert_eq!(diff.len(), 1);
+    /// assert_eq!(diff[0].field, "border_style");
+    /// assert_eq!(diff[0].new, "Double");
+    /// ```
+    #[must_use]
+    pub fn diff(&self, other: &BoxenOptions) -> Vec<FieldDiff> {
+        // Destructuring without `..` keeps this list in step with the struct
+        let Self {
+            border_style,
+            border_thickness,
+            bevel,
+            inner_border,
+            inner_border_color,
+            padding,
+            margin,
+            text_alignment,
+            alignment_marker,
+            numeric_align,
+            wrap,
+            overflow_no_wrap,
+            pre_wrapped,
+            title,
+            title_alignment,
+            title_position,
+            title_connectors,
+            title_margin,
+            title_require_fill,
+            title_full_width,
+            align_content_to_title,
+            float,
+            width,
+            max_width,
+            content_width,
+            height,
+            border_color,
+            background_color,
+            text_color,
+            title_color,
+            title_gradient,
+            dim_border,
+            dim_content,
+            inset_shadow,
+            force_color,
+            reset_mode,
+            line_ending,
+            mirror,
+            fullscreen,
+            ascii_only,
+            line_prefix,
+            line_suffix,
+            caption,
+            caption_alignment,
+            tile_horizontal,
+            ignore_terminal_bounds,
+            clamp_to_terminal,
+            collapse_margin_to_edge,
+            max_input_bytes,
+            max_total_cells,
+            error_on_empty,
+            preserve_blank_edges,
+            collapse_whitespace,
+            tab_width,
+            columns,
+            vertical_whitespace,
+            normalize,
+            scrollbar,
+            pointer,
+            truncation_indicator,
+        } = self;
+
+        let mut diffs = Vec::new();
+        macro_rules! compare {
+            ($($field:ident),* $(,)?) => {
+                $(
+                    if *$field != other.$field {
+                        diffs.push(FieldDiff {
+                            field: stringify!($field),
+                            old: format!("{:?}", $field),
+                            new: format!("{:?}", other.$field),
+                        });
+                    }
+                )*
+            };
+        }
+        compare!(
+            border_style,
+            border_thickness,
+            bevel,
+            inner_border,
+            inner_border_color,
+            padding,
+            margin,
+            text_alignment,
+            alignment_marker,
+            numeric_align,
+            wrap,
+            overflow_no_wrap,
+            pre_wrapped,
+            title,
+            title_alignment,
+            title_position,
+            title_connectors,
+            title_margin,
+            title_require_fill,
+            title_full_width,
+            align_content_to_title,
+            float,
+            width,
+            max_width,
+            content_width,
+            height,
+            border_color,
+            background_color,
+            text_color,
+            title_color,
+            title_gradient,
+            dim_border,
+            dim_content,
+            inset_shadow,
+            force_color,
+            reset_mode,
+            line_ending,
+            mirror,
+            fullscreen,
+            ascii_only,
+            line_prefix,
+            line_suffix,
+            caption,
+            caption_alignment,
+            tile_horizontal,
+            ignore_terminal_bounds,
+            clamp_to_terminal,
+            collapse_margin_to_edge,
+            max_input_bytes,
+            max_total_cells,
+            error_on_empty,
+            preserve_blank_edges,
+            collapse_whitespace,
+            tab_width,
+            columns,
+            vertical_whitespace,
+            normalize,
+            scrollbar,
+            pointer,
+            truncation_indicator,
+        );
+
+        diffs
+    }
+
     /// Helper to create `InvalidDimensions` error with basic recommendations
     fn invalid_dimensions_error(
         message: String,
@@ -2615,6 +5521,84 @@ impl BoxenOptions {
 
         BoxenError::configuration_error(message, recommendations)
     }
+
+    /// Build options from `BOXEN_*` environment variables applied onto defaults
+    ///
+    /// Recognized variables (unset or empty variables are ignored):
+    ///
+    /// - `BOXEN_BORDER_STYLE`: border style name, e.g. `double` or `round`
+    /// - `BOXEN_BORDER_COLOR`: color name or hex code for the border
+    /// - `BOXEN_BACKGROUND_COLOR`: color name or hex code for the content area
+    /// - `BOXEN_PADDING`: spacing in the format accepted by `Spacing::from_str`
+    /// - `BOXEN_MARGIN`: spacing in the format accepted by `Spacing::from_str`
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// use ::boxen::{boxen, BoxenOptions};
+    ///
+    /// // BOXEN_BORDER_STYLE=round BOXEN_PADDING=1 my-cli
+    /// let options = BoxenOptions::from_env().unwrap();
+    /// let result = boxen("Themed from the environment", Some(options)).unwrap();
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns `BoxenError::ConfigurationError` naming the offending variable if a
+    /// value cannot be parsed or is not valid Unicode.
+    pub fn from_env() -> BoxenResult<Self> {
+        let mut options = Self::default();
+
+        if let Some(style) = Self::env_value("BOXEN_BORDER_STYLE")? {
+            options.border_style = style;
+        }
+        if let Some(color) = Self::env_value("BOXEN_BORDER_COLOR")? {
+            options.border_color = Some(color);
+        }
+        if let Some(color) = Self::env_value("BOXEN_BACKGROUND_COLOR")? {
+            options.background_color = Some(color);
+        }
+        if let Some(padding) = Self::env_value("BOXEN_PADDING")? {
+            options.padding = padding;
+        }
+        if let Some(margin) = Self::env_value("BOXEN_MARGIN")? {
+            options.margin = margin;
+        }
+
+        Ok(options)
+    }
+
+    /// Read and parse a single environment variable, treating empty as unset
+    fn env_value<T>(name: &str) -> BoxenResult<Option<T>>
+    where
+        T: std::str::FromStr<Err = crate::error::BoxenError>,
+    {
+        use crate::error::{BoxenError, ErrorRecommendation};
+
+        match std::env::var(name) {
+            Ok(value) if value.trim().is_empty() => Ok(None),
+            Ok(value) => value.parse().map(Some).map_err(|e: BoxenError| {
+                let mut recommendations = e.recommendations();
+                recommendations.push(ErrorRecommendation::suggestion_only(
+                    "Invalid environment variable".to_string(),
+                    format!("Unset {name} or correct its value"),
+                ));
+                BoxenError::configuration_error(
+                    format!("Invalid value for {name}: {e}"),
+                    recommendations,
+                )
+            }),
+            Err(std::env::VarError::NotPresent) => Ok(None),
+            Err(std::env::VarError::NotUnicode(_)) => Err(BoxenError::configuration_error(
+                format!("{name} is not valid Unicode"),
+                vec![ErrorRecommendation::suggestion_only(
+                    "Invalid environment variable".to_string(),
+                    format!("Unset {name} or set it to plain text"),
+                )],
+            )),
+        }
+    }
+
     /// Calculate dimension constraints based on terminal size and options
     ///
     /// # Errors
@@ -2627,10 +5611,14 @@ impl BoxenOptions {
     /// Returns `BoxenError::TerminalSizeError` if:
     /// - Terminal size cannot be detected and no explicit dimensions are provided
     /// - Terminal dimensions are smaller than the required margins
+    ///
+    /// Returns `BoxenError::ConfigurationError` if a `FullscreenMode::Custom`
+    /// function returns a zero width or height.
     pub fn calculate_constraints(&self) -> BoxenResult<DimensionConstraints> {
         let terminal_width = get_terminal_width();
         let terminal_height = get_terminal_height();
-        let border_width = calculate_border_width(&self.border_style);
+        let border_width =
+            calculate_border_width_with_thickness(&self.border_style, self.border_thickness);
 
         // Handle fullscreen mode first
         if let Some(fullscreen_mode) = &self.fullscreen {
@@ -2701,6 +5689,15 @@ impl BoxenOptions {
             }
             terminal_width - self.margin.horizontal()
         };
+        let max_width = [
+            self.max_width,
+            self.clamp_to_terminal.then_some(terminal_width),
+        ]
+        .into_iter()
+        .flatten()
+        .fold(max_width, |width, cap| {
+            width.min(cap.saturating_sub(self.margin.horizontal()))
+        });
 
         // Calculate maximum available height
         let max_height = if let Some(ref height_spec) = self.height {
@@ -2812,20 +5809,22 @@ impl BoxenOptions {
             }
         }
 
-        // Validate against terminal constraints
-        if total_width > constraints.terminal_width {
-            return Err(Self::configuration_error(format!(
-                "Box width ({}) exceeds terminal width ({})",
-                total_width, constraints.terminal_width
-            )));
-        }
-
-        if let Some(terminal_height) = constraints.terminal_height {
-            if total_height > terminal_height {
+        // Validate against terminal constraints, unless output isn't bound for the terminal
+        if !self.ignore_terminal_bounds {
+            if total_width > constraints.terminal_width {
                 return Err(Self::configuration_error(format!(
-                    "Box height ({total_height}) exceeds terminal height ({terminal_height})"
+                    "Box width ({}) exceeds terminal width ({})",
+                    total_width, constraints.terminal_width
                 )));
             }
+
+            if let Some(terminal_height) = constraints.terminal_height {
+                if total_height > terminal_height {
+                    return Err(Self::configuration_error(format!(
+                        "Box height ({total_height}) exceeds terminal height ({terminal_height})"
+                    )));
+                }
+            }
         }
 
         Ok(LayoutDimensions {
@@ -2899,6 +5898,35 @@ impl BoxenOptions {
         }
     }
 
+    /// Reject zero dimensions from a `FullscreenMode::Custom` function up front,
+    /// before they surface as a confusing margin or padding error
+    fn validate_fullscreen_output(width: usize, height: usize) -> BoxenResult<()> {
+        use crate::error::{BoxenError, ErrorRecommendation};
+
+        let zero = match (width, height) {
+            (0, 0) => "width and height",
+            (0, _) => "width",
+            (_, 0) => "height",
+            _ => return Ok(()),
+        };
+
+        Err(BoxenError::configuration_error(
+            format!("Fullscreen function returned {zero} 0 (got {width}x{height})"),
+            vec![
+                ErrorRecommendation::suggestion_only(
+                    "Zero fullscreen dimensions".to_string(),
+                    "The custom fullscreen function must return a positive width and height"
+                        .to_string(),
+                ),
+                ErrorRecommendation::with_auto_fix(
+                    "Clamp the result".to_string(),
+                    "Clamp the computed dimensions to at least 1".to_string(),
+                    "FullscreenMode::Custom(|w, h| ((w / 2).max(1), (h / 2).max(1)))".to_string(),
+                ),
+            ],
+        ))
+    }
+
     /// Calculate constraints for fullscreen mode
     fn calculate_fullscreen_constraints(
         &self,
@@ -2916,6 +5944,7 @@ impl BoxenOptions {
                 // Use custom function to calculate dimensions
                 let height = terminal_height.unwrap_or(24); // Fallback height
                 let (custom_width, custom_height) = func(terminal_width, height);
+                Self::validate_fullscreen_output(custom_width, custom_height)?;
                 (custom_width, Some(custom_height))
             }
         };
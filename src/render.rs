@@ -300,14 +300,19 @@
 //! - **Performance Tests**: Speed and memory usage benchmarks
 //! - **Error Handling Tests**: Comprehensive error scenario coverage
 
-use crate::color::{apply_color_with_dim, apply_colors};
+use crate::color::{apply_color_with_dim, apply_colors, paint};
 use crate::error::BoxenResult;
 use crate::memory::pool::with_pooled_string;
-use crate::options::{BoxenOptions, TitleAlignment};
-use crate::text::text_width;
-use crate::text::wrapping::wrap_text;
+use crate::options::{
+    BorderChars, BorderStyle, BoxenOptions, Edge, Height, LineEnding, ResetMode, Spacing,
+    TextAlignment, TitleAlignment, TitlePosition, VerticalWhitespace, Width,
+};
+use crate::text::measurement::{EMOJI_PRESENTATION_SELECTOR, Measure, char_width_with_selector};
+use crate::text::wrapping::wrap_text_measured;
+use crate::text::{strip_ansi_codes, text_width};
+use colored::Colorize;
 use std::fmt::Write;
-use unicode_width::UnicodeWidthChar;
+use std::io::IsTerminal;
 
 /// Main boxen function that renders text within a styled box.
 ///
@@ -464,8 +469,284 @@ use unicode_width::UnicodeWidthChar;
 /// - Box rendering fails due to I/O errors
 /// - Border or content rendering encounters unexpected issues
 pub fn boxen<S: AsRef<str>>(text: S, options: Option<BoxenOptions>) -> BoxenResult<String> {
-    let text = text.as_ref();
-    let options = options.unwrap_or_default();
+    boxen_observed(text.as_ref(), options, &mut (), Measure::default())
+}
+
+/// What a row of a rendered box contains, as reported by [`boxen_annotated`]
+#[non_exhaustive]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum RowKind {
+    /// Title drawn on its own line: above the box, or first when there is no border
+    Title,
+    /// Blank margin row above or below the box
+    Margin,
+    /// Top border, including any embedded title
+    TopBorder,
+    /// Padding row between a border and the content
+    Padding,
+    /// Content row with its index among the content rows
+    Content(usize),
+    /// Bottom border
+    BottomBorder,
+    /// Caption line below the box
+    Caption,
+}
+
+/// Render a box and classify every output row
+///
+/// The returned kinds line up one-to-one with `rendered.lines()`, so callers
+/// updating the terminal in place can map a row back to what it shows.
+///
+/// # Errors
+///
+/// Returns the same errors as [`boxen`].
+///
+/// # Examples
+///
+/// ```rust
+/// use ::boxen::{RowKind, boxen_annotated};
+///
+/// let (rendered, rows) = boxen_annotated("Hi", None).unwrap();
+/// assert_eq!(rows, vec![RowKind::TopBorder, RowKind::Content(0), RowKind::BottomBorder]);
+/// assert_eq!(rendered.lines().count(), rows.len());
+/// ```
+pub fn boxen_annotated<S: AsRef<str>>(
+    text: S,
+    options: Option<BoxenOptions>,
+) -> BoxenResult<(String, Vec<RowKind>)> {
+    struct ContentRows(usize);
+    impl RenderObserver for ContentRows {
+        fn processed(&mut self, _text: &str, lines: &[String]) {
+            self.0 = lines.len();
+        }
+    }
+
+    let resolved = options.clone().unwrap_or_default();
+    let mut content = ContentRows(0);
+    let rendered = boxen_observed(text.as_ref(), options, &mut content, Measure::default())?;
+
+    let has_border = resolved.border_style.is_visible();
+    let title_above = resolved.title.is_some() && resolved.title_position == TitlePosition::Above;
+    let mut rows = Vec::new();
+    if title_above {
+        rows.push(RowKind::Title);
+    }
+    rows.extend(std::iter::repeat_n(RowKind::Margin, resolved.margin.top));
+    if has_border {
+        rows.push(RowKind::TopBorder);
+    } else if border_title(&resolved).is_some() {
+        rows.push(RowKind::Title);
+    }
+    rows.extend(std::iter::repeat_n(RowKind::Padding, resolved.padding.top));
+    rows.extend((0..content.0).map(RowKind::Content));
+    rows.extend(std::iter::repeat_n(
+        RowKind::Padding,
+        resolved.padding.bottom,
+    ));
+    if has_border {
+        rows.push(RowKind::BottomBorder);
+    }
+    rows.extend(std::iter::repeat_n(RowKind::Margin, resolved.margin.bottom));
+    if resolved.caption.is_some() {
+        rows.push(RowKind::Caption);
+    }
+
+    Ok((rendered, rows))
+}
+
+/// Render a box, measuring every character with `width_fn` instead of its
+/// Unicode width
+///
+/// The function applies to wrapping, alignment, titles, and border sizing for
+/// this call only. It is passed along with the render rather than set
+/// globally, so callbacks run during the render, nested renders, and other
+/// threads all keep Unicode widths. Escape sequences are still skipped before
+/// measuring.
+///
+/// # Errors
+///
+/// Returns the same errors as [`boxen`].
+///
+/// # Examples
+///
+/// ```rust
+/// use ::boxen::boxen_with_width_fn;
+///
+/// // Treat every character as one column, e.g. for a fixed-cell renderer
+/// let result = boxen_with_width_fn("日本", None, |_| 1).unwrap();
+/// assert_eq!(result, "┌──┐\n│日本│\n└──┘");
+/// ```
+pub fn boxen_with_width_fn<S: AsRef<str>>(
+    text: S,
+    options: Option<BoxenOptions>,
+    width_fn: fn(char) -> usize,
+) -> BoxenResult<String> {
+    boxen_observed(
+        text.as_ref(),
+        options,
+        &mut (),
+        Measure::with_width_fn(width_fn),
+    )
+}
+
+/// Render a box around raw bytes, decoding them as UTF-8
+///
+/// Invalid sequences are replaced with `U+FFFD` (`�`) rather than rejected,
+/// so input from files, sockets, or child processes can be boxed without
+/// validating it first. Valid UTF-8 renders exactly as with [`boxen`].
+///
+/// # Errors
+///
+/// Returns the same errors as [`boxen`].
+///
+/// # Examples
+///
+/// ```rust
+/// use ::boxen::boxen_bytes;
+///
+/// let result = boxen_bytes(b"ok\xFF", None).unwrap();
+/// assert_eq!(result, "┌───┐\n│ok�│\n└───┘");
+/// ```
+pub fn boxen_bytes(bytes: &[u8], options: Option<BoxenOptions>) -> BoxenResult<String> {
+    boxen(String::from_utf8_lossy(bytes), options)
+}
+
+/// Render a box one row at a time
+///
+/// Validation, wrapping, and layout happen up front, as the box width depends
+/// on every line. Each row's borders, padding, colors, and positioning are
+/// then produced only when the iterator reaches it, so a very tall box is never
+/// held in memory as a whole. Rows match `boxen(text, options)?.lines()` and
+/// carry no line terminator, whatever the `line_ending`. Boxes taller than the
+/// terminal need `ignore_terminal_bounds`, as with [`boxen`].
+///
+/// # Errors
+///
+/// Returns the same errors as [`boxen`] for invalid options or content. Each
+/// row is a `Result` as well, since styling it can fail.
+///
+/// # Examples
+///
+/// ```rust
+/// use ::boxen::boxen_line_iter;
+///
+/// let rows = boxen_line_iter("Hi", None)
+///     .unwrap()
+///     .collect::<Result<Vec<_>, _>>()
+///     .unwrap();
+/// assert_eq!(rows, vec!["┌──┐", "│Hi│", "└──┘"]);
+/// ```
+pub fn boxen_line_iter<S: AsRef<str>>(
+    text: S,
+    options: Option<BoxenOptions>,
+) -> BoxenResult<impl Iterator<Item = BoxenResult<String>>> {
+    let measure = Measure::default();
+    let (options, content, layout) = prepare_render(text.as_ref(), options, &mut (), measure)?;
+    let tile_width = options
+        .tile_horizontal
+        .then(crate::terminal::get_terminal_width);
+    let reset_mode = options.reset_mode;
+
+    Ok(
+        BoxRows::new(options, content, layout, measure)?.map(move |row| {
+            let row = match tile_width {
+                Some(width) => tile_to_width(&row?, width, measure),
+                None => row?,
+            };
+            Ok(match reset_mode {
+                ResetMode::Full => row,
+                ResetMode::Targeted => crate::color::targeted_resets(&row),
+            })
+        }),
+    )
+}
+
+/// Columns [`boxen`] wraps the content of `text` to with `options`
+pub(crate) fn content_wrap_width(text: &str, options: BoxenOptions) -> BoxenResult<usize> {
+    let (_, content, _) = prepare_render(text, Some(options), &mut (), Measure::default())?;
+    Ok(content.wrap_width)
+}
+
+/// Hooks invoked as [`boxen`] finishes each rendering phase
+///
+/// The unit implementation does nothing, so plain renders pay no cost.
+pub(crate) trait RenderObserver {
+    /// Options were validated and automatic colors resolved
+    fn validated(&mut self) {}
+    /// Content was normalized, wrapped, and aligned into `lines`
+    fn processed(&mut self, _text: &str, _lines: &[String]) {}
+    /// Layout dimensions were calculated
+    fn laid_out(&mut self) {}
+    /// The final box was rendered
+    fn rendered(&mut self, _output: &str) {}
+}
+
+impl RenderObserver for () {}
+
+/// [`boxen`] with phase notifications delivered to `observer` and widths
+/// taken from `measure`
+pub(crate) fn boxen_observed<O: RenderObserver>(
+    text: &str,
+    options: Option<BoxenOptions>,
+    observer: &mut O,
+    measure: Measure,
+) -> BoxenResult<String> {
+    let (options, processed_content, layout) = prepare_render(text, options, observer, measure)?;
+    let tile_horizontal = options.tile_horizontal;
+    let reset_mode = options.reset_mode;
+    let line_ending = options.line_ending;
+
+    // Render the box
+    let rendered = BoxRows::new(options, processed_content, layout, measure)
+        .and_then(render_box)
+        .map_err(|e| {
+            crate::error::BoxenError::rendering_error(
+                format!("Box rendering failed: {e}"),
+                vec![crate::error::ErrorRecommendation::suggestion_only(
+                    "Rendering error".to_string(),
+                    "Check your configuration and try reducing complexity".to_string(),
+                )],
+            )
+        })?;
+
+    let rendered = if tile_horizontal {
+        tile_to_width(&rendered, crate::terminal::get_terminal_width(), measure)
+    } else {
+        rendered
+    };
+    let rendered = match reset_mode {
+        ResetMode::Full => rendered,
+        ResetMode::Targeted => crate::color::targeted_resets(&rendered),
+    };
+    // Rows are joined with `\n` throughout rendering; rows never contain one
+    let rendered = match line_ending {
+        LineEnding::Lf => rendered,
+        LineEnding::CrLf => rendered.replace('\n', "\r\n"),
+    };
+    observer.rendered(&rendered);
+    Ok(rendered)
+}
+
+/// Validate the options and lay out `text`: everything [`boxen`] does before
+/// drawing the first row
+fn prepare_render<O: RenderObserver>(
+    text: &str,
+    options: Option<BoxenOptions>,
+    observer: &mut O,
+    measure: Measure,
+) -> BoxenResult<(
+    BoxenOptions,
+    ProcessedContent,
+    crate::options::LayoutDimensions,
+)> {
+    let mut options = options.unwrap_or_default();
+
+    // Size guards come first so oversized input is never scanned or copied
+    crate::error::validation::validate_input_size(text, &options)?;
+    crate::error::validation::validate_non_empty(text, &options)?;
+
+    // Unsupported title placements are configuration mistakes, not bad input
+    crate::error::validation::validate_title_position(&options)?;
 
     // Comprehensive input validation
     crate::error::validation::validate_all_options(text, &options).map_err(|e| {
@@ -475,8 +756,87 @@ pub fn boxen<S: AsRef<str>>(text: S, options: Option<BoxenOptions>) -> BoxenResu
         )
     })?;
 
+    // Automatic colors are derived from the content as given by the caller
+    for color in [
+        &mut options.border_color,
+        &mut options.inner_border_color,
+        &mut options.title_color,
+        &mut options.background_color,
+        &mut options.text_color,
+    ] {
+        if *color == Some(crate::options::Color::Auto) {
+            *color = Some(crate::color::auto_color(text));
+        }
+    }
+    observer.validated();
+
+    if options.tile_horizontal {
+        // Float offsets would be repeated along with the box, so tile from the left edge
+        options.float = crate::options::Float::Left;
+    }
+
+    if options.collapse_margin_to_edge {
+        collapse_edge_margins(&mut options);
+    }
+
+    // Labels expand tabs from their own first column, like content lines
+    if let Some(tab_width) = options.tab_width {
+        for label in [&mut options.title, &mut options.caption]
+            .into_iter()
+            .flatten()
+        {
+            if let std::borrow::Cow::Owned(expanded) =
+                crate::text::measurement::expand_tabs_measured(label, tab_width, measure)
+            {
+                *label = expanded;
+            }
+        }
+    }
+
+    if options.align_content_to_title {
+        options.padding.left += title_alignment_indent(&options, measure)?;
+    }
+
+    if options.mirror {
+        mirror_layout(&mut options);
+    }
+
+    // Downgrade to ASCII before measuring so widths reflect the final glyphs
+    let text = if options.ascii_only {
+        options.border_style = ascii_border_style(options.border_style)?;
+        for label in [
+            &mut options.title,
+            &mut options.caption,
+            &mut options.line_prefix,
+            &mut options.line_suffix,
+        ] {
+            *label = label
+                .as_deref()
+                .map(|label| crate::text::to_ascii(label).into_owned());
+        }
+        options.truncation_indicator = options
+            .truncation_indicator
+            .map(|ch| if ch.is_ascii() { ch } else { 'v' });
+        options.title_connectors = options
+            .title_connectors
+            .filter(|(left, right)| left.is_ascii() && right.is_ascii());
+        crate::text::to_ascii(text)
+    } else {
+        std::borrow::Cow::Borrowed(text)
+    };
+    let text = text.as_ref();
+
+    // An inner border is a nested box that becomes this box's content
+    let framed;
+    let text = if let Some(style) = options.inner_border.take() {
+        framed = render_inner_frame(text, &mut options, style, measure)?;
+        framed.as_str()
+    } else {
+        text
+    };
+
     // Process the text content
-    let processed_content = process_content(text, &options).map_err(|e| {
+    let processed_content = process_content(text, &options, measure).map_err(|e| {
         crate::error::BoxenError::rendering_error(
             format!("Text processing failed: {e}"),
             vec![crate::error::ErrorRecommendation::suggestion_only(
@@ -485,6 +845,7 @@ pub fn boxen<S: AsRef<str>>(text: S, options: Option<BoxenOptions>) -> BoxenResu
             )],
         )
     })?;
+    observer.processed(text, &processed_content.lines);
 
     // Calculate final layout dimensions
     let layout = options
@@ -498,937 +859,4729 @@ pub fn boxen<S: AsRef<str>>(text: S, options: Option<BoxenOptions>) -> BoxenResu
                 e.recommendations(),
             )
         })?;
-
-    // Render the box
-    render_box(&processed_content, &options, &layout).map_err(|e| {
-        crate::error::BoxenError::rendering_error(
-            format!("Box rendering failed: {e}"),
-            vec![crate::error::ErrorRecommendation::suggestion_only(
-                "Rendering error".to_string(),
-                "Check your configuration and try reducing complexity".to_string(),
-            )],
-        )
-    })
-}
-
-/// Processed text content with dimensions
-#[derive(Debug)]
-struct ProcessedContent {
-    lines: Vec<String>,
-    content_width: usize,
-    content_height: usize,
+    crate::error::validation::validate_total_cells(
+        layout.total_width,
+        layout.total_height,
+        &options,
+    )?;
+    observer.laid_out();
+
+    Ok((options, processed_content, layout))
 }
 
-/// Process text content according to options
-fn process_content(text: &str, options: &BoxenOptions) -> BoxenResult<ProcessedContent> {
-    // Calculate maximum content width available
+/// Render `text` inside the inner border and turn `options` into the outer
+/// frame that holds it
+///
+/// One cell of padding on each side moves outside the inner frame; the rest,
+/// together with every text setting, applies to the content inside it. The
+/// outer box then treats the rendered frame as verbatim content.
+fn render_inner_frame(
+    text: &str,
+    options: &mut BoxenOptions,
+    style: BorderStyle,
+    measure: Measure,
+) -> BoxenResult<String> {
+    let outer_padding = Spacing::from([
+        options.padding.top.min(1),
+        options.padding.right.min(1),
+        options.padding.bottom.min(1),
+        options.padding.left.min(1),
+    ]);
+    let inner_padding = Spacing::from([
+        options.padding.top - outer_padding.top,
+        options.padding.right - outer_padding.right,
+        options.padding.bottom - outer_padding.bottom,
+        options.padding.left - outer_padding.left,
+    ]);
+    options.padding = outer_padding;
+
+    let sized = options.width.is_some() || options.fullscreen.is_some();
     let max_content_width = options.calculate_max_content_width()?;
-
-    // Calculate maximum content height available
-    let max_content_height = options.calculate_max_content_height()?;
-
-    // Wrap text if needed
-    let wrapped_lines = if text.is_empty() {
-        vec![String::new()]
+    let max_content_height = if options.height.is_some() || options.fullscreen.is_some() {
+        options.calculate_max_content_height()?
     } else {
-        wrap_text(text, max_content_width)?
+        None
     };
 
-    // Calculate the natural width of the content
-    let natural_content_width = wrapped_lines
-        .iter()
-        .map(|line| text_width(line))
-        .max()
-        .unwrap_or(0);
+    let inner = BoxenOptions {
+        border_style: if options.ascii_only {
+            ascii_border_style(style)?
+        } else {
+            style
+        },
+        border_color: options.inner_border_color.clone(),
+        padding: inner_padding,
+        width: sized.then_some(Width::Fixed(max_content_width)),
+        max_width: (!sized).then_some(max_content_width),
+        height: max_content_height.map(Height::Fixed),
+        text_alignment: options.text_alignment,
+        alignment_marker: options.alignment_marker,
+        numeric_align: options.numeric_align,
+        wrap: options.wrap,
+        overflow_no_wrap: options.overflow_no_wrap,
+        pre_wrapped: options.pre_wrapped,
+        content_width: options.content_width,
+        background_color: options.background_color.clone(),
+        dim_border: options.dim_border,
+        dim_content: options.dim_content,
+        line_prefix: options.line_prefix.take(),
+        line_suffix: options.line_suffix.take(),
+        preserve_blank_edges: options.preserve_blank_edges,
+        collapse_whitespace: options.collapse_whitespace,
+        vertical_whitespace: options.vertical_whitespace,
+        tab_width: options.tab_width.take(),
+        columns: options.columns,
+        normalize: options.normalize.take(),
+        force_color: options.force_color,
+        ignore_terminal_bounds: true,
+        ..BoxenOptions::default()
+    };
+    let framed = boxen_observed(text, Some(inner), &mut (), measure)?;
+
+    // The frame is already laid out, so the outer box must not reflow it
+    options.text_alignment = TextAlignment::Left;
+    options.alignment_marker = None;
+    options.numeric_align = false;
+    options.wrap = false;
+    options.content_width = None;
+    options.columns = false;
+    options.collapse_whitespace = false;
+    options.vertical_whitespace = VerticalWhitespace::Keep;
+    Ok(framed)
+}
 
-    // Determine target width based on options
-    let target_width = if options.fullscreen.is_some() || options.width.is_some() {
-        // For fullscreen mode or specified width, use the maximum available content width
-        max_content_width
+/// `style`, or `BorderStyle::Classic` when any of its glyphs is not ASCII
+fn ascii_border_style(style: BorderStyle) -> BoxenResult<BorderStyle> {
+    if !style.is_visible() {
+        return Ok(style);
+    }
+    let chars = style.get_chars()?;
+    let glyphs = [
+        chars.top_left,
+        chars.top_right,
+        chars.bottom_left,
+        chars.bottom_right,
+        chars.left,
+        chars.right,
+        chars.top,
+        chars.bottom,
+    ];
+    Ok(if glyphs.iter().all(char::is_ascii) {
+        style
     } else {
-        // For normal mode, use the smaller of natural width or max width for better layout
-        natural_content_width.min(max_content_width)
-    };
+        BorderStyle::Classic
+    })
+}
 
-    // Apply height constraints if specified
-    let height_constrained_lines = if let Some(max_height) = max_content_height {
-        crate::text::apply_height_constraints(&wrapped_lines, max_height)
+/// Render a box and write it, followed by a newline, to `writer`
+///
+/// The writer is flushed afterwards so the box appears immediately even on
+/// buffered or line-buffered streams. A generic writer is treated as a
+/// non-terminal sink, so escape sequences are stripped unless color is forced
+/// through [`BoxenOptions::force_color`], `FORCE_COLOR`, or `CLICOLOR_FORCE`
+/// (see [`crate::color::should_use_color`]).
+///
+/// # Errors
+///
+/// Returns any error from [`boxen`], or a `BoxenError::RenderingError` if
+/// writing or flushing fails.
+///
+/// # Examples
+///
+/// ```rust
+/// use ::boxen::write_boxen;
+///
+/// let mut output = Vec::new();
+/// write_boxen(&mut output, "Hello", None).unwrap();
+/// assert_eq!(String::from_utf8(output).unwrap(), "┌─────┐\n│Hello│\n└─────┘\n");
+/// ```
+pub fn write_boxen<W: std::io::Write, S: AsRef<str>>(
+    writer: &mut W,
+    text: S,
+    options: Option<BoxenOptions>,
+) -> BoxenResult<()> {
+    write_boxen_to(writer, text, options, false)
+}
+
+/// Shared body of the write helpers, with the target's terminal status known
+fn write_boxen_to<W: std::io::Write, S: AsRef<str>>(
+    writer: &mut W,
+    text: S,
+    options: Option<BoxenOptions>,
+    is_terminal: bool,
+) -> BoxenResult<()> {
+    let mut options = options.unwrap_or_default();
+    let line_ending = options.line_ending;
+    let use_color = crate::color::should_use_color(options.force_color, is_terminal);
+    // `colored` decides from stdout, so the decision for this target is passed on
+    options.force_color = Some(use_color);
+
+    let rendered = boxen(text, Some(options))?;
+    let rendered = if use_color {
+        rendered
     } else {
-        wrapped_lines
+        strip_ansi_codes(&rendered)
     };
+    write!(writer, "{rendered}{}", line_ending.as_str())
+        .and_then(|()| writer.flush())
+        .map_err(|e| {
+            crate::error::BoxenError::rendering_error(
+                format!("Failed to write box: {e}"),
+                vec![crate::error::ErrorRecommendation::suggestion_only(
+                    "Output error".to_string(),
+                    "Check that the output stream is still open and writable".to_string(),
+                )],
+            )
+        })
+}
 
-    // Apply text alignment without padding (padding will be applied during rendering)
-    let aligned_lines = crate::text::align_lines(
-        &height_constrained_lines,
-        options.text_alignment,
-        target_width,
-    );
-
-    let content_height = aligned_lines.len();
+/// Render a box and print it to stdout with a trailing newline
+///
+/// Colors are dropped when stdout is not a terminal, unless forced; see
+/// [`crate::color::should_use_color`] for the full precedence.
+///
+/// # Errors
+///
+/// Returns any error from [`boxen`], or a `BoxenError::RenderingError` if
+/// stdout cannot be written or flushed.
+///
+/// # Examples
+///
+/// ```rust
+/// use ::boxen::print_boxen;
+///
+/// print_boxen("Build succeeded", None).unwrap();
+/// ```
+pub fn print_boxen<S: AsRef<str>>(text: S, options: Option<BoxenOptions>) -> BoxenResult<()> {
+    let stdout = std::io::stdout();
+    let is_terminal = stdout.is_terminal();
+    write_boxen_to(&mut stdout.lock(), text, options, is_terminal)
+}
 
-    Ok(ProcessedContent {
-        lines: aligned_lines,
-        content_width: target_width,
-        content_height,
-    })
+/// Render a box and print it to stderr with a trailing newline
+///
+/// # Errors
+///
+/// Returns any error from [`boxen`], or a `BoxenError::RenderingError` if
+/// stderr cannot be written or flushed.
+pub fn eprint_boxen<S: AsRef<str>>(text: S, options: Option<BoxenOptions>) -> BoxenResult<()> {
+    let stderr = std::io::stderr();
+    let is_terminal = stderr.is_terminal();
+    write_boxen_to(&mut stderr.lock(), text, options, is_terminal)
 }
 
-/// Render the complete box with borders, content, and margins
-fn render_box(
-    content: &ProcessedContent,
-    options: &BoxenOptions,
-    layout: &crate::options::LayoutDimensions,
-) -> BoxenResult<String> {
-    let border_chars = options.border_style.get_chars()?;
-    let has_border = options.border_style.is_visible();
+/// Split an over-wide rendered box into horizontal pages
+///
+/// Each page holds the same display columns of every line, at most `page_width`
+/// cells wide, so pages can be shown one after another on a narrow terminal.
+/// Slicing is ANSI-aware: escape sequences are never split, and colors active
+/// at a page boundary are reopened on the next page. A zero `page_width`
+/// returns the box unchanged as a single page.
+///
+/// # Examples
+///
+/// ```rust
+/// use ::boxen::{builder, paginate_horizontal, text::text_width};
+///
+/// let wide = builder()
+///     .width(50)
+///     .ignore_terminal_bounds(true)
+///     .render("A wide table row")
+///     .unwrap();
+/// let pages = paginate_horizontal(&wide, 20);
+///
+/// assert_eq!(pages.len(), 3);
+/// assert!(pages[2].lines().all(|line| text_width(line) == 10));
+/// ```
+#[must_use]
+pub fn paginate_horizontal(box_str: &str, page_width: usize) -> Vec<String> {
+    if page_width == 0 {
+        return vec![box_str.to_string()];
+    }
 
-    // Pre-allocate string capacity to reduce reallocations
-    let estimated_chars_per_line = layout.total_width + 10; // +10 for ANSI codes
-    let estimated_lines = layout.total_height + options.margin.vertical();
-    let estimated_capacity = estimated_chars_per_line * estimated_lines;
-    let mut result = String::with_capacity(estimated_capacity);
+    let lines: Vec<&str> = box_str.split('\n').collect();
+    let total_width = lines.iter().map(|line| text_width(line)).max().unwrap_or(0);
+    let page_count = total_width.div_ceil(page_width).max(1);
+
+    (0..page_count)
+        .map(|page| {
+            lines
+                .iter()
+                .map(|line| {
+                    crate::text::measurement::slice_columns(line, page * page_width, page_width)
+                })
+                .collect::<Vec<_>>()
+                .join("\n")
+        })
+        .collect()
+}
 
-    // Add top margins
-    for _ in 0..options.margin.top {
-        writeln!(result).unwrap();
-    }
+/// Stack rendered boxes vertically inside one outer box
+///
+/// Each entry of `boxes` is a complete rendered box. They are placed one below
+/// another, left-aligned, and the result is boxed with `options`; the outer box
+/// sizes itself to the widest inner box unless `options` sets a width.
+///
+/// # Errors
+///
+/// Returns any error from [`boxen`] for the outer box, for example when the
+/// widest inner box does not fit the terminal.
+///
+/// # Examples
+///
+/// ```rust
+/// use ::boxen::{BorderStyle, BoxenOptions, boxen, group};
+///
+/// let first = boxen("one", None).unwrap();
+/// let second = boxen("two", None).unwrap();
+/// let options = BoxenOptions {
+///     border_style: BorderStyle::Double,
+///     ..Default::default()
+/// };
+/// let grouped = group(&[&first, &second], &options).unwrap();
+/// assert_eq!(grouped.lines().count(), 3 + 3 + 2);
+/// assert!(grouped.starts_with("╔═════╗"));
+/// ```
+pub fn group(boxes: &[&str], options: &BoxenOptions) -> BoxenResult<String> {
+    let stacked = boxes.join("\n");
+    boxen(stacked, Some(options.clone()))
+}
 
-    if has_border {
-        // Render top border with title
-        let top_border = render_top_border(&border_chars, options, layout.inner_width)?;
-        add_line_with_float_positioning(&mut result, &top_border, options, layout);
+/// Braille spinner frames used by [`spinner_box`]
+pub const SPINNER_FRAMES: [char; 10] = ['⠋', '⠙', '⠹', '⠸', '⠼', '⠴', '⠦', '⠧', '⠇', '⠏'];
 
-        // Render content lines with borders and padding
-        render_content_with_borders(&mut result, content, options, layout, &border_chars)?;
+/// Spinner frames used by [`spinner_box`] when `ascii_only` is set
+pub const SPINNER_FRAMES_ASCII: [char; 4] = ['|', '/', '-', '\\'];
 
-        // Render bottom border
-        let bottom_border = render_bottom_border(&border_chars, layout.inner_width, options)?;
-        add_line_with_float_positioning(&mut result, &bottom_border, options, layout);
+/// Box `label` behind one frame of a loading spinner
+///
+/// The glyph is `SPINNER_FRAMES[frame % SPINNER_FRAMES.len()]`, so callers can
+/// pass an ever-increasing tick count. With `ascii_only` the frames come from
+/// [`SPINNER_FRAMES_ASCII`] instead. Every frame is one column wide, so
+/// successive frames of the same label render at the same size.
+///
+/// # Errors
+///
+/// Returns any error from [`boxen`].
+///
+/// # Examples
+///
+/// ```rust
+/// use ::boxen::{BoxenOptions, spinner_box};
+///
+/// let frame = spinner_box(0, "Loading", &BoxenOptions::default()).unwrap();
+/// assert_eq!(frame, "┌─────────┐\n│⠋ Loading│\n└─────────┘");
+/// ```
+pub fn spinner_box(frame: usize, label: &str, options: &BoxenOptions) -> BoxenResult<String> {
+    let frames: &[char] = if options.ascii_only {
+        &SPINNER_FRAMES_ASCII
     } else {
-        // No border - just render content with padding and margins
-        render_content_without_borders(&mut result, content, options, layout)?;
-    }
+        &SPINNER_FRAMES
+    };
+    let glyph = frames[frame % frames.len()];
+    boxen(format!("{glyph} {label}"), Some(options.clone()))
+}
 
-    // Add bottom margins as empty lines
-    for _ in 0..options.margin.bottom {
-        result.push('\n');
+/// Stack rendered boxes vertically, left-aligned, with no outer frame
+///
+/// With `connectors`, a `│` row is inserted between adjacent boxes, in the
+/// center column of the narrower of the two. Where it meets a border drawn
+/// with box-drawing characters, that border gets a `┬` (above) or `┴` (below).
+///
+/// # Examples
+///
+/// ```rust
+/// use ::boxen::{boxen, join_vertical};
+///
+/// let first = boxen("start", None).unwrap();
+/// let second = boxen("end", None).unwrap();
+/// let flow = join_vertical(&[&first, &second], true);
+/// assert_eq!(
+///     flow,
+///     "┌─────┐\n│start│\n└─┬───┘\n  │\n┌─┴─┐\n│end│\n└───┘"
+/// );
+/// ```
+#[must_use]
+pub fn join_vertical(boxes: &[&str], connectors: bool) -> String {
+    if !connectors {
+        return boxes.join("\n");
     }
 
-    // Only remove the final newline if there are no bottom margins
-    if options.margin.bottom == 0 && result.ends_with('\n') {
-        result.pop();
+    let box_width = |rendered: &str| rendered.lines().map(text_width).max().unwrap_or(0);
+    let mut rows: Vec<String> = Vec::new();
+    for (index, rendered) in boxes.iter().enumerate() {
+        let mut lines: Vec<String> = rendered.lines().map(str::to_string).collect();
+        if index > 0 {
+            let column = box_width(boxes[index - 1]).min(box_width(rendered)) / 2;
+            if let Some(above) = rows.last_mut() {
+                *above = replace_border_glyph(above, column, '┬');
+            }
+            rows.push(format!("{}│", " ".repeat(column)));
+            if let Some(below) = lines.first_mut() {
+                *below = replace_border_glyph(below, column, '┴');
+            }
+        }
+        rows.extend(lines);
     }
-
-    Ok(result)
+    rows.join("\n")
 }
 
-/// Render the top border with optional title embedding
-fn render_top_border(
-    border_chars: &crate::options::BorderChars,
-    options: &BoxenOptions,
-    inner_width: usize,
-) -> BoxenResult<String> {
-    with_pooled_string(|border| {
-        // Reserve capacity upfront
-        border.reserve(inner_width + 2);
+/// Replace the box-drawing character at display `column` of `line` with
+/// `glyph`, keeping escape sequences; other lines are returned unchanged
+fn replace_border_glyph(line: &str, column: usize, glyph: char) -> String {
+    let mut result = String::with_capacity(line.len());
+    let mut current = 0;
+    let mut chars = line.chars().peekable();
+    while let Some(ch) = chars.next() {
+        if ch == '\x1b' && chars.peek() == Some(&'[') {
+            result.push(ch);
+            for escape_char in chars.by_ref() {
+                result.push(escape_char);
+                if escape_char.is_ascii_alphabetic() {
+                    break;
+                }
+            }
+            continue;
+        }
 
-        if let Some(title) = &options.title {
-            // Render top border with title - this handles color application internally
-            render_top_border_with_title_colored(
-                border,
-                title,
-                border_chars,
-                options,
-                inner_width,
-            )?;
+        if current == column && ('\u{2500}'..='\u{257F}').contains(&ch) {
+            result.push(glyph);
         } else {
-            // No title - build border and apply color to entire thing
-            border.push(border_chars.top_left);
-            for _ in 0..inner_width {
-                border.push(border_chars.top);
-            }
-            border.push(border_chars.top_right);
+            result.push(ch);
+        }
+        current += char_width_with_selector(ch, chars.peek().copied()).unwrap_or(0);
+    }
+    result
+}
 
-            // Apply border color and dim styling to entire border
-            let styled_border = apply_color_with_dim(
-                border.as_str(),
-                options.border_color.as_ref(),
-                options.dim_border,
-            )?;
+/// Recover the text inside a rendered box
+///
+/// Recognizes boxes drawn with any of the named border styles. ANSI escape
+/// sequences are stripped first, then margins, borders, and padding are
+/// removed; the title in the top border is discarded. Indentation shared by
+/// every content line is treated as padding and removed with it. Returns
+/// `None` if the input does not look like a box.
+///
+/// # Examples
+///
+/// ```rust
+/// use ::boxen::{builder, unbox};
+///
+/// let boxed = builder().padding(1).margin(1).render("Hello\nworld").unwrap();
+/// assert_eq!(unbox(&boxed).as_deref(), Some("Hello\nworld"));
+/// assert_eq!(unbox("not a box"), None);
+/// ```
+#[must_use]
+pub fn unbox(box_str: &str) -> Option<String> {
+    let plain = crate::text::strip_ansi_codes(box_str);
+    let lines: Vec<&str> = plain
+        .lines()
+        .skip_while(|line| line.trim().is_empty())
+        .collect();
+    let end = lines.iter().rposition(|line| !line.trim().is_empty())?;
+    let (top, rest) = lines[..=end].split_first()?;
+    let (bottom, body) = rest.split_last()?;
+
+    let margin = top.len() - top.trim_start_matches(' ').len();
+    let top = top[margin..].trim_end();
+    let bottom = bottom.get(margin..)?.trim_end();
+
+    let chars = BorderStyle::available_styles()
+        .into_iter()
+        .filter_map(|name| BorderStyle::from_name(name).ok())
+        .filter(BorderStyle::is_visible)
+        .filter_map(|style| style.get_chars().ok())
+        .find(|chars| {
+            top.starts_with(chars.top_left)
+                && top.ends_with(chars.top_right)
+                && bottom.starts_with(chars.bottom_left)
+                && bottom.ends_with(chars.bottom_right)
+        })?;
 
-            return Ok(styled_border.to_string());
-        }
+    let mut content = Vec::with_capacity(body.len());
+    for line in body {
+        let inner = line
+            .get(margin..)?
+            .trim_end()
+            .strip_prefix(chars.left)?
+            .strip_suffix(chars.right)?;
+        content.push(inner.trim_end());
+    }
 
-        Ok(border.as_str().to_string())
-    })
+    let first = content.iter().position(|line| !line.is_empty());
+    let last = content.iter().rposition(|line| !line.is_empty());
+    let content = match (first, last) {
+        (Some(first), Some(last)) => &content[first..=last],
+        _ => return Some(String::new()),
+    };
+
+    let indent = content
+        .iter()
+        .filter(|line| !line.is_empty())
+        .map(|line| line.len() - line.trim_start_matches(' ').len())
+        .min()
+        .unwrap_or(0);
+
+    Some(
+        content
+            .iter()
+            .map(|line| line.get(indent..).unwrap_or(""))
+            .collect::<Vec<_>>()
+            .join("\n"),
+    )
 }
 
-/// Render top border with embedded title, applying colors correctly
-#[allow(clippy::too_many_lines)]
-fn render_top_border_with_title_colored(
-    result: &mut impl Write,
-    title: &str,
-    border_chars: &crate::options::BorderChars,
+/// The four border edges of a box, rendered and styled separately
+///
+/// Produced by [`render_edges`] for compositing borders around content drawn
+/// by other means. Side edges hold one entry per inner row.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Edges {
+    /// Top border including both corners and any title
+    pub top: String,
+    /// Bottom border including both corners
+    pub bottom: String,
+    /// Left border character for each inner row
+    pub left: Vec<String>,
+    /// Right border character for each inner row, with any scrollbar thumb
+    pub right: Vec<String>,
+}
+
+/// Render the border edges for a box with the given inner size
+///
+/// `inner_width` and `inner_height` count the area between the borders,
+/// padding included. The edges are styled exactly as [`boxen`] would draw
+/// them, with the border color, dimming, title, pointer, and scrollbar
+/// applied. With `BorderStyle::None` every edge is empty.
+///
+/// # Errors
+///
+/// Returns `BoxenError::InvalidBorderStyle` for an invalid custom border and
+/// `BoxenError::InvalidColor` if a color cannot be applied; `Color::Auto`
+/// cannot be resolved here because there is no content to derive it from.
+///
+/// # Examples
+///
+/// ```rust
+/// use ::boxen::{render_edges, BoxenOptions};
+///
+/// let edges = render_edges(&BoxenOptions::default(), 3, 2).unwrap();
+/// assert_eq!(edges.top, "┌───┐");
+/// assert_eq!(edges.left, vec!["│", "│"]);
+/// assert_eq!(edges.bottom, "└───┘");
+/// ```
+pub fn render_edges(
     options: &BoxenOptions,
     inner_width: usize,
-) -> BoxenResult<()> {
-    let title_width = text_width(title);
+    inner_height: usize,
+) -> BoxenResult<Edges> {
+    if !options.border_style.is_visible() {
+        return Ok(Edges {
+            top: String::new(),
+            bottom: String::new(),
+            left: vec![String::new(); inner_height],
+            right: vec![String::new(); inner_height],
+        });
+    }
 
-    // If title is too long, truncate it
-    let effective_title = if title_width > inner_width {
-        // Truncate title to fit - use pooled buffer for truncation
-        with_pooled_string(|truncated| {
-            truncated.reserve(inner_width);
-            let mut current_width = 0;
-            for ch in title.chars() {
-                // Calculate char width directly without allocation
-                let char_width = UnicodeWidthChar::width(ch).unwrap_or(1);
-                if current_width + char_width > inner_width {
-                    break;
-                }
-                truncated.push(ch);
-                current_width += char_width;
-            }
-            truncated.as_str().to_string()
-        })
+    let border_chars = if options.ascii_only {
+        ascii_border_style(options.border_style.clone())?.get_chars()?
     } else {
-        title.to_string()
+        options.border_style.get_chars()?
     };
-
-    let effective_title_width = text_width(&effective_title);
-    let remaining_width = inner_width - effective_title_width;
-
-    // Apply title color with fallback chain: title_color → border_color → None
-    let title_color = options
-        .title_color
-        .as_ref()
-        .or(options.border_color.as_ref());
-
-    let styled_title = if let Some(color) = title_color {
-        apply_colors(&effective_title, Some(color), None)?.to_string()
-    } else {
-        effective_title.clone()
+    let style = |ch: char| -> BoxenResult<String> {
+        Ok(paint(
+            &apply_color_with_dim(
+                &side_border(ch, options),
+                options.border_color.as_ref(),
+                options.dim_border,
+            )?,
+            options.force_color,
+        ))
     };
 
-    // Helper to style border characters
-    let style_border_char = |ch: char| -> BoxenResult<String> {
-        let ch_str = ch.to_string();
-        let styled =
-            apply_color_with_dim(&ch_str, options.border_color.as_ref(), options.dim_border)?;
-        Ok(styled.to_string())
-    };
+    let thumb = options
+        .scrollbar
+        .map_or(0..0, |scrollbar| scrollbar.thumb_range(inner_height));
+    let left = style(border_chars.left)?;
+    let right = style(border_chars.right)?;
+    let right_thumb = style(scrollbar_thumb(options))?;
+
+    Ok(Edges {
+        top: render_top_border(&border_chars, options, inner_width, Measure::default())?,
+        bottom: render_bottom_border(&border_chars, inner_width, options)?,
+        left: vec![left; inner_height],
+        right: (0..inner_height)
+            .map(|row| {
+                if thumb.contains(&row) {
+                    right_thumb.clone()
+                } else {
+                    right.clone()
+                }
+            })
+            .collect(),
+    })
+}
 
-    // Helper to style border string
-    let style_border_str = |s: &str| -> BoxenResult<String> {
-        let styled = apply_color_with_dim(s, options.border_color.as_ref(), options.dim_border)?;
-        Ok(styled.to_string())
+/// Dashed border drawn by [`render_placeholder`]
+const PLACEHOLDER_BORDER: BorderChars = BorderChars {
+    top_left: '┌',
+    top_right: '┐',
+    bottom_left: '└',
+    bottom_right: '┘',
+    left: '╎',
+    right: '╎',
+    top: '╌',
+    bottom: '╌',
+};
+
+/// Dashed border drawn by [`render_placeholder`] with `ascii_only`
+const PLACEHOLDER_ASCII_BORDER: BorderChars = BorderChars {
+    top_left: '+',
+    top_right: '+',
+    bottom_left: '+',
+    bottom_right: '+',
+    left: ':',
+    right: ':',
+    top: '-',
+    bottom: '-',
+};
+
+/// Render an empty, dim, dashed frame `width` columns wide and `height` rows
+/// tall, previewing where a box would be placed
+///
+/// The frame is drawn with [`render_edges`], so the border color and title
+/// from `options` still apply, but the border style, thickness, and dimming
+/// are forced and any pointer or scrollbar is dropped. Margins are not added.
+/// Sizes below 2 are raised to 2 so both edges fit. With `ascii_only` the
+/// frame is drawn with `+`, `-`, and `:` and the title is downgraded.
+///
+/// # Errors
+///
+/// Returns the same errors as [`render_edges`], for example
+/// `BoxenError::InvalidColor` for an unknown border or title color or an
+/// unresolved `Color::Auto`.
+///
+/// # Examples
+///
+/// ```rust
+/// use ::boxen::{BoxenOptions, render_placeholder, text::strip_ansi_codes};
+///
+/// let preview = render_placeholder(&BoxenOptions::default(), 5, 3).unwrap();
+/// assert_eq!(strip_ansi_codes(&preview), "┌╌╌╌┐\n╎   ╎\n└╌╌╌┘");
+/// ```
+pub fn render_placeholder(
+    options: &BoxenOptions,
+    width: usize,
+    height: usize,
+) -> BoxenResult<String> {
+    let mut frame = BoxenOptions {
+        border_style: BorderStyle::Custom(if options.ascii_only {
+            PLACEHOLDER_ASCII_BORDER
+        } else {
+            PLACEHOLDER_BORDER
+        }),
+        border_thickness: 1,
+        dim_border: true,
+        pointer: None,
+        scrollbar: None,
+        ..options.clone()
     };
-
-    match options.title_alignment {
-        TitleAlignment::Left => {
-            write!(result, "{}", style_border_char(border_chars.top_left)?).map_err(|e| {
-                crate::error::BoxenError::rendering_error(
-                    format!("Failed to write border: {e}"),
-                    vec![],
-                )
-            })?;
-            write!(result, "{styled_title}").map_err(|e| {
-                crate::error::BoxenError::rendering_error(
-                    format!("Failed to write title: {e}"),
-                    vec![],
-                )
-            })?;
-            let border_fill = border_chars.top.to_string().repeat(remaining_width);
-            write!(result, "{}", style_border_str(&border_fill)?).map_err(|e| {
-                crate::error::BoxenError::rendering_error(
-                    format!("Failed to write border: {e}"),
-                    vec![],
-                )
-            })?;
-            write!(result, "{}", style_border_char(border_chars.top_right)?).map_err(|e| {
-                crate::error::BoxenError::rendering_error(
-                    format!("Failed to write border: {e}"),
-                    vec![],
-                )
-            })?;
-        }
-        TitleAlignment::Right => {
-            write!(result, "{}", style_border_char(border_chars.top_left)?).map_err(|e| {
-                crate::error::BoxenError::rendering_error(
-                    format!("Failed to write border: {e}"),
-                    vec![],
-                )
-            })?;
-            let border_fill = border_chars.top.to_string().repeat(remaining_width);
-            write!(result, "{}", style_border_str(&border_fill)?).map_err(|e| {
-                crate::error::BoxenError::rendering_error(
-                    format!("Failed to write border: {e}"),
-                    vec![],
-                )
-            })?;
-            write!(result, "{styled_title}").map_err(|e| {
-                crate::error::BoxenError::rendering_error(
-                    format!("Failed to write title: {e}"),
-                    vec![],
-                )
-            })?;
-            write!(result, "{}", style_border_char(border_chars.top_right)?).map_err(|e| {
-                crate::error::BoxenError::rendering_error(
-                    format!("Failed to write border: {e}"),
-                    vec![],
-                )
-            })?;
-        }
-        TitleAlignment::Center => {
-            let left_padding = remaining_width / 2;
-            let right_padding = remaining_width - left_padding;
-
-            write!(result, "{}", style_border_char(border_chars.top_left)?).map_err(|e| {
-                crate::error::BoxenError::rendering_error(
-                    format!("Failed to write border: {e}"),
-                    vec![],
-                )
-            })?;
-            let left_border_fill = border_chars.top.to_string().repeat(left_padding);
-            write!(result, "{}", style_border_str(&left_border_fill)?).map_err(|e| {
-                crate::error::BoxenError::rendering_error(
-                    format!("Failed to write border: {e}"),
-                    vec![],
-                )
-            })?;
-            write!(result, "{styled_title}").map_err(|e| {
-                crate::error::BoxenError::rendering_error(
-                    format!("Failed to write title: {e}"),
-                    vec![],
-                )
-            })?;
-            let right_border_fill = border_chars.top.to_string().repeat(right_padding);
-            write!(result, "{}", style_border_str(&right_border_fill)?).map_err(|e| {
-                crate::error::BoxenError::rendering_error(
-                    format!("Failed to write border: {e}"),
-                    vec![],
-                )
-            })?;
-            write!(result, "{}", style_border_char(border_chars.top_right)?).map_err(|e| {
-                crate::error::BoxenError::rendering_error(
-                    format!("Failed to write border: {e}"),
-                    vec![],
-                )
-            })?;
-        }
+    if frame.ascii_only {
+        frame.title = frame
+            .title
+            .as_deref()
+            .map(|title| crate::text::to_ascii(title).into_owned());
     }
+    let inner_width = width.max(2) - 2;
+    let inner_height = height.max(2) - 2;
+
+    let edges = render_edges(&frame, inner_width, inner_height)?;
+
+    let blank = " ".repeat(inner_width);
+    let mut rows = Vec::with_capacity(inner_height + 2);
+    rows.push(edges.top);
+    rows.extend(
+        edges
+            .left
+            .iter()
+            .zip(&edges.right)
+            .map(|(left, right)| format!("{left}{blank}{right}")),
+    );
+    rows.push(edges.bottom);
+    Ok(rows.join("\n"))
+}
 
-    Ok(())
+/// Repeat every rendered line side by side until it fills `width` columns,
+/// trimming the final partial copy at a display-cell boundary
+fn tile_to_width(rendered: &str, width: usize, measure: Measure) -> String {
+    let tiled: Vec<String> = rendered
+        .split('\n')
+        .map(|line| {
+            let line_width = measure.text_width(line);
+            if line_width == 0 {
+                return line.to_string();
+            }
+            let copies = width.div_ceil(line_width);
+            crate::text::measurement::truncate_to_width_measured(
+                &line.repeat(copies),
+                width,
+                measure,
+            )
+        })
+        .collect();
+    tiled.join("\n")
 }
 
-/// Render the bottom border
-fn render_bottom_border(
-    border_chars: &crate::options::BorderChars,
-    inner_width: usize,
-    options: &BoxenOptions,
-) -> BoxenResult<String> {
-    with_pooled_string(|border| {
-        // Reserve capacity upfront
-        border.reserve(inner_width + 2);
+/// Extra left padding that lines content up under the first visible title glyph
+///
+/// Left-aligned titles start at a fixed column. Centered and right-aligned titles
+/// depend on the box width, so they are only supported when the width is fixed
+/// (via `width` or `fullscreen`); otherwise no indent is added.
+fn title_alignment_indent(options: &BoxenOptions, measure: Measure) -> BoxenResult<usize> {
+    let Some(title) = border_title(options) else {
+        return Ok(0);
+    };
 
-        border.push(border_chars.bottom_left);
-        for _ in 0..inner_width {
-            border.push(border_chars.bottom);
-        }
-        border.push(border_chars.bottom_right);
+    let has_border = options.border_style.is_visible();
+    let fixed_width = options.width.is_some() || options.fullscreen.is_some();
+    let inner_width = if fixed_width {
+        Some(options.calculate_max_content_width()? + options.padding.horizontal())
+    } else {
+        None
+    };
 
-        // Apply border color and dim styling
-        let styled_border = apply_color_with_dim(
-            border.as_str(),
-            options.border_color.as_ref(),
-            options.dim_border,
-        )?;
+    let connector_widths =
+        options
+            .title_connectors
+            .filter(|_| has_border)
+            .map_or((0, 0), |(left, right)| {
+                (
+                    measure.char_width(left, None).unwrap_or(1),
+                    measure.char_width(right, None).unwrap_or(1),
+                )
+            });
+    let margins = if has_border {
+        title_margin(options)
+    } else {
+        (0, 0)
+    };
+    let leading_whitespace = measure.text_width(title) - measure.text_width(title.trim_start());
+
+    let segment_start = match (options.title_alignment, inner_width) {
+        (TitleAlignment::Left, _) => 0,
+        (TitleAlignment::Center | TitleAlignment::Right, None) => return Ok(0),
+        (alignment, Some(inner_width)) => {
+            let segment_width = (measure.text_width(title)
+                + connector_widths.0
+                + connector_widths.1
+                + margins.0
+                + margins.1)
+                .min(inner_width);
+            let remaining = inner_width - segment_width;
+            if alignment == TitleAlignment::Center {
+                remaining / 2
+            } else {
+                remaining
+            }
+        }
+    };
 
-        Ok(styled_border.to_string())
-    })
+    let title_start = segment_start + margins.0 + connector_widths.0 + leading_whitespace;
+    Ok(title_start.saturating_sub(options.padding.left))
 }
 
-/// Render content lines with left and right borders and padding
-fn render_content_with_borders(
-    result: &mut String,
-    content: &ProcessedContent,
-    options: &BoxenOptions,
-    layout: &crate::options::LayoutDimensions,
-    border_chars: &crate::options::BorderChars,
-) -> BoxenResult<()> {
-    // Add top padding
-    for _ in 0..options.padding.top {
-        let padded_line = render_padded_empty_line(border_chars, layout.inner_width, options)?;
-        add_line_with_float_positioning(result, &padded_line, options, layout);
+/// Border characters to keep on each side of the title, including the single
+/// fill character required by `title_require_fill`
+fn title_margin(options: &BoxenOptions) -> (usize, usize) {
+    let (left, right) = options.title_margin;
+    if options.title_require_fill {
+        (left.max(1), right.max(1))
+    } else {
+        (left, right)
     }
+}
 
-    // Render content lines
-    for line in &content.lines {
-        let content_line = render_content_line(line, border_chars, options, layout.inner_width)?;
-        add_line_with_float_positioning(result, &content_line, options, layout);
+/// Split a leading alignment marker such as `\x01C\x01` off a content line
+///
+/// `L`, `C`, and `R` between two delimiters select left, center, and right
+/// alignment. Lines without a recognized marker are returned unchanged.
+fn split_alignment_marker(line: &str, delimiter: char) -> (Option<TextAlignment>, &str) {
+    let mut chars = line.chars();
+    if chars.next() != Some(delimiter) {
+        return (None, line);
     }
-
-    // Add bottom padding
-    for _ in 0..options.padding.bottom {
-        let padded_line = render_padded_empty_line(border_chars, layout.inner_width, options)?;
-        add_line_with_float_positioning(result, &padded_line, options, layout);
+    let alignment = match chars.next() {
+        Some('L') => TextAlignment::Left,
+        Some('C') => TextAlignment::Center,
+        Some('R') => TextAlignment::Right,
+        _ => return (None, line),
+    };
+    if chars.next() != Some(delimiter) {
+        return (None, line);
     }
+    (Some(alignment), chars.as_str())
+}
 
-    Ok(())
+/// Processed text content with dimensions
+#[derive(Debug)]
+struct ProcessedContent {
+    lines: Vec<String>,
+    /// Columns the text was wrapped to, or clipped to without wrapping
+    wrap_width: usize,
+    /// Whether the height constraint dropped some of the lines
+    truncated: bool,
+    content_width: usize,
+    content_height: usize,
 }
 
-/// Render content without borders (border style is None)
-fn render_content_without_borders(
-    result: &mut String,
-    content: &ProcessedContent,
+/// Process text content according to options
+fn process_content(
+    text: &str,
     options: &BoxenOptions,
-    layout: &crate::options::LayoutDimensions,
-) -> BoxenResult<()> {
-    // Render title if present (requirement 5.4)
-    if let Some(title) = &options.title {
-        let title_line = render_title_without_border(title, options, layout.inner_width)?;
-        add_line_with_float_positioning(result, &title_line, options, layout);
+    measure: Measure,
+) -> BoxenResult<ProcessedContent> {
+    let text = match options.normalize {
+        Some(form) => crate::text::normalize(text, form),
+        None => std::borrow::Cow::Borrowed(text),
+    };
+    const VERTICAL_WHITESPACE: [char; 2] = ['\x0b', '\x0c'];
+    let text = match options.vertical_whitespace {
+        VerticalWhitespace::LineBreak if text.contains(VERTICAL_WHITESPACE) => {
+            std::borrow::Cow::Owned(text.replace(VERTICAL_WHITESPACE, "\n"))
+        }
+        VerticalWhitespace::Strip if text.contains(VERTICAL_WHITESPACE) => {
+            std::borrow::Cow::Owned(text.replace(VERTICAL_WHITESPACE, ""))
+        }
+        _ => text,
+    };
+    // Columns are padded from the first tab, so they are laid out before tabs expand
+    let columns = options.columns && text.contains('\t');
+    let text = if columns {
+        std::borrow::Cow::Owned(align_columns(&text, measure))
+    } else {
+        text
+    };
+    let wrap = options.wrap && !options.pre_wrapped && !columns;
+    let text = match options.tab_width {
+        Some(tab_width) => {
+            match crate::text::measurement::expand_tabs_measured(&text, tab_width, measure) {
+                std::borrow::Cow::Owned(expanded) => std::borrow::Cow::Owned(expanded),
+                std::borrow::Cow::Borrowed(_) => text,
+            }
+        }
+        None => text,
+    };
+    let text = if options.collapse_whitespace {
+        std::borrow::Cow::Owned(collapse_inner_whitespace(&text))
+    } else {
+        text
+    };
+    let text = text.as_ref();
+
+    // Calculate maximum content width available, reserving room for line affixes
+    let affix_width = options
+        .line_prefix
+        .as_deref()
+        .map_or(0, |prefix| measure.text_width(prefix))
+        + options
+            .line_suffix
+            .as_deref()
+            .map_or(0, |suffix| measure.text_width(suffix));
+    let max_content_width = options.calculate_max_content_width()?;
+    if affix_width > 0 && max_content_width <= affix_width {
+        return Err(crate::error::BoxenError::invalid_dimensions(
+            format!(
+                "Content width {max_content_width} leaves no room for text after the line prefix and suffix ({affix_width} columns)"
+            ),
+            Some(max_content_width),
+            None,
+            vec![crate::error::ErrorRecommendation::suggestion_only(
+                "Line affixes too wide".to_string(),
+                "Use a shorter line_prefix/line_suffix or a wider box".to_string(),
+            )],
+        ));
     }
+    let max_content_width = max_content_width - affix_width;
+
+    // Wrap at the reading measure when one is set, but align within the full
+    // width. A box with no content columns is reported by wrapping, as without
+    // a measure.
+    let wrap_width = match options.content_width {
+        Some(measure) if max_content_width > 0 => measure.clamp(1, max_content_width),
+        _ => max_content_width,
+    };
 
-    // Add top padding
-    for _ in 0..options.padding.top {
-        let empty_line = " ".repeat(layout.inner_width);
-        let styled_line = if let Some(bg_color) = &options.background_color {
-            apply_colors(&empty_line, None, Some(bg_color))?.to_string()
-        } else {
-            empty_line
-        };
-        add_line_with_float_positioning(result, &styled_line, options, layout);
+    // Calculate maximum content height available
+    let max_content_height = options.calculate_max_content_height()?;
+
+    // Wrap text if needed
+    let mut line_alignments = Vec::new();
+    let mut wrapped_lines = if text.is_empty() {
+        vec![String::new()]
+    } else if let Some(delimiter) = options.alignment_marker {
+        // Markers are stripped per source line before wrapping, and every wrapped
+        // row of a marked line inherits its alignment
+        let mut lines = Vec::new();
+        for line in text.lines() {
+            let (alignment, line) = split_alignment_marker(line, delimiter);
+            let rows = if wrap {
+                crate::text::wrapping::wrap_line_measured(line, wrap_width, measure)
+            } else {
+                vec![fit_unwrapped_line(
+                    line,
+                    max_content_width,
+                    options,
+                    measure,
+                )?]
+            };
+            line_alignments.extend(std::iter::repeat_n(alignment, rows.len()));
+            lines.extend(rows);
+        }
+        lines
+    } else if !wrap {
+        text.lines()
+            .map(|line| fit_unwrapped_line(line, max_content_width, options, measure))
+            .collect::<BoxenResult<_>>()?
+    } else {
+        wrap_text_measured(text, wrap_width, measure)?
+    };
+
+    // Line splitting swallows the final newline; keep it as a blank row when asked
+    if options.preserve_blank_edges && text.ends_with('\n') {
+        wrapped_lines.push(String::new());
     }
 
-    // Render content lines with padding
-    for line in &content.lines {
-        let padded_line = with_pooled_string(|buffer| {
-            // Reserve capacity upfront
-            buffer.reserve(options.padding.left + line.len() + options.padding.right);
+    // Calculate the natural width of the content
+    let natural_content_width = wrapped_lines
+        .iter()
+        .map(|line| measure.text_width(line))
+        .max()
+        .unwrap_or(0);
 
-            // Build padded line using write! macro
-            for _ in 0..options.padding.left {
-                buffer.push(' ');
-            }
-            buffer.push_str(line);
-            for _ in 0..options.padding.right {
-                buffer.push(' ');
-            }
+    // Determine target width based on options
+    let target_width = if options.fullscreen.is_some() || options.width.is_some() {
+        // For fullscreen mode or specified width, use the maximum available content width
+        max_content_width
+    } else {
+        // For normal mode, use the smaller of natural width or max width for better layout
+        natural_content_width.min(max_content_width)
+    };
 
-            buffer.as_str().to_string()
-        });
-        let styled_line = if let Some(bg_color) = &options.background_color {
-            apply_colors(&padded_line, None, Some(bg_color))?.to_string()
-        } else {
-            padded_line
-        };
-        add_line_with_float_positioning(result, &styled_line, options, layout);
+    // Apply height constraints if specified
+    let truncated = max_content_height.is_some_and(|max_height| wrapped_lines.len() > max_height);
+    let height_constrained_lines = if let Some(max_height) = max_content_height {
+        crate::text::apply_height_constraints(&wrapped_lines, max_height)
+    } else {
+        wrapped_lines
+    };
+
+    // Apply text alignment without padding (padding will be applied during rendering)
+    let aligned_lines = height_constrained_lines
+        .iter()
+        .enumerate()
+        .map(|(index, line)| {
+            // Explicit markers win over numeric detection
+            let alignment = line_alignments
+                .get(index)
+                .copied()
+                .flatten()
+                .or_else(|| {
+                    (options.numeric_align && crate::text::is_numeric_line(line))
+                        .then_some(TextAlignment::Right)
+                })
+                .map(|alignment| {
+                    if options.mirror {
+                        mirror_text_alignment(alignment)
+                    } else {
+                        alignment
+                    }
+                })
+                .unwrap_or(options.text_alignment);
+            crate::text::alignment::align_line_measured(line, alignment, target_width, ' ', measure)
+        })
+        .collect();
+
+    let aligned_lines = apply_line_affixes(aligned_lines, options);
+
+    let content_height = aligned_lines.len();
+
+    Ok(ProcessedContent {
+        lines: aligned_lines,
+        wrap_width: if wrap { wrap_width } else { max_content_width },
+        truncated,
+        content_width: target_width + affix_width,
+        content_height,
+    })
+}
+
+/// Columns between the end of the widest first cell and the second column
+const COLUMN_GAP: usize = 2;
+
+/// Replace the tabs in each line with the spaces that start every cell at
+/// its column; lines without a tab are kept as written
+///
+/// A column is as wide as its widest cell that is followed by a tab, so the
+/// last cell of a line never widens the columns of other lines.
+fn align_columns(text: &str, measure: Measure) -> String {
+    let mut widths: Vec<usize> = Vec::new();
+    for line in text.split('\n') {
+        let mut cells: Vec<&str> = line.split('\t').collect();
+        cells.pop();
+        for (column, cell) in cells.into_iter().enumerate() {
+            if column == widths.len() {
+                widths.push(0);
+            }
+            widths[column] = widths[column].max(measure.text_width(cell));
+        }
     }
 
-    // Add bottom padding
-    for _ in 0..options.padding.bottom {
-        let empty_line = " ".repeat(layout.inner_width);
-        let styled_line = if let Some(bg_color) = &options.background_color {
-            apply_colors(&empty_line, None, Some(bg_color))?.to_string()
-        } else {
-            empty_line
-        };
-        add_line_with_float_positioning(result, &styled_line, options, layout);
+    let mut aligned = String::with_capacity(text.len());
+    for (index, line) in text.split('\n').enumerate() {
+        if index > 0 {
+            aligned.push('\n');
+        }
+        let mut cells = line.split('\t').peekable();
+        let mut column = 0;
+        while let Some(cell) = cells.next() {
+            aligned.push_str(cell);
+            if cells.peek().is_some() {
+                let pad = widths[column] - measure.text_width(cell) + COLUMN_GAP;
+                aligned.extend(std::iter::repeat_n(' ', pad));
+            }
+            column += 1;
+        }
     }
+    aligned
+}
 
-    Ok(())
+/// Replace each run of spaces and tabs after a line's indentation with one space
+fn collapse_inner_whitespace(text: &str) -> String {
+    let mut collapsed = String::with_capacity(text.len());
+    for (index, line) in text.split('\n').enumerate() {
+        if index > 0 {
+            collapsed.push('\n');
+        }
+        let body = line.trim_start_matches([' ', '\t']);
+        collapsed.push_str(&line[..line.len() - body.len()]);
+
+        let mut in_run = false;
+        for ch in body.chars() {
+            if ch == ' ' || ch == '\t' {
+                if !in_run {
+                    collapsed.push(' ');
+                }
+                in_run = true;
+            } else {
+                collapsed.push(ch);
+                in_run = false;
+            }
+        }
+    }
+    collapsed
 }
 
-/// Render a single content line with borders and padding
-fn render_content_line(
+/// Apply `overflow_no_wrap` to a line that is not being wrapped
+fn fit_unwrapped_line(
     line: &str,
-    border_chars: &crate::options::BorderChars,
+    width: usize,
     options: &BoxenOptions,
-    inner_width: usize,
+    measure: Measure,
 ) -> BoxenResult<String> {
-    with_pooled_string(|content_area| {
-        // Reserve capacity upfront for the entire line
-        content_area.reserve(inner_width);
+    let line_width = measure.text_width(line);
+    if line_width <= width {
+        return Ok(line.to_string());
+    }
 
-        // Left padding
-        for _ in 0..options.padding.left {
-            content_area.push(' ');
+    match options.overflow_no_wrap {
+        crate::options::NoWrapOverflow::Error => {
+            Err(crate::error::BoxenError::text_processing_error(
+                format!(
+                    "Line is {line_width} columns wide but wrapping is disabled and the content width is {width}"
+                ),
+                vec![crate::error::ErrorRecommendation::suggestion_only(
+                    "Line too long".to_string(),
+                    "Enable wrapping, widen the box, or use NoWrapOverflow::Clip".to_string(),
+                )],
+            ))
         }
+        crate::options::NoWrapOverflow::Clip => Ok(
+            crate::text::measurement::truncate_to_width_measured(line, width, measure),
+        ),
+        crate::options::NoWrapOverflow::Overflow => Ok(line.to_string()),
+    }
+}
 
-        // Content
-        content_area.push_str(line);
+/// Wrap each aligned content line in the configured `line_prefix`/`line_suffix`
+fn apply_line_affixes(lines: Vec<String>, options: &BoxenOptions) -> Vec<String> {
+    if options.line_prefix.is_none() && options.line_suffix.is_none() {
+        return lines;
+    }
 
-        // Right padding (fill to inner width)
-        let current_content_width = text_width(content_area.as_str());
-        let remaining_width = inner_width - current_content_width;
-        for _ in 0..remaining_width {
-            content_area.push(' ');
+    let prefix = options.line_prefix.as_deref().unwrap_or_default();
+    let suffix = options.line_suffix.as_deref().unwrap_or_default();
+    lines
+        .into_iter()
+        .map(|line| format!("{prefix}{line}{suffix}"))
+        .collect()
+}
+
+/// Where a [`BoxRows`] is within the box, from the first output row to the last
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum RowState {
+    TitleAbove,
+    TopMargin(usize),
+    TopBorder,
+    /// The title line of a box without a border
+    BorderlessTitle,
+    /// A row between the top and bottom borders: padding or content, counted
+    /// from the first top padding row
+    Track(usize),
+    BottomBorder,
+    BottomMargin(usize),
+    Caption,
+    Done,
+}
+
+/// The rows of a laid-out box, each rendered only when it is requested
+///
+/// Rows carry no line terminator. The shared state (border characters,
+/// scrollbar thumb, vertical title, shadow colors) is resolved once up front.
+struct BoxRows {
+    options: BoxenOptions,
+    content: ProcessedContent,
+    layout: crate::options::LayoutDimensions,
+    measure: Measure,
+    border_chars: crate::options::BorderChars,
+    thumb: std::ops::Range<usize>,
+    vertical_title: (usize, Vec<char>),
+    /// Options for the top and bottom padding rows of an inset shadow
+    shadow: Option<(BoxenOptions, BoxenOptions)>,
+    state: RowState,
+}
+
+impl BoxRows {
+    fn new(
+        options: BoxenOptions,
+        content: ProcessedContent,
+        layout: crate::options::LayoutDimensions,
+        measure: Measure,
+    ) -> BoxenResult<Self> {
+        let mut border_chars = options.border_style.get_chars()?;
+        if options.mirror {
+            // Side borders are swapped per row, after the scrollbar and title
+            let chars = &mut border_chars;
+            (chars.top_left, chars.top_right) = (chars.top_right, chars.top_left);
+            (chars.bottom_left, chars.bottom_right) = (chars.bottom_right, chars.bottom_left);
         }
 
-        // Apply background color to content area if specified
-        let styled_content = if let Some(bg_color) = &options.background_color {
-            apply_colors(content_area.as_str(), None, Some(bg_color))?.to_string()
-        } else {
-            content_area.as_str().to_string()
+        // The scrollbar track spans every row between the top and bottom borders
+        let track_len = options.padding.vertical() + content.lines.len();
+        let thumb = options
+            .scrollbar
+            .map_or(0..0, |scrollbar| scrollbar.thumb_range(track_len));
+        let vertical_title = vertical_title_rows(&options, track_len);
+
+        // An inset shadow shades whole top padding rows dark and bottom ones light
+        let shadow = options.inset_shadow.then(|| {
+            let (dark, light) = inset_shadow_colors();
+            (
+                BoxenOptions {
+                    background_color: Some(dark),
+                    ..options.clone()
+                },
+                BoxenOptions {
+                    background_color: Some(light),
+                    ..options.clone()
+                },
+            )
+        });
+
+        let mut rows = Self {
+            options,
+            content,
+            layout,
+            measure,
+            border_chars,
+            thumb,
+            vertical_title,
+            shadow,
+            state: RowState::TitleAbove,
         };
+        rows.state = rows.skip_absent(RowState::TitleAbove);
+        Ok(rows)
+    }
 
-        // Build borders separately and apply border styling
-        let left_border = apply_color_with_dim(
-            &border_chars.left.to_string(),
-            options.border_color.as_ref(),
-            options.dim_border,
-        )?
-        .to_string();
+    fn track_len(&self) -> usize {
+        self.options.padding.vertical() + self.content.lines.len()
+    }
 
-        let right_border = apply_color_with_dim(
-            &border_chars.right.to_string(),
-            options.border_color.as_ref(),
-            options.dim_border,
-        )?
-        .to_string();
+    fn has_border(&self) -> bool {
+        self.options.border_style.is_visible()
+    }
 
-        // Combine borders and content using write! macro with pooled buffer
-        with_pooled_string(|result| {
-            result.reserve(left_border.len() + styled_content.len() + right_border.len());
-            write!(result, "{left_border}{styled_content}{right_border}").map_err(|e| {
-                crate::error::BoxenError::rendering_error(
-                    format!("Failed to write content line: {e}"),
-                    vec![],
-                )
-            })?;
-            Ok(result.as_str().to_string())
-        })
-    })
-}
+    /// The state after `state`, whether or not that row is drawn
+    fn successor(&self, state: RowState) -> RowState {
+        match state {
+            RowState::TitleAbove => RowState::TopMargin(0),
+            RowState::TopMargin(index) if index + 1 < self.options.margin.top => {
+                RowState::TopMargin(index + 1)
+            }
+            RowState::TopMargin(_) => RowState::TopBorder,
+            RowState::TopBorder => RowState::BorderlessTitle,
+            RowState::BorderlessTitle => RowState::Track(0),
+            RowState::Track(row) if row + 1 < self.track_len() => RowState::Track(row + 1),
+            RowState::Track(_) => RowState::BottomBorder,
+            RowState::BottomBorder => RowState::BottomMargin(0),
+            RowState::BottomMargin(index) if index + 1 < self.options.margin.bottom => {
+                RowState::BottomMargin(index + 1)
+            }
+            RowState::BottomMargin(_) => RowState::Caption,
+            RowState::Caption | RowState::Done => RowState::Done,
+        }
+    }
 
-/// Render an empty line with borders and padding (for top/bottom padding)
-fn render_padded_empty_line(
-    border_chars: &crate::options::BorderChars,
-    inner_width: usize,
-    options: &BoxenOptions,
-) -> BoxenResult<String> {
-    with_pooled_string(|content_area| {
-        // Reserve capacity upfront
-        content_area.reserve(inner_width);
+    /// Whether the box has a row for `state`
+    fn is_present(&self, state: RowState) -> bool {
+        let options = &self.options;
+        match state {
+            RowState::TitleAbove => {
+                options.title.is_some() && options.title_position == TitlePosition::Above
+            }
+            RowState::TopMargin(index) => index < options.margin.top,
+            RowState::TopBorder | RowState::BottomBorder => self.has_border(),
+            RowState::BorderlessTitle => !self.has_border() && border_title(options).is_some(),
+            RowState::Track(row) => row < self.track_len(),
+            RowState::BottomMargin(index) => index < options.margin.bottom,
+            RowState::Caption => options.caption.is_some(),
+            RowState::Done => true,
+        }
+    }
 
-        // Build the content area (all spaces)
-        for _ in 0..inner_width {
-            content_area.push(' ');
+    /// The first state from `state` on that has a row
+    fn skip_absent(&self, mut state: RowState) -> RowState {
+        while !self.is_present(state) {
+            state = self.successor(state);
         }
+        state
+    }
 
-        // Apply background color to content area if specified
-        let styled_content = if let Some(bg_color) = &options.background_color {
-            apply_colors(content_area.as_str(), None, Some(bg_color))?.to_string()
-        } else {
-            content_area.as_str().to_string()
+    /// Render the row for a present, unfinished `state`
+    fn render(&self, state: RowState) -> BoxenResult<String> {
+        let options = &self.options;
+        let layout = &self.layout;
+        let measure = self.measure;
+        let box_width = layout.total_width - options.margin.horizontal();
+        let line = match state {
+            RowState::TopMargin(_) | RowState::BottomMargin(_) | RowState::Done => {
+                return Ok(String::new());
+            }
+            RowState::TitleAbove => {
+                let title = options.title.as_deref().unwrap_or_default();
+                render_label_line(title, options.title_alignment, options, box_width, measure)?
+            }
+            RowState::TopBorder => {
+                render_top_border(&self.border_chars, options, layout.inner_width, measure)?
+            }
+            RowState::BorderlessTitle => {
+                let title = border_title(options).map_or("", String::as_str);
+                render_title_without_border(title, options, layout.inner_width, measure)?
+            }
+            RowState::Track(row) if self.has_border() => self.render_bordered_row(row)?,
+            RowState::Track(row) => self.render_borderless_row(row)?,
+            RowState::BottomBorder => {
+                let marker = options
+                    .truncation_indicator
+                    .filter(|_| self.content.truncated);
+                render_marked_bottom_border(
+                    &self.border_chars,
+                    layout.inner_width,
+                    options,
+                    marker,
+                )?
+            }
+            RowState::Caption => {
+                let caption = options.caption.as_deref().unwrap_or_default();
+                render_label_line(
+                    caption,
+                    options.caption_alignment,
+                    options,
+                    box_width,
+                    measure,
+                )?
+            }
         };
+        Ok(float_position_line(&line, options, layout, measure))
+    }
 
-        // Build borders separately and apply border styling
-        let left_border = apply_color_with_dim(
-            &border_chars.left.to_string(),
-            options.border_color.as_ref(),
-            options.dim_border,
-        )?
-        .to_string();
+    /// The border characters for track row `row`, with the scrollbar thumb
+    /// and any vertical title glyph in place
+    fn row_chars(&self, row: usize) -> crate::options::BorderChars {
+        let mut chars = self.border_chars;
+        if self.thumb.contains(&row) {
+            chars.right = scrollbar_thumb(&self.options);
+        }
+        if let Some(&ch) = row
+            .checked_sub(self.vertical_title.0)
+            .and_then(|index| self.vertical_title.1.get(index))
+        {
+            chars.left = ch;
+        }
+        if self.options.mirror {
+            (chars.left, chars.right) = (chars.right, chars.left);
+        }
+        chars
+    }
 
-        let right_border = apply_color_with_dim(
-            &border_chars.right.to_string(),
-            options.border_color.as_ref(),
-            options.dim_border,
-        )?
-        .to_string();
+    /// A padding or content row between the left and right borders
+    fn render_bordered_row(&self, row: usize) -> BoxenResult<String> {
+        let options = &self.options;
+        let inner_width = self.layout.inner_width;
+        let chars = self.row_chars(row);
+        match self.content_index(row) {
+            Ok(index) => render_content_line(
+                &self.content.lines[index],
+                &chars,
+                options,
+                inner_width,
+                self.measure,
+            ),
+            Err(below) => {
+                let padding_options = match &self.shadow {
+                    Some((dark, light)) => {
+                        if below {
+                            light
+                        } else {
+                            dark
+                        }
+                    }
+                    None => options,
+                };
+                render_padded_empty_line(&chars, inner_width, padding_options)
+            }
+        }
+    }
 
-        // Combine borders and content using write! macro with pooled buffer
-        with_pooled_string(|result| {
-            result.reserve(left_border.len() + styled_content.len() + right_border.len());
-            write!(result, "{left_border}{styled_content}{right_border}").map_err(|e| {
-                crate::error::BoxenError::rendering_error(
-                    format!("Failed to write padded line: {e}"),
-                    vec![],
+    /// A padding or content row of a box without a border. Every row, blank
+    /// or not, spans the full inner width so background colors form a solid
+    /// block.
+    fn render_borderless_row(&self, row: usize) -> BoxenResult<String> {
+        let options = &self.options;
+        let inner_width = self.layout.inner_width;
+        let Ok(index) = self.content_index(row) else {
+            let empty_line = " ".repeat(inner_width);
+            return Ok(if let Some(bg_color) = &options.background_color {
+                paint(
+                    &apply_colors(&empty_line, None, Some(bg_color))?,
+                    options.force_color,
                 )
-            })?;
-            Ok(result.as_str().to_string())
-        })
-    })
+            } else {
+                empty_line
+            });
+        };
+
+        let line = &self.content.lines[index];
+        let padded_line = with_pooled_string(|buffer| {
+            // Reserve capacity upfront
+            buffer.reserve(inner_width + line.len());
+
+            for _ in 0..options.padding.left {
+                buffer.push(' ');
+            }
+            buffer.push_str(line);
+
+            // Right padding fills to the inner width; overflowing lines keep their length
+            let remaining = inner_width.saturating_sub(self.measure.text_width(buffer.as_str()));
+            for _ in 0..remaining {
+                buffer.push(' ');
+            }
+
+            buffer.as_str().to_string()
+        });
+        style_content_area(padded_line, options)
+    }
+
+    /// The content line shown on track row `row`, or whether a padding row
+    /// lies below the content
+    fn content_index(&self, row: usize) -> Result<usize, bool> {
+        match row.checked_sub(self.options.padding.top) {
+            Some(index) if index < self.content.lines.len() => Ok(index),
+            Some(_) => Err(true),
+            None => Err(false),
+        }
+    }
 }
 
-/// Render title without border (for `BorderStyle::None`)
-fn render_title_without_border(
-    title: &str,
+impl Iterator for BoxRows {
+    type Item = BoxenResult<String>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let state = self.state;
+        if state == RowState::Done {
+            return None;
+        }
+        self.state = self.skip_absent(self.successor(state));
+        Some(self.render(state))
+    }
+}
+
+/// Render the complete box with borders, content, and margins
+fn render_box(rows: BoxRows) -> BoxenResult<String> {
+    // A bottom margin keeps its final newline unless a caption follows it
+    let trailing_newline = rows.options.margin.bottom > 0 && rows.options.caption.is_none();
+    let mut result = rows.collect::<BoxenResult<Vec<_>>>()?.join("\n");
+    if trailing_newline {
+        result.push('\n');
+    }
+    Ok(result)
+}
+
+/// The title to draw in the top border, unless it is placed above the box
+fn border_title(options: &BoxenOptions) -> Option<&String> {
+    options
+        .title
+        .as_ref()
+        .filter(|_| options.title_position == TitlePosition::Border)
+}
+
+/// Render a standalone label line (such as a caption) aligned within `width`
+///
+/// The label is truncated to fit and colored with `title_color` when set;
+/// the alignment padding is left unstyled.
+fn render_label_line(
+    label: &str,
+    alignment: TitleAlignment,
     options: &BoxenOptions,
-    inner_width: usize,
+    width: usize,
+    measure: Measure,
 ) -> BoxenResult<String> {
-    let title_width = text_width(title);
-
-    // If title is too long, truncate it - use pooled buffer for pre-allocation
-    let effective_title = if title_width > inner_width {
-        with_pooled_string(|truncated| {
-            truncated.reserve(inner_width);
-            let mut current_width = 0;
-            for ch in title.chars() {
-                // Calculate char width directly without allocation
-                let char_width = UnicodeWidthChar::width(ch).unwrap_or(1);
-                if current_width + char_width > inner_width {
-                    break;
-                }
-                truncated.push(ch);
-                current_width += char_width;
-            }
-            truncated.as_str().to_string()
-        })
+    let label = if measure.text_width(label) > width {
+        truncate_title(label, width, options, measure)
     } else {
-        title.to_string()
+        label.to_string()
     };
 
-    let effective_title_width = text_width(&effective_title);
-    let remaining_width = inner_width - effective_title_width;
+    let remaining_width = width - measure.text_width(&label);
+    let left_padding = match alignment {
+        TitleAlignment::Left => 0,
+        TitleAlignment::Center => remaining_width / 2,
+        TitleAlignment::Right => remaining_width,
+    };
+    let right_padding = remaining_width - left_padding;
 
-    let title_line = with_pooled_string(|buffer| {
-        buffer.reserve(inner_width);
-        match options.title_alignment {
-            TitleAlignment::Left => {
-                write!(buffer, "{effective_title}").map_err(|e| {
-                    crate::error::BoxenError::rendering_error(
-                        format!("Failed to write title: {e}"),
-                        vec![],
-                    )
-                })?;
-                for _ in 0..remaining_width {
-                    buffer.push(' ');
+    let styled_label = if let Some(color) = &options.title_color {
+        paint(
+            &apply_colors(&label, Some(color), None)?,
+            options.force_color,
+        )
+    } else {
+        label
+    };
+
+    Ok(format!(
+        "{}{styled_label}{}",
+        " ".repeat(left_padding),
+        " ".repeat(right_padding)
+    ))
+}
+
+/// Render the top border with optional title embedding
+fn render_top_border(
+    border_chars: &crate::options::BorderChars,
+    options: &BoxenOptions,
+    inner_width: usize,
+    measure: Measure,
+) -> BoxenResult<String> {
+    let inner_width = border_span(inner_width, options);
+    with_pooled_string(|border| {
+        // Reserve capacity upfront
+        border.reserve(inner_width + 2);
+
+        if let Some(title) = border_title(options) {
+            // Render top border with title - this handles color application
+            // internally. The title shares the fill left between the bevels.
+            let bevel = bevel_cells(options, inner_width);
+            render_top_border_with_title_colored(
+                border,
+                title,
+                border_chars,
+                options,
+                inner_width - 2 * bevel,
+                bevel,
+                measure,
+            )?;
+        } else {
+            // No title - build border and apply color to entire thing
+            border.push(border_chars.top_left);
+            push_border_fill(border, border_chars.top, inner_width, options, Edge::Top);
+            border.push(border_chars.top_right);
+
+            // Apply border color and dim styling to entire border
+            let styled_border = apply_color_with_dim(
+                border.as_str(),
+                options.border_color.as_ref(),
+                options.dim_border,
+            )?;
+
+            return Ok(paint(&styled_border, options.force_color));
+        }
+
+        Ok(border.as_str().to_string())
+    })
+}
+
+/// Shorten a title to `max_width` columns
+///
+/// Boxes capped by `max_width` end the shortened title with an ellipsis, when
+/// there is room for one; otherwise the title is simply cut.
+fn truncate_title(
+    title: &str,
+    max_width: usize,
+    options: &BoxenOptions,
+    measure: Measure,
+) -> String {
+    let ellipsis = if options.ascii_only { "..." } else { "…" };
+    let ellipsis_width = measure.text_width(ellipsis);
+    if options.max_width.is_none()
+        || measure.text_width(title) <= max_width
+        || max_width <= ellipsis_width
+    {
+        return cut_to_width(title, max_width, measure);
+    }
+
+    let mut truncated = cut_to_width(title, max_width - ellipsis_width, measure);
+    truncated.push_str(ellipsis);
+    truncated
+}
+
+/// Keep the leading characters of `title` that fit in `max_width` columns
+///
+/// Emoji presentation selectors stay attached to their base character so a
+/// truncated title never ends in a half-measured sequence. Escape sequences
+/// before the cut are kept whole, and a style still open at the cut is reset
+/// so it cannot run into the border.
+fn cut_to_width(title: &str, max_width: usize, measure: Measure) -> String {
+    use crate::text::ansi::{AnsiToken, tokenize};
+
+    // Use pooled buffer for truncation
+    with_pooled_string(|truncated| {
+        truncated.reserve(max_width);
+        let mut current_width = 0;
+        let mut style_open = false;
+        'tokens: for token in tokenize(title) {
+            let text = match token {
+                AnsiToken::Text(text) => text,
+                AnsiToken::Sgr(sequence) => {
+                    style_open = !matches!(sequence, "\x1b[0m" | "\x1b[m");
+                    truncated.push_str(sequence);
+                    continue;
                 }
-            }
-            TitleAlignment::Right => {
-                for _ in 0..remaining_width {
-                    buffer.push(' ');
+                AnsiToken::Csi(sequence) | AnsiToken::Osc(sequence) => {
+                    truncated.push_str(sequence);
+                    continue;
                 }
-                write!(buffer, "{effective_title}").map_err(|e| {
-                    crate::error::BoxenError::rendering_error(
-                        format!("Failed to write title: {e}"),
-                        vec![],
-                    )
-                })?;
-            }
-            TitleAlignment::Center => {
-                let left_padding = remaining_width / 2;
-                let right_padding = remaining_width - left_padding;
-                for _ in 0..left_padding {
-                    buffer.push(' ');
+            };
+            let mut chars = text.chars().peekable();
+            while let Some(ch) = chars.next() {
+                // Calculate char width directly without allocation
+                let char_width = measure.char_width(ch, chars.peek().copied()).unwrap_or(1);
+                if current_width + char_width > max_width {
+                    break 'tokens;
                 }
-                write!(buffer, "{effective_title}").map_err(|e| {
-                    crate::error::BoxenError::rendering_error(
-                        format!("Failed to write title: {e}"),
-                        vec![],
-                    )
-                })?;
-                for _ in 0..right_padding {
-                    buffer.push(' ');
+                truncated.push(ch);
+                if let Some(selector) = chars.next_if_eq(&EMOJI_PRESENTATION_SELECTOR) {
+                    truncated.push(selector);
                 }
+                current_width += char_width;
             }
         }
-        Ok::<String, crate::error::BoxenError>(buffer.as_str().to_string())
-    })?;
+        if style_open {
+            truncated.push_str("\x1b[0m");
+        }
+        truncated.as_str().to_string()
+    })
+}
+
+/// Render top border with embedded title, applying colors correctly
+#[allow(clippy::too_many_lines)]
+fn render_top_border_with_title_colored(
+    result: &mut impl Write,
+    title: &str,
+    border_chars: &crate::options::BorderChars,
+    options: &BoxenOptions,
+    inner_width: usize,
+    bevel: usize,
+    measure: Measure,
+) -> BoxenResult<()> {
+    let connector_pair_width = |left: char, right: char| {
+        measure.char_width(left, None).unwrap_or(1) + measure.char_width(right, None).unwrap_or(1)
+    };
+    // Connectors are only drawn when there is room for both of them
+    let connectors = options
+        .title_connectors
+        .filter(|&(left, right)| connector_pair_width(left, right) <= inner_width);
+    let connector_width = connectors.map_or(0, |(left, right)| connector_pair_width(left, right));
+    // Margins are border fill reserved next to the title, dropped when they don't fit
+    let (margin_left, margin_right) = Some(title_margin(options))
+        .filter(|(left, right)| connector_width + left + right < inner_width)
+        .unwrap_or((0, 0));
+    let title_space = inner_width - connector_width - margin_left - margin_right;
+
+    let title_width = measure.text_width(title);
+
+    // If title is too long, truncate it
+    let effective_title = if title_width > title_space {
+        truncate_title(title, title_space, options, measure)
+    } else {
+        title.to_string()
+    };
+
+    let effective_title_width =
+        measure.text_width(&effective_title) + connector_width + margin_left + margin_right;
+    let remaining_width = inner_width - effective_title_width;
+
+    // Apply title color with fallback chain: title_color → border_color → None.
+    // A title with its own escape sequences is already styled and kept as is.
+    let pre_styled = effective_title.contains('\x1b');
+    let title_color = options
+        .title_color
+        .as_ref()
+        .or(options.border_color.as_ref())
+        .filter(|_| !pre_styled);
+
+    // A full-width title paints the box background across the whole border row
+    let header_background = options
+        .background_color
+        .as_ref()
+        .filter(|_| options.title_full_width);
+
+    let gradient = options.title_gradient.as_ref().filter(|_| !pre_styled);
+    let mut styled_title = if pre_styled {
+        effective_title.clone()
+    } else if let Some((start, end)) = gradient {
+        crate::color::gradient_text_on(
+            &effective_title,
+            start,
+            end,
+            header_background,
+            options.force_color,
+        )?
+    } else if title_color.is_some() || header_background.is_some() {
+        paint(
+            &apply_colors(&effective_title, title_color, header_background)?,
+            options.force_color,
+        )
+    } else {
+        effective_title.clone()
+    };
+
+    // Helper to add the header background to a styled border piece
+    let on_header = |styled: colored::ColoredString| -> BoxenResult<String> {
+        let styled = match header_background {
+            Some(background) => styled.on_color(crate::color::parse_color(background)?),
+            None => styled,
+        };
+        Ok(paint(&styled, options.force_color))
+    };
+
+    // Helper to style border characters
+    let style_border_char = |ch: char| -> BoxenResult<String> {
+        let ch_str = ch.to_string();
+        on_header(apply_color_with_dim(
+            &ch_str,
+            options.border_color.as_ref(),
+            options.dim_border,
+        )?)
+    };
+
+    // Helper to style border string
+    let style_border_str = |s: &str| -> BoxenResult<String> {
+        on_header(apply_color_with_dim(
+            s,
+            options.border_color.as_ref(),
+            options.dim_border,
+        )?)
+    };
+
+    // Connectors belong to the border, so they take the border styling
+    if let Some((left, right)) = connectors {
+        styled_title = format!(
+            "{}{styled_title}{}",
+            style_border_char(left)?,
+            style_border_char(right)?
+        );
+    }
+
+    // Corners carry the blank bevel cells between them and the fill
+    let mut top_left = style_border_char(border_chars.top_left)?;
+    let mut top_right = style_border_char(border_chars.top_right)?;
+    if bevel > 0 {
+        let blank = style_border_str(&" ".repeat(bevel))?;
+        top_left.push_str(&blank);
+        top_right.insert_str(0, &blank);
+    }
+
+    if margin_left + margin_right > 0 {
+        styled_title = format!(
+            "{}{styled_title}{}",
+            style_border_str(&border_chars.top.to_string().repeat(margin_left))?,
+            style_border_str(&border_chars.top.to_string().repeat(margin_right))?
+        );
+    }
+
+    match options.title_alignment {
+        TitleAlignment::Left => {
+            write!(result, "{}", top_left).map_err(|e| {
+                crate::error::BoxenError::rendering_error(
+                    format!("Failed to write border: {e}"),
+                    vec![],
+                )
+            })?;
+            write!(result, "{styled_title}").map_err(|e| {
+                crate::error::BoxenError::rendering_error(
+                    format!("Failed to write title: {e}"),
+                    vec![],
+                )
+            })?;
+            let border_fill = border_chars.top.to_string().repeat(remaining_width);
+            write!(result, "{}", style_border_str(&border_fill)?).map_err(|e| {
+                crate::error::BoxenError::rendering_error(
+                    format!("Failed to write border: {e}"),
+                    vec![],
+                )
+            })?;
+            write!(result, "{}", top_right).map_err(|e| {
+                crate::error::BoxenError::rendering_error(
+                    format!("Failed to write border: {e}"),
+                    vec![],
+                )
+            })?;
+        }
+        TitleAlignment::Right => {
+            write!(result, "{}", top_left).map_err(|e| {
+                crate::error::BoxenError::rendering_error(
+                    format!("Failed to write border: {e}"),
+                    vec![],
+                )
+            })?;
+            let border_fill = border_chars.top.to_string().repeat(remaining_width);
+            write!(result, "{}", style_border_str(&border_fill)?).map_err(|e| {
+                crate::error::BoxenError::rendering_error(
+                    format!("Failed to write border: {e}"),
+                    vec![],
+                )
+            })?;
+            write!(result, "{styled_title}").map_err(|e| {
+                crate::error::BoxenError::rendering_error(
+                    format!("Failed to write title: {e}"),
+                    vec![],
+                )
+            })?;
+            write!(result, "{}", top_right).map_err(|e| {
+                crate::error::BoxenError::rendering_error(
+                    format!("Failed to write border: {e}"),
+                    vec![],
+                )
+            })?;
+        }
+        TitleAlignment::Center => {
+            let left_padding = remaining_width / 2;
+            let right_padding = remaining_width - left_padding;
+
+            write!(result, "{}", top_left).map_err(|e| {
+                crate::error::BoxenError::rendering_error(
+                    format!("Failed to write border: {e}"),
+                    vec![],
+                )
+            })?;
+            let left_border_fill = border_chars.top.to_string().repeat(left_padding);
+            write!(result, "{}", style_border_str(&left_border_fill)?).map_err(|e| {
+                crate::error::BoxenError::rendering_error(
+                    format!("Failed to write border: {e}"),
+                    vec![],
+                )
+            })?;
+            write!(result, "{styled_title}").map_err(|e| {
+                crate::error::BoxenError::rendering_error(
+                    format!("Failed to write title: {e}"),
+                    vec![],
+                )
+            })?;
+            let right_border_fill = border_chars.top.to_string().repeat(right_padding);
+            write!(result, "{}", style_border_str(&right_border_fill)?).map_err(|e| {
+                crate::error::BoxenError::rendering_error(
+                    format!("Failed to write border: {e}"),
+                    vec![],
+                )
+            })?;
+            write!(result, "{}", top_right).map_err(|e| {
+                crate::error::BoxenError::rendering_error(
+                    format!("Failed to write border: {e}"),
+                    vec![],
+                )
+            })?;
+        }
+    }
+
+    Ok(())
+}
+
+/// Width of the top and bottom border fill, widened so the corners line up
+/// with side borders drawn `border_thickness` characters wide
+fn border_span(inner_width: usize, options: &BoxenOptions) -> usize {
+    inner_width + 2 * (options.border_thickness.max(1) - 1)
+}
+
+/// A left or right border character repeated `border_thickness` times
+fn side_border(ch: char, options: &BoxenOptions) -> String {
+    ch.to_string().repeat(options.border_thickness.max(1))
+}
+
+/// Render the bottom border
+fn render_bottom_border(
+    border_chars: &crate::options::BorderChars,
+    inner_width: usize,
+    options: &BoxenOptions,
+) -> BoxenResult<String> {
+    render_marked_bottom_border(border_chars, inner_width, options, None)
+}
+
+/// Render the bottom border with `marker` replacing its middle fill character
+fn render_marked_bottom_border(
+    border_chars: &crate::options::BorderChars,
+    inner_width: usize,
+    options: &BoxenOptions,
+    marker: Option<char>,
+) -> BoxenResult<String> {
+    let inner_width = border_span(inner_width, options);
+    with_pooled_string(|border| {
+        // Reserve capacity upfront
+        border.reserve(inner_width + 2);
+
+        border.push(border_chars.bottom_left);
+        push_border_fill(
+            border,
+            border_chars.bottom,
+            inner_width,
+            options,
+            Edge::Bottom,
+        );
+        border.push(border_chars.bottom_right);
+
+        // Every border character is one column wide, so the middle fill
+        // column is also a character index, counting the corner
+        let border = match marker.filter(|_| inner_width > 0) {
+            Some(marker) => std::borrow::Cow::Owned(
+                border
+                    .as_str()
+                    .chars()
+                    .enumerate()
+                    .map(|(index, ch)| {
+                        if index == 1 + inner_width / 2 {
+                            marker
+                        } else {
+                            ch
+                        }
+                    })
+                    .collect(),
+            ),
+            None => std::borrow::Cow::Borrowed(border.as_str()),
+        };
+
+        // Apply border color and dim styling
+        let styled_border =
+            apply_color_with_dim(&border, options.border_color.as_ref(), options.dim_border)?;
+
+        Ok(paint(&styled_border, options.force_color))
+    })
+}
+
+/// Push `width` copies of a horizontal border character, substituting the
+/// pointer glyph when `options.pointer` targets this edge
+fn push_border_fill(
+    border: &mut impl Write,
+    fill: char,
+    width: usize,
+    options: &BoxenOptions,
+    edge: Edge,
+) {
+    let pointer_column = options
+        .pointer
+        .filter(|pointer| pointer.edge == edge && width > 0)
+        .map(|pointer| {
+            let glyph = if options.ascii_only {
+                pointer.ascii_glyph()
+            } else {
+                pointer.glyph()
+            };
+            (pointer.offset.min(width - 1), glyph)
+        });
+    let bevel = bevel_cells(options, width);
+
+    for column in 0..width {
+        let ch = match pointer_column {
+            Some((pointer_column, glyph)) if pointer_column == column => glyph,
+            _ if column < bevel || column >= width - bevel => ' ',
+            _ => fill,
+        };
+        let _ = border.write_char(ch);
+    }
+}
+
+/// Blank cells at each end of a top or bottom border fill `width` wide
+fn bevel_cells(options: &BoxenOptions, width: usize) -> usize {
+    options.bevel.min(width / 2)
+}
+
+/// Glyph drawn over the right border where the scrollbar thumb sits
+const SCROLLBAR_THUMB: char = '█';
+
+/// Scrollbar thumb glyph for `options`, `#` when `ascii_only` is set
+const fn scrollbar_thumb(options: &BoxenOptions) -> char {
+    if options.ascii_only {
+        '#'
+    } else {
+        SCROLLBAR_THUMB
+    }
+}
+
+/// The first row and characters of a `TitlePosition::Left` title within a
+/// left border `track_len` rows tall, cut off when the title is taller
+fn vertical_title_rows(options: &BoxenOptions, track_len: usize) -> (usize, Vec<char>) {
+    let Some(title) = options
+        .title
+        .as_ref()
+        .filter(|_| options.title_position == TitlePosition::Left)
+    else {
+        return (0, Vec::new());
+    };
+
+    let chars: Vec<char> = title.chars().take(track_len).collect();
+    let remaining = track_len - chars.len();
+    let start = match options.title_alignment {
+        TitleAlignment::Left => 0,
+        TitleAlignment::Center => remaining / 2,
+        TitleAlignment::Right => remaining,
+    };
+    (start, chars)
+}
+
+/// Render a single content line with borders and padding
+fn render_content_line(
+    line: &str,
+    border_chars: &crate::options::BorderChars,
+    options: &BoxenOptions,
+    inner_width: usize,
+    measure: Measure,
+) -> BoxenResult<String> {
+    with_pooled_string(|content_area| {
+        // Reserve capacity upfront for the entire line
+        content_area.reserve(inner_width);
+
+        // Left padding
+        for _ in 0..options.padding.left {
+            content_area.push(' ');
+        }
+
+        // Content
+        content_area.push_str(line);
+
+        // Right padding (fill to inner width). This is measured in display
+        // columns, so a wrapped line that stops one cell short because its next
+        // character was wide still gets the extra space before the border.
+        let current_content_width = measure.text_width(content_area.as_str());
+        let overflowing = current_content_width > inner_width;
+        let remaining_width = inner_width.saturating_sub(current_content_width);
+        for _ in 0..remaining_width {
+            content_area.push(' ');
+        }
+
+        // Apply background color and dimming to content area if specified
+        let styled_content = if options.inset_shadow && !overflowing {
+            shade_inset_padding(content_area.as_str(), options)?
+        } else {
+            style_content_area(content_area.as_str().to_string(), options)?
+        };
+
+        // Build borders separately and apply border styling
+        let left_border = paint(
+            &apply_color_with_dim(
+                &side_border(border_chars.left, options),
+                options.border_color.as_ref(),
+                options.dim_border,
+            )?,
+            options.force_color,
+        );
+
+        // Lines that overflow (see `NoWrapOverflow::Overflow`) run past the right border
+        let right_border = if overflowing {
+            String::new()
+        } else {
+            paint(
+                &apply_color_with_dim(
+                    &side_border(border_chars.right, options),
+                    options.border_color.as_ref(),
+                    options.dim_border,
+                )?,
+                options.force_color,
+            )
+        };
+
+        // Combine borders and content using write! macro with pooled buffer
+        with_pooled_string(|result| {
+            result.reserve(left_border.len() + styled_content.len() + right_border.len());
+            write!(result, "{left_border}{styled_content}{right_border}").map_err(|e| {
+                crate::error::BoxenError::rendering_error(
+                    format!("Failed to write content line: {e}"),
+                    vec![],
+                )
+            })?;
+            Ok(result.as_str().to_string())
+        })
+    })
+}
+
+/// Backgrounds for the shaded (top and left) and lit (bottom and right) sides
+/// of an inset shadow
+fn inset_shadow_colors() -> (crate::options::Color, crate::options::Color) {
+    (
+        crate::options::Color::Named("bright_black".to_string()),
+        crate::options::Color::Named("white".to_string()),
+    )
+}
+
+/// Style a padded content row for an inset shadow: the left padding dark, the
+/// right padding light, and the text between like any other content
+fn shade_inset_padding(row: &str, options: &BoxenOptions) -> BoxenResult<String> {
+    let (dark, light) = inset_shadow_colors();
+    // Padding cells are plain spaces, one byte each, at both ends of the row
+    let (left, rest) = row.split_at(options.padding.left.min(row.len()));
+    let (middle, right) = rest.split_at(rest.len().saturating_sub(options.padding.right));
+
+    let shade = |cells: &str, color: &crate::options::Color| -> BoxenResult<String> {
+        Ok(if cells.is_empty() {
+            String::new()
+        } else {
+            paint(
+                &apply_colors(cells, None, Some(color))?,
+                options.force_color,
+            )
+        })
+    };
+    Ok(format!(
+        "{}{}{}",
+        shade(left, &dark)?,
+        style_content_area(middle.to_string(), options)?,
+        shade(right, &light)?
+    ))
+}
+
+/// Apply text and background colors and `dim_content` to a padded content line
+fn style_content_area(content: String, options: &BoxenOptions) -> BoxenResult<String> {
+    if options.text_color.is_none() && options.background_color.is_none() && !options.dim_content {
+        return Ok(content);
+    }
+
+    let mut styled = apply_colors(
+        &content,
+        options.text_color.as_ref(),
+        options.background_color.as_ref(),
+    )?;
+    if options.dim_content {
+        styled = styled.dimmed();
+    }
+    Ok(paint(&styled, options.force_color))
+}
+
+/// Render an empty line with borders and padding (for top/bottom padding)
+fn render_padded_empty_line(
+    border_chars: &crate::options::BorderChars,
+    inner_width: usize,
+    options: &BoxenOptions,
+) -> BoxenResult<String> {
+    with_pooled_string(|content_area| {
+        // Reserve capacity upfront
+        content_area.reserve(inner_width);
+
+        // Build the content area (all spaces)
+        for _ in 0..inner_width {
+            content_area.push(' ');
+        }
+
+        // Apply background color to content area if specified
+        let styled_content = if let Some(bg_color) = &options.background_color {
+            paint(
+                &apply_colors(content_area.as_str(), None, Some(bg_color))?,
+                options.force_color,
+            )
+        } else {
+            content_area.as_str().to_string()
+        };
+
+        // Build borders separately and apply border styling
+        let left_border = paint(
+            &apply_color_with_dim(
+                &side_border(border_chars.left, options),
+                options.border_color.as_ref(),
+                options.dim_border,
+            )?,
+            options.force_color,
+        );
+
+        let right_border = paint(
+            &apply_color_with_dim(
+                &side_border(border_chars.right, options),
+                options.border_color.as_ref(),
+                options.dim_border,
+            )?,
+            options.force_color,
+        );
+
+        // Combine borders and content using write! macro with pooled buffer
+        with_pooled_string(|result| {
+            result.reserve(left_border.len() + styled_content.len() + right_border.len());
+            write!(result, "{left_border}{styled_content}{right_border}").map_err(|e| {
+                crate::error::BoxenError::rendering_error(
+                    format!("Failed to write padded line: {e}"),
+                    vec![],
+                )
+            })?;
+            Ok(result.as_str().to_string())
+        })
+    })
+}
+
+/// Render title without border (for `BorderStyle::None`)
+fn render_title_without_border(
+    title: &str,
+    options: &BoxenOptions,
+    inner_width: usize,
+    measure: Measure,
+) -> BoxenResult<String> {
+    let title_width = measure.text_width(title);
+
+    // If title is too long, truncate it - use pooled buffer for pre-allocation
+    let effective_title = if title_width > inner_width {
+        truncate_title(title, inner_width, options, measure)
+    } else {
+        title.to_string()
+    };
+
+    let effective_title_width = measure.text_width(&effective_title);
+    let remaining_width = inner_width - effective_title_width;
+
+    let title_line = with_pooled_string(|buffer| {
+        buffer.reserve(inner_width);
+        match options.title_alignment {
+            TitleAlignment::Left => {
+                write!(buffer, "{effective_title}").map_err(|e| {
+                    crate::error::BoxenError::rendering_error(
+                        format!("Failed to write title: {e}"),
+                        vec![],
+                    )
+                })?;
+                for _ in 0..remaining_width {
+                    buffer.push(' ');
+                }
+            }
+            TitleAlignment::Right => {
+                for _ in 0..remaining_width {
+                    buffer.push(' ');
+                }
+                write!(buffer, "{effective_title}").map_err(|e| {
+                    crate::error::BoxenError::rendering_error(
+                        format!("Failed to write title: {e}"),
+                        vec![],
+                    )
+                })?;
+            }
+            TitleAlignment::Center => {
+                let left_padding = remaining_width / 2;
+                let right_padding = remaining_width - left_padding;
+                for _ in 0..left_padding {
+                    buffer.push(' ');
+                }
+                write!(buffer, "{effective_title}").map_err(|e| {
+                    crate::error::BoxenError::rendering_error(
+                        format!("Failed to write title: {e}"),
+                        vec![],
+                    )
+                })?;
+                for _ in 0..right_padding {
+                    buffer.push(' ');
+                }
+            }
+        }
+        Ok::<String, crate::error::BoxenError>(buffer.as_str().to_string())
+    })?;
+
+    // Apply background color if specified
+    let styled_title = if let Some(bg_color) = &options.background_color {
+        paint(
+            &apply_colors(&title_line, None, Some(bg_color))?,
+            options.force_color,
+        )
+    } else {
+        title_line
+    };
+
+    Ok(styled_title)
+}
+
+/// Swap the left and right side of every horizontal setting for a mirrored
+/// box. Border glyphs are swapped as rows are drawn. The float is kept: the
+/// terminal decides which edge column 0 is on.
+fn mirror_layout(options: &mut BoxenOptions) {
+    let mirror_title = |alignment: TitleAlignment| match alignment {
+        TitleAlignment::Left => TitleAlignment::Right,
+        TitleAlignment::Center => TitleAlignment::Center,
+        TitleAlignment::Right => TitleAlignment::Left,
+    };
+
+    let padding = &mut options.padding;
+    (padding.left, padding.right) = (padding.right, padding.left);
+    let margin = &mut options.margin;
+    (margin.left, margin.right) = (margin.right, margin.left);
+    options.title_margin = (options.title_margin.1, options.title_margin.0);
+    options.text_alignment = mirror_text_alignment(options.text_alignment);
+    options.title_alignment = mirror_title(options.title_alignment);
+    options.caption_alignment = mirror_title(options.caption_alignment);
+}
+
+/// The alignment on the opposite side, for mirrored boxes
+fn mirror_text_alignment(alignment: TextAlignment) -> TextAlignment {
+    match alignment {
+        TextAlignment::Left => TextAlignment::Right,
+        TextAlignment::Center => TextAlignment::Center,
+        TextAlignment::Right => TextAlignment::Left,
+    }
+}
+
+/// Zero the margins between the box and the terminal edges it is placed against
+///
+/// Output starts on the first row, so the top margin always goes; the
+/// horizontal side depends on the float. A centered box touches neither side.
+fn collapse_edge_margins(options: &mut BoxenOptions) {
+    use crate::options::Float;
+
+    options.margin.top = 0;
+    match options.float {
+        Float::Left => options.margin.left = 0,
+        Float::Right => options.margin.right = 0,
+        Float::Center => {}
+    }
+}
+
+/// Place a line horizontally according to the float and margins
+fn float_position_line(
+    line: &str,
+    options: &BoxenOptions,
+    layout: &crate::options::LayoutDimensions,
+    measure: Measure,
+) -> String {
+    use crate::options::Float;
+    use crate::terminal::get_terminal_width;
+
+    let terminal_width = get_terminal_width();
+    let box_width_without_margins = layout.total_width - options.margin.horizontal();
+
+    // Margins may only push a left-floated box off screen when the output isn't
+    // bound for the terminal; otherwise they shrink to what the terminal can fit
+    let fit_margin = |margin: usize, used: usize| {
+        if options.ignore_terminal_bounds {
+            margin
+        } else {
+            margin.min(terminal_width.saturating_sub(used))
+        }
+    };
+
+    // Rows may run wider than the box, e.g. with `NoWrapOverflow::Overflow`
+    let line_width = measure.text_width(line).max(box_width_without_margins);
+
+    // Calculate positioning based on float mode
+    let left_spacing = match options.float {
+        Float::Left => {
+            // Left float: use the specified left margin
+            fit_margin(options.margin.left, line_width)
+        }
+        Float::Center => {
+            // Center float: center the box within terminal width
+            let available_space = terminal_width.saturating_sub(layout.total_width);
+            available_space / 2 + options.margin.left
+        }
+        Float::Right => {
+            // Right float: align to right edge minus right margin
+            if terminal_width > box_width_without_margins + options.margin.right {
+                terminal_width - box_width_without_margins - options.margin.right
+            } else {
+                options.margin.left // Fallback to left margin if not enough space
+            }
+        }
+    };
+
+    let mut result = String::with_capacity(left_spacing + line.len() + options.margin.right);
+
+    // Add calculated left spacing
+    for _ in 0..left_spacing {
+        result.push(' ');
+    }
+
+    // Add the line content
+    result.push_str(line);
+
+    // For right margin, we only add it for left float mode
+    // For center and right float, the positioning handles the spacing
+    if matches!(options.float, Float::Left) {
+        let right_spacing = fit_margin(options.margin.right, left_spacing + line_width);
+        for _ in 0..right_spacing {
+            result.push(' ');
+        }
+    }
+
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::options::{
+        BorderStyle, BoxenOptions, Height, Spacing, TextAlignment, TitleAlignment, Width,
+    };
+
+    // Force enable colors for tests (colored crate disables them in non-TTY environments)
+    #[ctor::ctor]
+    fn init_colors() {
+        colored::control::set_override(true);
+    }
+
+    // Set terminal size for fullscreen tests
+    #[ctor::ctor]
+    fn init_terminal_size() {
+        unsafe {
+            std::env::set_var("COLUMNS", "80");
+            std::env::set_var("LINES", "24");
+        }
+    }
+
+    #[test]
+    fn test_basic_box_rendering() {
+        let result = boxen("Hello", None).unwrap();
+
+        // Should contain the text
+        assert!(result.contains("Hello"));
+
+        // Should have single-line borders (default)
+        assert!(result.contains("┌")); // Top-left corner
+        assert!(result.contains("┐")); // Top-right corner
+        assert!(result.contains("└")); // Bottom-left corner
+        assert!(result.contains("┘")); // Bottom-right corner
+        assert!(result.contains("│")); // Vertical borders
+        assert!(result.contains("─")); // Horizontal borders
+
+        // Should be 3 lines (top border, content, bottom border)
+        assert_eq!(result.lines().count(), 3);
+    }
+
+    #[test]
+    fn test_empty_text() {
+        let result = boxen("", None).unwrap();
+
+        // Should still render a box
+        assert!(result.contains("┌"));
+        assert!(result.contains("┐"));
+        assert!(result.contains("└"));
+        assert!(result.contains("┘"));
+
+        // Should be 3 lines
+        assert_eq!(result.lines().count(), 3);
+    }
+
+    #[test]
+    fn test_multiline_text() {
+        let result = boxen("Hello\nWorld", None).unwrap();
+
+        // Should contain both lines
+        assert!(result.contains("Hello"));
+        assert!(result.contains("World"));
+
+        // Should be 4 lines (top border, 2 content lines, bottom border)
+        assert_eq!(result.lines().count(), 4);
+    }
+
+    #[test]
+    fn test_no_border_style() {
+        let options = BoxenOptions {
+            border_style: BorderStyle::None,
+            ..Default::default()
+        };
+
+        let result = boxen("Hello", Some(options)).unwrap();
+
+        // Should contain the text
+        assert!(result.contains("Hello"));
+
+        // Should not contain border characters
+        assert!(!result.contains("┌"));
+        assert!(!result.contains("│"));
+
+        // Should be 1 line (just content)
+        assert_eq!(result.lines().count(), 1);
+    }
+
+    #[test]
+    fn test_different_border_styles() {
+        let styles = vec![
+            BorderStyle::Single,
+            BorderStyle::Double,
+            BorderStyle::Round,
+            BorderStyle::Bold,
+            BorderStyle::Classic,
+        ];
+
+        for style in styles {
+            let options = BoxenOptions {
+                border_style: style,
+                ..Default::default()
+            };
+
+            let result = boxen("Test", Some(options)).unwrap();
+
+            // Should contain the text
+            assert!(result.contains("Test"));
+
+            // Should be 3 lines
+            assert_eq!(result.lines().count(), 3);
+        }
+    }
+
+    #[test]
+    fn test_padding() {
+        let options = BoxenOptions {
+            padding: Spacing {
+                top: 1,
+                right: 2,
+                bottom: 1,
+                left: 2,
+            },
+            ..Default::default()
+        };
+
+        let result = boxen("Hello", Some(options)).unwrap();
+
+        // Should be 5 lines (top border, top padding, content, bottom padding, bottom border)
+        assert_eq!(result.lines().count(), 5);
+
+        // Content line should have padding
+        let lines: Vec<&str> = result.lines().collect();
+        let content_line = lines[2]; // Middle line should be content
+        assert!(content_line.contains("Hello"));
+
+        // Should have left padding (2 spaces after left border)
+        assert!(content_line.starts_with("│  Hello"));
+    }
+
+    #[test]
+    fn test_margins() {
+        let options = BoxenOptions {
+            margin: Spacing {
+                top: 1,
+                right: 1,
+                bottom: 1,
+                left: 1,
+            },
+            ..Default::default() // Don't specify width to avoid calculation issues
+        };
+
+        let result = boxen("Hello", Some(options)).unwrap();
+
+        // Should be 5 lines (top margin, top border, content, bottom border, bottom margin)
+        assert_eq!(result.lines().count(), 5);
+
+        // First line should be empty (top margin)
+        let lines: Vec<&str> = result.lines().collect();
+        assert_eq!(lines[0], "");
+
+        // Last line should be empty (bottom margin)
+        assert_eq!(lines[4], "");
+
+        // Border lines should have left margin (1 space)
+        assert!(lines[1].starts_with(" ┌"));
+        assert!(lines[3].starts_with(" └"));
+    }
+
+    #[test]
+    fn test_title_basic() {
+        let options = BoxenOptions {
+            title: Some("Title".to_string()),
+            ..Default::default()
+        };
+
+        let result = boxen("Content", Some(options)).unwrap();
+
+        // Should contain both title and content
+        assert!(result.contains("Title"));
+        assert!(result.contains("Content"));
+
+        // Title should be in the top border
+        let lines: Vec<&str> = result.lines().collect();
+        let top_border = lines[0];
+        assert!(top_border.contains("Title"));
+        assert!(top_border.starts_with("┌"));
+        assert!(top_border.ends_with("┐"));
+    }
+
+    #[test]
+    fn test_title_alignment() {
+        let alignments = vec![
+            TitleAlignment::Left,
+            TitleAlignment::Center,
+            TitleAlignment::Right,
+        ];
+
+        for alignment in alignments {
+            let options = BoxenOptions {
+                title: Some("Title".to_string()),
+                title_alignment: alignment,
+                width: Some(Width::Fixed(20)), // Fixed width for predictable alignment
+                ..Default::default()
+            };
+
+            let result = boxen("Content", Some(options)).unwrap();
+
+            // Should contain the title
+            assert!(result.contains("Title"));
+
+            let lines: Vec<&str> = result.lines().collect();
+            let top_border = lines[0];
+            assert!(top_border.contains("Title"));
+        }
+    }
+
+    #[test]
+    fn test_title_truncation() {
+        let options = BoxenOptions {
+            title: Some("Very Long Title That Should Be Truncated".to_string()),
+            width: Some(Width::Fixed(15)), // Small width to force truncation
+            ..Default::default()
+        };
+
+        let result = boxen("Content", Some(options)).unwrap();
+
+        // Should contain some part of the title
+        let lines: Vec<&str> = result.lines().collect();
+        let top_border = lines[0];
+
+        // Should start and end with border characters
+        assert!(top_border.starts_with("┌"));
+        assert!(top_border.ends_with("┐"));
+
+        // Should be exactly the specified width
+        assert_eq!(text_width(top_border), 15);
+    }
+
+    #[test]
+    fn test_title_connectors() {
+        let options = BoxenOptions {
+            title: Some(" Title ".to_string()),
+            title_connectors: Some(('┤', '├')),
+            width: Some(Width::Fixed(20)),
+            ..Default::default()
+        };
+
+        let result = boxen("Content", Some(options)).unwrap();
+        let lines: Vec<&str> = result.lines().collect();
+
+        assert!(lines[0].starts_with("┌┤ Title ├─"));
+        assert!(lines[0].ends_with("┐"));
+        assert_eq!(text_width(lines[0]), 20);
+        assert_eq!(text_width(lines[0]), text_width(lines[1]));
+    }
+
+    #[test]
+    fn test_title_connectors_truncated_title() {
+        let options = BoxenOptions {
+            title: Some("Very Long Title That Should Be Truncated".to_string()),
+            title_connectors: Some(('┤', '├')),
+            width: Some(Width::Fixed(15)),
+            ..Default::default()
+        };
+
+        let result = boxen("Content", Some(options)).unwrap();
+        let top_border = result.lines().next().unwrap();
+
+        assert!(top_border.starts_with("┌┤Very"));
+        assert!(top_border.ends_with("├┐"));
+        assert_eq!(text_width(top_border), 15);
+    }
+
+    #[test]
+    fn test_ascii_only_output() {
+        let options = BoxenOptions {
+            border_style: BorderStyle::Classic,
+            title: Some("Résumé…".to_string()),
+            title_connectors: Some(('┤', '├')),
+            ascii_only: true,
+            ..Default::default()
+        };
+
+        let result = boxen("“Smart” — 日本語 ☎\u{FE0F}", Some(options)).unwrap();
+        assert!(result.is_ascii());
+        assert!(result.contains("\"Smart\" - ??? ?"));
+        assert!(result.starts_with("+Resume..."));
+
+        let lines: Vec<&str> = result.lines().collect();
+        assert!(lines.iter().all(|line| line.len() == lines[0].len()));
+    }
+
+    #[test]
+    fn test_ascii_only_downgrades_every_glyph() {
+        let options = BoxenOptions {
+            ascii_only: true,
+            title: Some("Título".to_string()),
+            caption: Some("— end".to_string()),
+            line_prefix: Some("» ".to_string()),
+            pointer: Some(crate::options::Pointer::new(Edge::Top, 1)),
+            scrollbar: Some(crate::options::ScrollbarState::new(0, 10, 2)),
+            truncation_indicator: Some('▼'),
+            height: Some(Height::Fixed(4)),
+            inner_border: Some(BorderStyle::Round),
+            ..Default::default()
+        };
+        let result = spinner_box(3, "Loading…", &options).unwrap();
+        assert!(result.is_ascii(), "{result}");
+        let lines: Vec<&str> = result.lines().collect();
+        assert!(lines[0].starts_with("+Titulo"));
+        assert!(lines[1].starts_with("|+"), "{result}");
+        assert!(lines.iter().any(|line| line.ends_with('#')));
+        assert!(lines.last().unwrap().contains("- end"));
+
+        // The pointer and indicator replace border fill with ASCII stand-ins
+        let options = BoxenOptions {
+            inner_border: None,
+            caption: None,
+            title: None,
+            scrollbar: None,
+            ..options
+        };
+        let result = boxen("a\nb\nc\nd", Some(options.clone())).unwrap();
+        assert_eq!(result, "+-^-+\n|\" a|\n|\" b|\n+-v-+");
+        let spinner = spinner_box(1, "x", &options).unwrap();
+        assert_eq!(spinner.lines().nth(1), Some("|\" / x|"));
+
+        let edges = render_edges(&options, 3, 1).unwrap();
+        assert_eq!(edges.left, vec!["|"]);
+    }
+
+    #[test]
+    fn test_variation_selector_box_width() {
+        let plain = boxen("☎", None).unwrap();
+        let emoji = boxen("☎\u{FE0F}", None).unwrap();
+
+        assert_eq!(plain.lines().next().unwrap(), "┌─┐");
+        assert_eq!(emoji.lines().next().unwrap(), "┌──┐");
+        for line in emoji.lines() {
+            assert_eq!(text_width(line), 4);
+        }
+    }
+
+    #[test]
+    fn test_variation_selector_title_truncation() {
+        let options = BoxenOptions {
+            title: Some("☎\u{FE0F}☎\u{FE0F}☎\u{FE0F}".to_string()),
+            width: Some(Width::Fixed(7)),
+            ..Default::default()
+        };
+
+        let result = boxen("Hi", Some(options)).unwrap();
+        let top_border = result.lines().next().unwrap();
+
+        assert_eq!(top_border, "┌☎\u{FE0F}☎\u{FE0F}─┐");
+        assert_eq!(text_width(top_border), 7);
+    }
+
+    #[test]
+    fn test_align_content_to_left_title() {
+        let options = BoxenOptions {
+            title: Some(" Title ".to_string()),
+            title_connectors: Some(('┤', '├')),
+            align_content_to_title: true,
+            width: Some(Width::Fixed(20)),
+            ..Default::default()
+        };
+
+        let result = boxen("Content", Some(options)).unwrap();
+        let lines: Vec<&str> = result.lines().collect();
+
+        let title_column = lines[0].chars().position(|c| c == 'T').unwrap();
+        let content_column = lines[1].chars().position(|c| c == 'C').unwrap();
+        assert_eq!(title_column, 3);
+        assert_eq!(content_column, title_column);
+        assert_eq!(text_width(lines[1]), 20);
+    }
+
+    #[test]
+    fn test_align_content_to_right_title_fixed_width() {
+        let options = BoxenOptions {
+            title: Some("Title".to_string()),
+            title_alignment: TitleAlignment::Right,
+            align_content_to_title: true,
+            width: Some(Width::Fixed(20)),
+            ..Default::default()
+        };
+
+        let result = boxen("Hi", Some(options)).unwrap();
+        let lines: Vec<&str> = result.lines().collect();
+
+        let title_column = lines[0].chars().position(|c| c == 'T').unwrap();
+        let content_column = lines[1].chars().position(|c| c == 'H').unwrap();
+        assert_eq!(content_column, title_column);
+    }
+
+    #[test]
+    fn test_align_content_to_title_keeps_larger_padding() {
+        let options = BoxenOptions {
+            title: Some("Title".to_string()),
+            padding: Spacing::from((4, 0)),
+            align_content_to_title: true,
+            ..Default::default()
+        };
+
+        let aligned = boxen("Hi", Some(options.clone())).unwrap();
+        let plain = boxen(
+            "Hi",
+            Some(BoxenOptions {
+                align_content_to_title: false,
+                ..options
+            }),
+        )
+        .unwrap();
+        assert_eq!(aligned, plain);
+    }
+
+    #[test]
+    fn test_line_prefix_reserves_width() {
+        let options = BoxenOptions {
+            line_prefix: Some("> ".to_string()),
+            width: Some(Width::Fixed(14)),
+            ..Default::default()
+        };
+
+        let result = boxen("Quoted text wraps here", Some(options)).unwrap();
+        let lines: Vec<&str> = result.lines().collect();
+        let content: Vec<&str> = lines[1..lines.len() - 1].to_vec();
+
+        assert_eq!(content.len(), 3);
+        for line in &content {
+            assert!(line.starts_with("│> "), "line {line:?}");
+            assert_eq!(text_width(line), 14);
+        }
+    }
+
+    #[test]
+    fn test_line_suffix_and_alignment() {
+        let options = BoxenOptions {
+            line_prefix: Some("• ".to_string()),
+            line_suffix: Some(" ·".to_string()),
+            text_alignment: TextAlignment::Right,
+            ..Default::default()
+        };
+
+        let result = boxen("one\nthree", Some(options)).unwrap();
+        let lines: Vec<&str> = result.lines().collect();
+
+        assert_eq!(lines[1], "│•   one ·│");
+        assert_eq!(lines[2], "│• three ·│");
+    }
+
+    #[test]
+    fn test_line_prefix_too_wide() {
+        let options = BoxenOptions {
+            line_prefix: Some(">>>>>>>>".to_string()),
+            width: Some(Width::Fixed(10)),
+            ..Default::default()
+        };
+
+        assert!(boxen("Hi", Some(options)).is_err());
+    }
+
+    #[test]
+    fn test_typographic_width_inside_boxes() {
+        for alignment in [
+            TextAlignment::Left,
+            TextAlignment::Center,
+            TextAlignment::Right,
+        ] {
+            let options = BoxenOptions {
+                text_alignment: alignment,
+                width: Some(Width::Fixed(10)),
+                ..Default::default()
+            };
+
+            let result = boxen("ﬁle\ncafe\u{0301}\n“hi”", Some(options)).unwrap();
+            for line in result.lines() {
+                assert_eq!(text_width(line), 10, "{alignment:?}: {line:?}");
+            }
+        }
+
+        assert_eq!(boxen("ﬁle", None).unwrap().lines().next().unwrap(), "┌───┐");
+        assert_eq!(
+            boxen("cafe\u{0301}", None).unwrap().lines().next().unwrap(),
+            "┌────┐"
+        );
+    }
+
+    #[test]
+    fn test_caption_below_box() {
+        let options = BoxenOptions {
+            caption: Some("Fig. 1".to_string()),
+            caption_alignment: TitleAlignment::Center,
+            width: Some(Width::Fixed(12)),
+            margin: Spacing::from([0, 0, 1, 2]),
+            ..Default::default()
+        };
+
+        let result = boxen("Body", Some(options)).unwrap();
+        let lines: Vec<&str> = result.lines().collect();
+
+        assert_eq!(lines.len(), 5);
+        assert!(lines[2].trim_start().starts_with('└'));
+        assert_eq!(lines[3], "");
+        // Left margin (2) plus centered within the 10-column box
+        assert_eq!(lines[4], "    Fig. 1  ");
+        assert!(!result.ends_with('\n'));
+    }
+
+    #[test]
+    fn test_caption_alignment_and_truncation() {
+        let right = BoxenOptions {
+            caption: Some("end".to_string()),
+            caption_alignment: TitleAlignment::Right,
+            width: Some(Width::Fixed(8)),
+            ..Default::default()
+        };
+        let result = boxen("x", Some(right)).unwrap();
+        assert_eq!(result.lines().last(), Some("     end"));
+
+        let long = BoxenOptions {
+            caption: Some("A much longer caption".to_string()),
+            width: Some(Width::Fixed(8)),
+            ..Default::default()
+        };
+        let result = boxen("x", Some(long)).unwrap();
+        assert_eq!(result.lines().last(), Some("A much l"));
+    }
+
+    #[test]
+    fn test_tile_to_narrow_width() {
+        let motif = boxen("ab", None).unwrap();
+        let tiled = tile_to_width(&motif, 10, Measure::default());
+        let lines: Vec<&str> = tiled.lines().collect();
+
+        assert_eq!(lines[0], "┌──┐┌──┐┌─");
+        assert_eq!(lines[1], "│ab││ab││a");
+        assert_eq!(lines[2], "└──┘└──┘└─");
+        assert!(lines.iter().all(|line| text_width(line) == 10));
+    }
+
+    #[test]
+    fn test_tile_trims_wide_characters_and_keeps_margins() {
+        let motif = boxen("日", None).unwrap();
+        let tiled = tile_to_width(&motif, 6, Measure::default());
+        assert_eq!(tiled.lines().nth(1), Some("│日││"));
+
+        let with_margin = "\n┌┐\n└┘\n";
+        assert_eq!(
+            tile_to_width(with_margin, 3, Measure::default()),
+            "\n┌┐┌\n└┘└\n"
+        );
+    }
+
+    #[test]
+    fn test_tile_horizontal_fills_terminal() {
+        let options = BoxenOptions {
+            tile_horizontal: true,
+            float: crate::options::Float::Center,
+            border_color: Some(crate::options::Color::Named("red".to_string())),
+            ..Default::default()
+        };
+
+        let result = boxen("~", Some(options)).unwrap();
+        let terminal_width = crate::terminal::get_terminal_width();
+        for line in result.lines() {
+            assert_eq!(text_width(line), terminal_width);
+        }
+        assert!(result.lines().next().unwrap().ends_with("\x1b[0m"));
+    }
+
+    #[test]
+    fn test_paginate_horizontal_page_widths() {
+        let options = BoxenOptions {
+            width: Some(crate::options::Width::Fixed(50)),
+            ..Default::default()
+        };
+        let wide = boxen("0123456789".repeat(4), Some(options)).unwrap();
+        let pages = paginate_horizontal(&wide, 20);
+
+        assert_eq!(pages.len(), 3);
+        for (page, expected_width) in pages.iter().zip([20, 20, 10]) {
+            assert_eq!(page.lines().count(), wide.lines().count());
+            assert!(page.lines().all(|line| text_width(line) == expected_width));
+        }
+        assert!(pages[0].starts_with("┌───"));
+        assert!(pages[2].ends_with("───┘"));
+
+        // Concatenating the pages line by line restores the box
+        let rejoined: Vec<String> = (0..wide.lines().count())
+            .map(|row| {
+                pages
+                    .iter()
+                    .map(|page| page.lines().nth(row).unwrap())
+                    .collect()
+            })
+            .collect();
+        assert_eq!(rejoined.join("\n"), wide);
+    }
+
+    #[test]
+    fn test_paginate_horizontal_preserves_colors() {
+        let line = "\x1b[31mred text that spans pages\x1b[0m plain";
+        let pages = paginate_horizontal(line, 10);
+
+        assert_eq!(pages[0], "\x1b[31mred text t\x1b[0m");
+        assert_eq!(pages[1], "\x1b[31mhat spans \x1b[0m");
+        assert_eq!(pages[2], "\x1b[31mpages\x1b[0m plai");
+        assert_eq!(pages[3], "n");
+
+        let options = BoxenOptions {
+            width: Some(crate::options::Width::Fixed(50)),
+            border_color: Some(crate::options::Color::Named("blue".to_string())),
+            ..Default::default()
+        };
+        let wide = boxen("colored", Some(options)).unwrap();
+        for page in paginate_horizontal(&wide, 20) {
+            // Border rows are colored across their full width, so every page keeps the color
+            for line in [page.lines().next().unwrap(), page.lines().last().unwrap()] {
+                assert!(line.starts_with("\x1b[34m"), "{line:?}");
+                assert!(line.ends_with("\x1b[0m"), "{line:?}");
+            }
+        }
+    }
+
+    #[test]
+    fn test_paginate_horizontal_wide_characters() {
+        let pages = paginate_horizontal("ab日本", 3);
+        assert_eq!(pages, vec!["ab ".to_string(), " 本".to_string()]);
+        assert_eq!(paginate_horizontal("short", 0), vec!["short".to_string()]);
+    }
+
+    #[test]
+    fn test_preserve_blank_edges() {
+        let options = BoxenOptions {
+            preserve_blank_edges: true,
+            ..Default::default()
+        };
+        let result = boxen("\nHello\n", Some(options)).unwrap();
+        assert_eq!(result, "┌─────┐\n│     │\n│Hello│\n│     │\n└─────┘");
+
+        let options = BoxenOptions {
+            preserve_blank_edges: true,
+            ..Default::default()
+        };
+        let result = boxen("\n\nverse\n\n", Some(options)).unwrap();
+        assert_eq!(result.lines().count(), 7);
+        assert_eq!(result.lines().filter(|line| *line == "│     │").count(), 4);
+    }
+
+    #[test]
+    fn test_trailing_newline_dropped_by_default() {
+        let result = boxen("\nHello\n", None).unwrap();
+        assert_eq!(result, "┌─────┐\n│     │\n│Hello│\n└─────┘");
+    }
+
+    #[test]
+    fn test_normalize_nfc_matches_precomposed() {
+        let options = BoxenOptions {
+            normalize: Some(crate::options::NormalizationForm::Nfc),
+            ..Default::default()
+        };
+        let decomposed = boxen("cafe\u{0301}", Some(options.clone())).unwrap();
+        let precomposed = boxen("café", Some(options)).unwrap();
+
+        assert_eq!(decomposed, precomposed);
+        assert_eq!(decomposed, "┌────┐\n│café│\n└────┘");
+
+        let content = process_content(
+            "cafe\u{0301}",
+            &BoxenOptions {
+                normalize: Some(crate::options::NormalizationForm::Nfc),
+                ..Default::default()
+            },
+            Measure::default(),
+        )
+        .unwrap();
+        assert_eq!(content.content_width, 4);
+        assert_eq!(content.lines[0].chars().count(), 4);
+    }
+
+    #[test]
+    fn test_title_margin_all_alignments() {
+        let render = |alignment| {
+            let options = BoxenOptions {
+                title: Some("Hi".to_string()),
+                title_margin: (2, 2),
+                title_alignment: alignment,
+                width: Some(crate::options::Width::Fixed(12)),
+                ..Default::default()
+            };
+            boxen("x", Some(options))
+                .unwrap()
+                .lines()
+                .next()
+                .unwrap()
+                .to_string()
+        };
+
+        assert_eq!(render(TitleAlignment::Left), "┌──Hi──────┐");
+        assert_eq!(render(TitleAlignment::Center), "┌────Hi────┐");
+        assert_eq!(render(TitleAlignment::Right), "┌──────Hi──┐");
+    }
+
+    #[test]
+    fn test_title_margin_reduces_title_space() {
+        let options = BoxenOptions {
+            title: Some("Long title".to_string()),
+            title_margin: (1, 2),
+            title_connectors: Some(('┤', '├')),
+            width: Some(crate::options::Width::Fixed(12)),
+            ..Default::default()
+        };
+        let result = boxen("x", Some(options)).unwrap();
+        assert_eq!(result.lines().next().unwrap(), "┌─┤Long ├──┐");
+        assert!(result.lines().all(|line| text_width(line) == 12));
+    }
+
+    #[test]
+    fn test_title_margin_dropped_when_no_room() {
+        let options = BoxenOptions {
+            title: Some("Hi".to_string()),
+            title_margin: (5, 5),
+            width: Some(crate::options::Width::Fixed(6)),
+            ..Default::default()
+        };
+        let result = boxen("x", Some(options)).unwrap();
+        assert_eq!(result.lines().next().unwrap(), "┌Hi──┐");
+    }
+
+    #[test]
+    fn test_write_boxen_writes_box_and_newline() {
+        let mut output = Vec::new();
+        write_boxen(&mut output, "Hello", None).unwrap();
+
+        let expected = format!("{}\n", boxen("Hello", None).unwrap());
+        assert_eq!(output, expected.into_bytes());
+    }
+
+    #[test]
+    fn test_write_boxen_reports_errors() {
+        struct FailingWriter;
+        impl std::io::Write for FailingWriter {
+            fn write(&mut self, _buf: &[u8]) -> std::io::Result<usize> {
+                Err(std::io::Error::new(
+                    std::io::ErrorKind::BrokenPipe,
+                    "closed",
+                ))
+            }
+            fn flush(&mut self) -> std::io::Result<()> {
+                Ok(())
+            }
+        }
+
+        let error = write_boxen(&mut FailingWriter, "Hello", None).unwrap_err();
+        assert!(matches!(error, crate::BoxenError::RenderingError { .. }));
+
+        // Rendering errors are returned before anything is written
+        let mut output = Vec::new();
+        let options = BoxenOptions {
+            width: Some(crate::options::Width::Fixed(1)),
+            ..Default::default()
+        };
+        assert!(write_boxen(&mut output, "Hello", Some(options)).is_err());
+        assert!(output.is_empty());
+    }
+
+    #[test]
+    fn test_write_boxen_color_detection() {
+        let colored_options = |force_color| BoxenOptions {
+            border_color: Some(crate::options::Color::Named("red".to_string())),
+            force_color,
+            ..Default::default()
+        };
+
+        let mut output = Vec::new();
+        write_boxen(&mut output, "Hello", Some(colored_options(Some(false)))).unwrap();
+        assert_eq!(
+            String::from_utf8(output).unwrap(),
+            "┌─────┐\n│Hello│\n└─────┘\n"
+        );
+
+        let mut output = Vec::new();
+        write_boxen(&mut output, "Hello", Some(colored_options(Some(true)))).unwrap();
+        assert!(String::from_utf8(output).unwrap().contains("\x1b[31m"));
+
+        // A plain writer is not a terminal, so only the environment can force color
+        let mut output = Vec::new();
+        write_boxen(&mut output, "Hello", Some(colored_options(None))).unwrap();
+        assert_eq!(
+            String::from_utf8(output).unwrap().contains('\x1b'),
+            crate::color::should_use_color(None, false)
+        );
+    }
+
+    #[test]
+    fn test_print_and_eprint_boxen() {
+        assert!(print_boxen("stdout", None).is_ok());
+        assert!(eprint_boxen("stderr", None).is_ok());
+    }
+
+    #[test]
+    fn test_auto_border_color() {
+        use crate::options::Color;
+
+        let render = |text: &str| {
+            let options = BoxenOptions {
+                border_color: Some(Color::Auto),
+                ..Default::default()
+            };
+            boxen(text, Some(options)).unwrap()
+        };
+        let explicit = |text: &str| {
+            let options = BoxenOptions {
+                border_color: Some(crate::color::auto_color(text)),
+                ..Default::default()
+            };
+            boxen(text, Some(options)).unwrap()
+        };
+
+        assert_eq!(render("disk full"), render("disk full"));
+        assert_eq!(render("disk full"), explicit("disk full"));
+        assert_ne!(
+            render("disk full").lines().next(),
+            render("disk fine").lines().next()
+        );
+    }
+
+    #[test]
+    fn test_scrollbar_thumb_at_top() {
+        let options = BoxenOptions {
+            scrollbar: Some(crate::options::ScrollbarState::new(0, 10, 3)),
+            ..Default::default()
+        };
+        let result = boxen("one\ntwo\nsix", Some(options)).unwrap();
+        assert_eq!(result, "┌───┐\n│one█\n│two│\n│six│\n└───┘");
+    }
+
+    #[test]
+    fn test_scrollbar_thumb_position_and_padding_rows() {
+        let options = BoxenOptions {
+            scrollbar: Some(crate::options::ScrollbarState::new(90, 100, 10)),
+            padding: crate::options::Spacing {
+                top: 1,
+                right: 0,
+                bottom: 1,
+                left: 0,
+            },
+            ..Default::default()
+        };
+        let text = "a\nb\nc\nd\ne\nf\ng\nh";
+        let lines: Vec<String> = boxen(text, Some(options))
+            .unwrap()
+            .lines()
+            .map(str::to_string)
+            .collect();
+
+        // 10 track rows, a 1-row thumb scrolled to the end lands on the bottom padding row
+        assert_eq!(lines.len(), 12);
+        assert!(lines[10].ends_with('█'));
+        assert_eq!(lines.iter().filter(|line| line.ends_with('█')).count(), 1);
+        assert!(lines.iter().all(|line| text_width(line) == 3));
+    }
+
+    #[test]
+    fn test_scrollbar_thumb_range() {
+        use crate::options::ScrollbarState;
+
+        assert_eq!(ScrollbarState::new(0, 10, 3).thumb_range(3), 0..1);
+        assert_eq!(ScrollbarState::new(7, 10, 3).thumb_range(3), 2..3);
+        assert_eq!(ScrollbarState::new(0, 20, 10).thumb_range(10), 0..5);
+        assert_eq!(ScrollbarState::new(5, 20, 10).thumb_range(10), 3..8);
+        assert_eq!(ScrollbarState::new(50, 20, 10).thumb_range(10), 5..10);
+        assert_eq!(ScrollbarState::new(0, 3, 10).thumb_range(4), 0..4);
+        assert_eq!(ScrollbarState::new(0, 0, 0).thumb_range(4), 0..0);
+    }
+
+    #[test]
+    fn test_title_exactly_filling_inner_width() {
+        let render = |require_fill: bool, alignment| {
+            let options = BoxenOptions {
+                title: Some("Status".to_string()),
+                title_alignment: alignment,
+                title_require_fill: require_fill,
+                width: Some(crate::options::Width::Fixed(8)),
+                ..Default::default()
+            };
+            let result = boxen("x", Some(options)).unwrap();
+            assert!(result.lines().all(|line| text_width(line) == 8));
+            result.lines().next().unwrap().to_string()
+        };
+
+        // Without the option the title replaces the whole border fill
+        assert_eq!(render(false, TitleAlignment::Left), "┌Status┐");
+        assert_eq!(render(false, TitleAlignment::Center), "┌Status┐");
+
+        // With it, the title is truncated to keep one fill character per side
+        assert_eq!(render(true, TitleAlignment::Left), "┌─Stat─┐");
+        assert_eq!(render(true, TitleAlignment::Center), "┌─Stat─┐");
+        assert_eq!(render(true, TitleAlignment::Right), "┌─Stat─┐");
+    }
+
+    #[test]
+    fn test_title_require_fill_short_title() {
+        let options = BoxenOptions {
+            title: Some("Hi".to_string()),
+            title_require_fill: true,
+            width: Some(crate::options::Width::Fixed(10)),
+            ..Default::default()
+        };
+        let result = boxen("x", Some(options)).unwrap();
+        assert_eq!(result.lines().next().unwrap(), "┌─Hi─────┐");
+    }
+
+    #[test]
+    fn test_alignment_markers_mix_left_and_center() {
+        let options = BoxenOptions {
+            alignment_marker: Some('\x01'),
+            width: Some(crate::options::Width::Fixed(12)),
+            ..Default::default()
+        };
+        let result = boxen("left\n\x01C\x01mid\n\x01R\x01end", Some(options)).unwrap();
+        let lines: Vec<&str> = result.lines().collect();
+
+        assert_eq!(lines[1], "│left      │");
+        assert_eq!(lines[2], "│   mid    │");
+        assert_eq!(lines[3], "│       end│");
+    }
+
+    #[test]
+    fn test_alignment_markers_apply_to_wrapped_rows() {
+        let options = BoxenOptions {
+            alignment_marker: Some('|'),
+            text_alignment: crate::options::TextAlignment::Right,
+            width: Some(crate::options::Width::Fixed(8)),
+            ..Default::default()
+        };
+        let result = boxen("|L|abcdefghij\nx\n|X|y", Some(options)).unwrap();
+        let lines: Vec<&str> = result.lines().collect();
+
+        assert_eq!(lines[1], "│abcdef│");
+        assert_eq!(lines[2], "│ghij  │");
+        assert_eq!(lines[3], "│     x│");
+        // Unknown markers are left in place
+        assert_eq!(lines[4], "│  |X|y│");
+    }
+
+    #[test]
+    fn test_alignment_markers_disabled_by_default() {
+        let result = boxen("|C|text", None).unwrap();
+        assert!(result.contains("|C|text"));
+        assert_eq!(
+            split_alignment_marker("\x01C\x01", '\x01'),
+            (Some(TextAlignment::Center), "")
+        );
+        assert_eq!(split_alignment_marker("\x01C", '\x01'), (None, "\x01C"));
+    }
+
+    #[test]
+    fn test_bottom_pointer() {
+        let options = BoxenOptions {
+            pointer: Some(crate::options::Pointer::new(Edge::Bottom, 3)),
+            ..Default::default()
+        };
+        let result = boxen("Hello, world", Some(options)).unwrap();
+        let lines: Vec<&str> = result.lines().collect();
+
+        assert_eq!(lines[0], "┌────────────┐");
+        assert_eq!(lines[2], "└───▼────────┘");
+        assert!(lines.iter().all(|line| text_width(line) == 14));
+    }
+
+    #[test]
+    fn test_top_pointer_and_offset_clamp() {
+        let options = BoxenOptions {
+            pointer: Some(crate::options::Pointer::new(Edge::Top, 99)),
+            border_style: crate::options::BorderStyle::Round,
+            ..Default::default()
+        };
+        let result = boxen("Hi", Some(options)).unwrap();
+        assert_eq!(result, "╭─▲╮\n│Hi│\n╰──╯");
+
+        // A titled top border keeps its title instead
+        let options = BoxenOptions {
+            pointer: Some(crate::options::Pointer::new(Edge::Top, 0)),
+            title: Some("T".to_string()),
+            ..Default::default()
+        };
+        let result = boxen("Hi", Some(options)).unwrap();
+        assert!(!result.contains('▲'));
+    }
+
+    #[test]
+    fn test_unbox_round_trip() {
+        assert_eq!(unbox(&crate::simple_box("Hello")).as_deref(), Some("Hello"));
+        assert_eq!(unbox(&crate::double_box("Hello")).as_deref(), Some("Hello"));
+
+        let options = BoxenOptions {
+            padding: Spacing::from(1),
+            margin: Spacing::from(2),
+            title: Some("Greeting".to_string()),
+            border_color: Some(crate::options::Color::Named("red".to_string())),
+            ..Default::default()
+        };
+        let boxed = boxen("Hello\nwide world", Some(options)).unwrap();
+        assert_eq!(unbox(&boxed).as_deref(), Some("Hello\nwide world"));
+    }
+
+    #[test]
+    fn test_unbox_rejects_non_boxes() {
+        assert_eq!(unbox(""), None);
+        assert_eq!(unbox("Hello"), None);
+        assert_eq!(unbox("┌──┐\n│Hi\n└──┘"), None);
+        assert_eq!(unbox("┌──┐\n│Hi│\n╚══╝"), None);
+    }
+
+    #[test]
+    fn test_numeric_align() {
+        let options = BoxenOptions {
+            numeric_align: true,
+            text_alignment: TextAlignment::Center,
+            ..Default::default()
+        };
+        let result = boxen("Revenue\n1,234.50\n-7.25\n42%\nQ3", Some(options)).unwrap();
+        let lines: Vec<&str> = result.lines().collect();
+
+        assert_eq!(lines[1], "│Revenue │");
+        assert_eq!(lines[2], "│1,234.50│");
+        assert_eq!(lines[3], "│   -7.25│");
+        assert_eq!(lines[4], "│     42%│");
+        assert_eq!(lines[5], "│   Q3   │");
+    }
+
+    #[test]
+    fn test_numeric_align_yields_to_markers() {
+        let options = BoxenOptions {
+            numeric_align: true,
+            alignment_marker: Some('|'),
+            ..Default::default()
+        };
+        let result = boxen("Amount\n|L|12\n7", Some(options)).unwrap();
+        let lines: Vec<&str> = result.lines().collect();
+
+        assert_eq!(lines[2], "│12    │");
+        assert_eq!(lines[3], "│     7│");
+    }
+
+    #[test]
+    fn test_max_width_ellipsizes_title() {
+        let options = BoxenOptions {
+            title: Some("Quarterly Revenue Report".to_string()),
+            max_width: Some(16),
+            ..Default::default()
+        };
+        let result = boxen("Revenue grew in every region", Some(options)).unwrap();
+        let lines: Vec<&str> = result.lines().collect();
+
+        // Content wraps to "Revenue grew" (12 columns) and the title follows
+        assert_eq!(lines[0], "┌Quarterly R…┐");
+        assert!(lines.iter().all(|line| text_width(line) == 14));
+    }
+
+    #[test]
+    fn test_max_width_keeps_narrow_boxes_and_margins() {
+        let options = BoxenOptions {
+            max_width: Some(40),
+            ..Default::default()
+        };
+        assert_eq!(boxen("Hi", Some(options)).unwrap(), "┌──┐\n│Hi│\n└──┘");
+
+        // The cap covers margins, and a fixed width larger than the cap is shrunk
+        let options = BoxenOptions {
+            title: Some("Deployment".to_string()),
+            margin: Spacing::from((2, 0)),
+            width: Some(Width::Fixed(30)),
+            max_width: Some(10),
+            ascii_only: true,
+            border_style: crate::options::BorderStyle::Classic,
+            ..Default::default()
+        };
+        let result = boxen("Done", Some(options)).unwrap();
+        let lines: Vec<&str> = result.lines().collect();
+
+        assert_eq!(lines[0], "  +D...+  ");
+        assert!(lines.iter().all(|line| text_width(line) <= 10));
+    }
+
+    #[test]
+    fn test_no_wrap_clip() {
+        let options = BoxenOptions {
+            wrap: false,
+            overflow_no_wrap: crate::options::NoWrapOverflow::Clip,
+            width: Some(Width::Fixed(10)),
+            ..Default::default()
+        };
+        let result = boxen("short\n\x1b[31mclipped red line\x1b[0m", Some(options)).unwrap();
+        let lines: Vec<&str> = result.lines().collect();
+
+        assert_eq!(lines.len(), 4);
+        assert_eq!(lines[1], "│short   │");
+        assert_eq!(lines[2], "│\x1b[31mclipped \x1b[0m│");
+        assert!(lines.iter().all(|line| text_width(line) == 10));
+    }
+
+    #[test]
+    fn test_no_wrap_overflow() {
+        let options = BoxenOptions {
+            wrap: false,
+            overflow_no_wrap: crate::options::NoWrapOverflow::Overflow,
+            width: Some(Width::Fixed(10)),
+            padding: Spacing::from((1, 0)),
+            ..Default::default()
+        };
+        let result = boxen("fits\nthis line runs long", Some(options)).unwrap();
+        let lines: Vec<&str> = result.lines().collect();
+
+        assert_eq!(lines[0], "┌────────┐");
+        assert_eq!(lines[1], "│ fits   │");
+        assert_eq!(lines[2], "│ this line runs long");
+        assert_eq!(lines[3], "└────────┘");
+    }
+
+    #[test]
+    fn test_no_wrap_error() {
+        let options = BoxenOptions {
+            wrap: false,
+            overflow_no_wrap: crate::options::NoWrapOverflow::Error,
+            width: Some(Width::Fixed(10)),
+            ..Default::default()
+        };
+        assert!(boxen("fits", Some(options.clone())).is_ok());
+
+        let err = boxen("this line runs long", Some(options)).unwrap_err();
+        assert!(err.to_string().contains("wrapping is disabled"));
+    }
+
+    #[test]
+    fn test_fullwidth_title_truncation() {
+        // Each fullwidth letter takes two columns; an odd title space leaves
+        // one column that must be filled with border, not half a glyph
+        for (width, expected) in [
+            (7, "┌ＡＢ─┐"),
+            (8, "┌ＡＢＣ┐"),
+            (9, "┌ＡＢＣ─┐"),
+            (3, "┌─┐"),
+            (5, "┌Ａ─┐"),
+        ] {
+            let options = BoxenOptions {
+                title: Some("ＡＢＣ".to_string()),
+                width: Some(Width::Fixed(width)),
+                ..Default::default()
+            };
+            let result = boxen("x", Some(options)).unwrap();
+            let lines: Vec<&str> = result.lines().collect();
+
+            assert_eq!(lines[0], expected, "width {width}");
+            assert!(lines.iter().all(|line| text_width(line) == width));
+        }
+    }
+
+    #[test]
+    fn test_fullwidth_title_with_ellipsis_and_no_border() {
+        let options = BoxenOptions {
+            title: Some("ＡＢＣＤ".to_string()),
+            max_width: Some(8),
+            width: Some(Width::Fixed(8)),
+            ..Default::default()
+        };
+        let result = boxen("x", Some(options)).unwrap();
+        assert_eq!(result.lines().next().unwrap(), "┌ＡＢ…─┐");
+
+        let options = BoxenOptions {
+            title: Some("ＡＢＣ".to_string()),
+            border_style: BorderStyle::None,
+            width: Some(Width::Fixed(5)),
+            ..Default::default()
+        };
+        let result = boxen("x", Some(options)).unwrap();
+        assert!(result.lines().all(|line| text_width(line) == 5));
+        assert!(result.starts_with("ＡＢ "));
+    }
+
+    #[test]
+    fn test_collapse_whitespace() {
+        let collapsed = BoxenOptions {
+            collapse_whitespace: true,
+            ..Default::default()
+        };
+        assert_eq!(
+            boxen("a     b", Some(collapsed.clone())).unwrap(),
+            "┌───┐\n│a b│\n└───┘"
+        );
+        assert_eq!(
+            boxen("a     b", None).unwrap(),
+            "┌───────┐\n│a     b│\n└───────┘"
+        );
+
+        // Indentation is preserved, tabs inside the line are squeezed too
+        let result = boxen("  x \t\t y\nz", Some(collapsed)).unwrap();
+        assert!(result.contains("│  x y│"));
+        assert!(result.contains("│z    │"));
+    }
+
+    #[test]
+    fn test_render_edges_match_rendered_borders() {
+        let options = BoxenOptions {
+            title: Some("Edges".to_string()),
+            border_style: crate::options::BorderStyle::Double,
+            border_color: Some(crate::options::Color::Named("cyan".to_string())),
+            pointer: Some(crate::options::Pointer::new(Edge::Bottom, 2)),
+            ..Default::default()
+        };
+        let border_chars = options.border_style.get_chars().unwrap();
+        let edges = render_edges(&options, 12, 3).unwrap();
+
+        assert_eq!(
+            edges.top,
+            render_top_border(&border_chars, &options, 12, Measure::default()).unwrap()
+        );
+        assert_eq!(
+            edges.bottom,
+            render_bottom_border(&border_chars, 12, &options).unwrap()
+        );
+        assert_eq!(edges.left.len(), 3);
+        assert_eq!(edges.right.len(), 3);
+
+        // Edges reassemble into the same box boxen draws
+        let rendered = boxen("Content     \nof the\nbox", Some(options)).unwrap();
+        let lines: Vec<&str> = rendered.lines().collect();
+        assert_eq!(lines[0], edges.top);
+        assert_eq!(lines[4], edges.bottom);
+        assert!(lines[1].starts_with(edges.left[0].as_str()));
+        assert!(lines[1].ends_with(edges.right[0].as_str()));
+    }
+
+    #[test]
+    fn test_render_edges_scrollbar_and_no_border() {
+        let options = BoxenOptions {
+            scrollbar: Some(crate::options::ScrollbarState::new(0, 8, 2)),
+            ..Default::default()
+        };
+        let edges = render_edges(&options, 2, 4).unwrap();
+        assert_eq!(edges.right, vec!["█", "│", "│", "│"]);
+
+        let options = BoxenOptions {
+            border_style: crate::options::BorderStyle::None,
+            ..Default::default()
+        };
+        let edges = render_edges(&options, 5, 2).unwrap();
+        assert!(edges.top.is_empty() && edges.bottom.is_empty());
+        assert_eq!(edges.left, vec!["", ""]);
+    }
+
+    #[test]
+    fn test_no_border_rows_share_width() {
+        let plain_width = |line: &str| text_width(&crate::text::strip_ansi_codes(line));
+
+        for text in ["short\nmuch longer line", "你好\nab", "x"] {
+            let options = BoxenOptions {
+                border_style: BorderStyle::None,
+                padding: Spacing::from([1, 3, 2, 2]),
+                background_color: Some(crate::options::Color::Named("blue".to_string())),
+                text_alignment: TextAlignment::Center,
+                ..Default::default()
+            };
+            let result = boxen(text, Some(options)).unwrap();
+            let lines: Vec<&str> = result.lines().collect();
+
+            let widest = text.lines().map(text_width).max().unwrap() + 5;
+            assert_eq!(lines.len(), text.lines().count() + 3);
+            assert!(
+                lines.iter().all(|line| plain_width(line) == widest),
+                "{text:?}: {lines:?}"
+            );
+        }
+    }
+
+    #[test]
+    fn test_left_float_margins_clamped_to_terminal() {
+        let terminal_width = crate::terminal::get_terminal_width();
+        let box_line = "─".repeat(terminal_width + 10);
+        let mut options = BoxenOptions {
+            margin: Spacing::from((4, 0)),
+            ..Default::default()
+        };
+        // A layout wider than the terminal, as produced for over-wide rows
+        let layout = crate::options::LayoutDimensions {
+            content_width: terminal_width + 10,
+            content_height: 1,
+            total_width: terminal_width + 18,
+            total_height: 1,
+            inner_width: terminal_width + 10,
+            inner_height: 1,
+        };
+
+        let result = float_position_line(&box_line, &options, &layout, Measure::default());
+        assert_eq!(result, box_line);
+
+        // Output bound for a file keeps the margins exactly
+        options.ignore_terminal_bounds = true;
+        let result = float_position_line(&box_line, &options, &layout, Measure::default());
+        assert_eq!(result, format!("    {box_line}    "));
+    }
+
+    #[test]
+    fn test_left_float_margins_with_overflowing_row() {
+        let terminal_width = crate::terminal::get_terminal_width();
+        let options = BoxenOptions {
+            wrap: false,
+            overflow_no_wrap: crate::options::NoWrapOverflow::Overflow,
+            margin: Spacing::from((2, 0)),
+            width: Some(Width::Fixed(20)),
+            ..Default::default()
+        };
+        let long_line = "x".repeat(terminal_width);
+        let result = boxen(format!("ok\n{long_line}"), Some(options)).unwrap();
+        let lines: Vec<&str> = result.lines().collect();
+
+        assert!(lines[1].starts_with("  │ok"));
+        assert!(lines[2].starts_with(&format!("│{long_line}")));
+    }
+
+    #[test]
+    fn test_left_float_margins_with_wide_box() {
+        let terminal_width = crate::terminal::get_terminal_width();
+
+        // Within the terminal, margins are kept as requested
+        let options = BoxenOptions {
+            width: Some(Width::Fixed(terminal_width)),
+            margin: Spacing::from((3, 0)),
+            ..Default::default()
+        };
+        let result = boxen("Wide", Some(options)).unwrap();
+        assert!(result.lines().all(|line| line.starts_with("   ┌")
+            || line.starts_with("   │")
+            || line.starts_with("   └")));
+        assert!(
+            result
+                .lines()
+                .all(|line| text_width(line) == terminal_width)
+        );
+
+        // Beyond it, only when bounds are ignored
+        let options = BoxenOptions {
+            width: Some(Width::Fixed(terminal_width + 20)),
+            margin: Spacing::from((3, 0)),
+            ignore_terminal_bounds: true,
+            ..Default::default()
+        };
+        let result = boxen("Wide", Some(options)).unwrap();
+        assert!(result.lines().all(|line| line.starts_with("   ")));
+        assert!(
+            result
+                .lines()
+                .all(|line| text_width(line) == terminal_width + 20)
+        );
+    }
+
+    /// Whether every visible character of `line` is drawn on background `code`
+    fn fully_on_background(line: &str, code: &str) -> bool {
+        let mut active = false;
+        let mut rest = line;
+        while let Some(ch) = rest.chars().next() {
+            if let Some(sequence) = rest.strip_prefix("\x1b[") {
+                let end = sequence.find('m').unwrap();
+                for param in sequence[..end].split(';') {
+                    if param == "0" {
+                        active = false;
+                    } else if param == code {
+                        active = true;
+                    }
+                }
+                rest = &sequence[end + 1..];
+                continue;
+            }
+            if !active {
+                return false;
+            }
+            rest = &rest[ch.len_utf8()..];
+        }
+        true
+    }
+
+    #[test]
+    fn test_title_full_width_header_bar() {
+        for alignment in [
+            TitleAlignment::Left,
+            TitleAlignment::Center,
+            TitleAlignment::Right,
+        ] {
+            let options = BoxenOptions {
+                title: Some("Header".to_string()),
+                title_alignment: alignment,
+                title_full_width: true,
+                title_connectors: Some(('┤', '├')),
+                background_color: Some(crate::options::Color::Named("blue".to_string())),
+                border_color: Some(crate::options::Color::Named("white".to_string())),
+                width: Some(Width::Fixed(20)),
+                ..Default::default()
+            };
+            let result = boxen("Body", Some(options)).unwrap();
+            let top = result.lines().next().unwrap();
+
+            assert!(fully_on_background(top, "44"), "{alignment:?}: {top:?}");
+            assert_eq!(text_width(top), 20);
+        }
+    }
+
+    #[test]
+    fn test_title_full_width_off_keeps_border_plain() {
+        let options = BoxenOptions {
+            title: Some("Header".to_string()),
+            background_color: Some(crate::options::Color::Named("blue".to_string())),
+            ..Default::default()
+        };
+        let result = boxen("Body", Some(options)).unwrap();
+        let top = result.lines().next().unwrap();
+        assert!(!top.contains("44"));
+        assert!(result.lines().nth(1).unwrap().contains("\x1b[44m"));
+    }
+
+    #[test]
+    fn test_border_thickness_doubles_sides() {
+        let options = BoxenOptions {
+            border_thickness: 2,
+            padding: Spacing::from((1, 0)),
+            ..Default::default()
+        };
+        let result = boxen("Hi", Some(options)).unwrap();
+        let lines: Vec<&str> = result.lines().collect();
+        assert_eq!(lines, vec!["┌──────┐", "││ Hi ││", "└──────┘"]);
+        assert!(lines.iter().all(|line| text_width(line) == 8));
+    }
+
+    #[test]
+    fn test_border_thickness_fixed_width_and_title() {
+        let options = BoxenOptions {
+            border_thickness: 2,
+            width: Some(Width::Fixed(12)),
+            title: Some("T".to_string()),
+            ..Default::default()
+        };
+        let result = boxen("wrap this text", Some(options)).unwrap();
+        let lines: Vec<&str> = result.lines().collect();
+        assert!(lines.iter().all(|line| text_width(line) == 12));
+        assert!(lines[0].starts_with("┌T"));
+        for line in &lines[1..lines.len() - 1] {
+            assert!(line.starts_with("││") && line.ends_with("││"));
+        }
+        assert_eq!(lines.len(), 5); // wrapped to 8 content columns
+    }
+
+    #[test]
+    fn test_border_thickness_edges() {
+        let options = BoxenOptions {
+            border_thickness: 3,
+            ..Default::default()
+        };
+        let edges = render_edges(&options, 2, 1).unwrap();
+        assert_eq!(edges.left, vec!["│││"]);
+        assert_eq!(edges.right, vec!["│││"]);
+        assert_eq!(text_width(&edges.top), 2 + 2 * 3);
+    }
+
+    #[test]
+    fn test_group_encloses_boxes() {
+        let narrow = boxen("a", None).unwrap();
+        let wide = boxen("wider", None).unwrap();
+        let options = BoxenOptions {
+            padding: Spacing::from((1, 0)),
+            ..Default::default()
+        };
+        let grouped = group(&[&narrow, &wide], &options).unwrap();
+        let lines: Vec<&str> = grouped.lines().collect();
+        assert_eq!(
+            lines,
+            vec![
+                "┌─────────┐",
+                "│ ┌─┐     │",
+                "│ │a│     │",
+                "│ └─┘     │",
+                "│ ┌─────┐ │",
+                "│ │wider│ │",
+                "│ └─────┘ │",
+                "└─────────┘",
+            ]
+        );
+        assert_eq!(unbox(&grouped).unwrap(), format!("{narrow}\n{wide}"));
+    }
+
+    #[test]
+    fn test_wide_char_odd_cell_keeps_border_aligned() {
+        // Inner width 5: "ab你" is 4 columns, and the next wide char cannot
+        // use the one remaining cell
+        let options = BoxenOptions {
+            width: Some(Width::Fixed(7)),
+            ..Default::default()
+        };
+        let result = boxen("ab你好世", Some(options)).unwrap();
+        let lines: Vec<&str> = result.lines().collect();
+        assert_eq!(lines, vec!["┌─────┐", "│ab你 │", "│好世 │", "└─────┘"]);
+        assert!(lines.iter().all(|line| text_width(line) == 7));
+
+        let options = BoxenOptions {
+            width: Some(Width::Fixed(7)),
+            text_alignment: TextAlignment::Right,
+            ..Default::default()
+        };
+        let result = boxen("ab你好世", Some(options)).unwrap();
+        assert!(result.lines().all(|line| text_width(line) == 7));
+        assert!(
+            result
+                .lines()
+                .skip(1)
+                .take(2)
+                .all(|line| line.ends_with('│'))
+        );
+    }
+
+    #[test]
+    fn test_registered_border_style() {
+        let chars = crate::options::BorderChars {
+            top_left: '<',
+            top_right: '>',
+            bottom_left: '{',
+            bottom_right: '}',
+            left: '[',
+            right: ']',
+            top: '=',
+            bottom: '~',
+        };
+        crate::borders::register_style("mystyle", chars);
+
+        let options = BoxenOptions {
+            border_style: BorderStyle::Registered("mystyle".to_string()),
+            ..Default::default()
+        };
+        let result = boxen("Hi", Some(options)).unwrap();
+        assert_eq!(result, "<==>\n[Hi]\n{~~}");
+
+        let options = BoxenOptions {
+            border_style: BorderStyle::Registered("render-test-unregistered".to_string()),
+            ..Default::default()
+        };
+        let error = boxen("Hi", Some(options)).unwrap_err();
+        assert!(error.to_string().contains("render-test-unregistered"));
+        assert!(!error.recommendations().is_empty());
+    }
+
+    #[test]
+    fn test_vertical_whitespace_handling() {
+        let result = boxen("one\x0ctwo\x0bsix", None).unwrap();
+        assert_eq!(result, "┌───┐\n│one│\n│two│\n│six│\n└───┘");
+
+        let strip = BoxenOptions {
+            vertical_whitespace: VerticalWhitespace::Strip,
+            ..Default::default()
+        };
+        let result = boxen("page\x0c1", Some(strip)).unwrap();
+        assert_eq!(result, "┌─────┐\n│page1│\n└─────┘");
+
+        let keep = BoxenOptions {
+            vertical_whitespace: VerticalWhitespace::Keep,
+            ..Default::default()
+        };
+        let result = boxen("a\x0cb", Some(keep)).unwrap();
+        assert!(result.contains("a\x0cb"));
+        assert_eq!(result.lines().count(), 3);
+    }
+
+    #[test]
+    fn test_title_position_above() {
+        let options = BoxenOptions {
+            title: Some("Status".to_string()),
+            title_position: TitlePosition::Above,
+            title_alignment: TitleAlignment::Center,
+            margin: Spacing::from([1, 0, 0, 0]),
+            width: Some(Width::Fixed(12)),
+            ..Default::default()
+        };
+        let result = boxen("ok", Some(options)).unwrap();
+        let lines: Vec<&str> = result.lines().collect();
+        assert_eq!(lines[0], "   Status   ");
+        assert_eq!(lines[1], "");
+        assert_eq!(lines[2], "┌──────────┐");
+        assert_eq!(lines.len(), 5);
+
+        // Without a border the title line is not repeated inside the box
+        let options = BoxenOptions {
+            title: Some("T".to_string()),
+            title_position: TitlePosition::Above,
+            border_style: BorderStyle::None,
+            ..Default::default()
+        };
+        let result = boxen("body", Some(options)).unwrap();
+        assert_eq!(result, "T   \nbody");
+    }
+
+    #[test]
+    fn test_trailing_combining_mark_box_width() {
+        let result = boxen("a\u{0301}", None).unwrap();
+        assert_eq!(result, "┌─┐\n│a\u{0301}│\n└─┘");
+        assert!(result.lines().all(|line| text_width(line) == 3));
+
+        let options = BoxenOptions {
+            width: Some(Width::Fixed(3)),
+            ..Default::default()
+        };
+        let result = boxen("e\u{0301}e\u{0301}", Some(options)).unwrap();
+        assert_eq!(result, "┌─┐\n│e\u{0301}│\n│e\u{0301}│\n└─┘");
+        assert!(result.lines().all(|line| text_width(line) == 3));
+    }
 
-    // Apply background color if specified
-    let styled_title = if let Some(bg_color) = &options.background_color {
-        apply_colors(&title_line, None, Some(bg_color))?.to_string()
-    } else {
-        title_line
-    };
+    #[test]
+    fn test_boxen_annotated_row_kinds() {
+        let options = BoxenOptions {
+            margin: Spacing::from([1, 0, 1, 0]),
+            padding: Spacing::from([1, 0, 1, 0]),
+            ..Default::default()
+        };
+        let (rendered, rows) = boxen_annotated("one\ntwo", Some(options.clone())).unwrap();
+        assert_eq!(rendered, boxen("one\ntwo", Some(options)).unwrap());
+        assert_eq!(
+            rows,
+            vec![
+                RowKind::Margin,
+                RowKind::TopBorder,
+                RowKind::Padding,
+                RowKind::Content(0),
+                RowKind::Content(1),
+                RowKind::Padding,
+                RowKind::BottomBorder,
+                RowKind::Margin,
+            ]
+        );
+        assert_eq!(rendered.lines().count(), rows.len());
+    }
 
-    Ok(styled_title)
-}
+    #[test]
+    fn test_boxen_annotated_title_and_caption_rows() {
+        let options = BoxenOptions {
+            title: Some("T".to_string()),
+            title_position: TitlePosition::Above,
+            caption: Some("c".to_string()),
+            height: Some(Height::Fixed(5)),
+            ..Default::default()
+        };
+        let (rendered, rows) = boxen_annotated("x", Some(options)).unwrap();
+        assert_eq!(rows.first(), Some(&RowKind::Title));
+        assert_eq!(rows.last(), Some(&RowKind::Caption));
+        assert_eq!(rendered.lines().count(), rows.len());
 
-/// Add a line to the result with float positioning applied
-fn add_line_with_float_positioning(
-    result: &mut String,
-    line: &str,
-    options: &BoxenOptions,
-    layout: &crate::options::LayoutDimensions,
-) {
-    use crate::options::Float;
-    use crate::terminal::get_terminal_width;
+        let borderless = BoxenOptions {
+            border_style: BorderStyle::None,
+            title: Some("T".to_string()),
+            ..Default::default()
+        };
+        let (rendered, rows) = boxen_annotated("x", Some(borderless)).unwrap();
+        assert_eq!(rows, vec![RowKind::Title, RowKind::Content(0)]);
+        assert_eq!(rendered.lines().count(), rows.len());
+    }
 
-    let terminal_width = get_terminal_width();
-    let box_width_without_margins = layout.total_width - options.margin.horizontal();
+    #[test]
+    fn test_inner_border_draws_both_frames() {
+        let options = BoxenOptions {
+            inner_border: Some(BorderStyle::Single),
+            border_style: BorderStyle::Double,
+            padding: Spacing::from([1, 2, 1, 2]),
+            ..Default::default()
+        };
+        let result = boxen("Hi", Some(options)).unwrap();
+        assert_eq!(
+            result,
+            "╔════════╗\n║        ║\n║ ┌────┐ ║\n║ │ Hi │ ║\n║ └────┘ ║\n║        ║\n╚════════╝"
+        );
+    }
 
-    // Calculate positioning based on float mode
-    let left_spacing = match options.float {
-        Float::Left => {
-            // Left float: use the specified left margin
-            options.margin.left
-        }
-        Float::Center => {
-            // Center float: center the box within terminal width
-            let available_space = terminal_width.saturating_sub(layout.total_width);
-            available_space / 2 + options.margin.left
-        }
-        Float::Right => {
-            // Right float: align to right edge minus right margin
-            if terminal_width > box_width_without_margins + options.margin.right {
-                terminal_width - box_width_without_margins - options.margin.right
-            } else {
-                options.margin.left // Fallback to left margin if not enough space
-            }
-        }
-    };
+    #[test]
+    fn test_inner_border_without_padding_touches_outer() {
+        let options = BoxenOptions {
+            inner_border: Some(BorderStyle::Single),
+            width: Some(Width::Fixed(10)),
+            text_alignment: TextAlignment::Center,
+            ..Default::default()
+        };
+        let result = boxen("Hi", Some(options)).unwrap();
+        assert_eq!(
+            result,
+            "┌────────┐\n│┌──────┐│\n││  Hi  ││\n│└──────┘│\n└────────┘"
+        );
+    }
 
-    // Add calculated left spacing
-    for _ in 0..left_spacing {
-        result.push(' ');
+    #[test]
+    fn test_inner_border_wraps_inside_inner_frame() {
+        let options = BoxenOptions {
+            inner_border: Some(BorderStyle::Single),
+            padding: Spacing::from([0, 1, 0, 1]),
+            width: Some(Width::Fixed(12)),
+            ..Default::default()
+        };
+        let result = boxen("aaaa bbbb", Some(options)).unwrap();
+        let lines: Vec<&str> = result.lines().collect();
+        assert_eq!(lines[1], "│ ┌──────┐ │");
+        assert_eq!(lines[2], "│ │aaaa  │ │");
+        assert_eq!(lines[3], "│ │bbbb  │ │");
+        assert!(lines.iter().all(|line| text_width(line) == 12));
     }
 
-    // Add the line content
-    result.push_str(line);
+    #[test]
+    fn test_inner_border_expands_tabs_inside_inner_frame() {
+        let options = BoxenOptions {
+            inner_border: Some(BorderStyle::Single),
+            border_style: BorderStyle::Double,
+            tab_width: Some(4),
+            ..Default::default()
+        };
+        let result = boxen("a\tb\nxx", Some(options)).unwrap();
+        let lines: Vec<&str> = result.lines().collect();
+        assert_eq!(lines[2], "║│a   b│║");
+        assert_eq!(lines[3], "║│xx   │║");
+        assert!(lines.iter().all(|line| text_width(line) == 9));
+    }
 
-    // For right margin, we only add it for left float mode
-    // For center and right float, the positioning handles the spacing
-    if matches!(options.float, Float::Left) {
-        for _ in 0..options.margin.right {
-            result.push(' ');
-        }
+    #[test]
+    fn test_inner_border_ascii_only() {
+        let options = BoxenOptions {
+            inner_border: Some(BorderStyle::Single),
+            border_style: BorderStyle::Classic,
+            ascii_only: true,
+            ..Default::default()
+        };
+        let result = boxen("Hi", Some(options)).unwrap();
+        assert!(result.is_ascii(), "{result}");
+        assert_eq!(result, "+----+\n|+--+|\n||Hi||\n|+--+|\n+----+");
     }
 
-    // Newline
-    result.push('\n');
-}
+    #[test]
+    fn test_inner_border_color() {
+        colored::control::set_override(true);
+        let options = BoxenOptions {
+            inner_border: Some(BorderStyle::Single),
+            inner_border_color: Some(crate::options::Color::Named("red".to_string())),
+            ..Default::default()
+        };
+        let result = boxen("Hi", Some(options)).unwrap();
+        let lines: Vec<&str> = result.lines().collect();
+        assert!(!lines[0].contains('\x1b'));
+        assert!(lines[1].contains("\x1b[31m┌"));
+    }
 
-#[cfg(test)]
-mod tests {
-    use super::*;
-    use crate::options::{
-        BorderStyle, BoxenOptions, Height, Spacing, TextAlignment, TitleAlignment, Width,
-    };
+    #[test]
+    fn test_title_position_left_writes_down_border() {
+        let options = BoxenOptions {
+            title: Some("Log".to_string()),
+            title_position: TitlePosition::Left,
+            padding: Spacing::from([1, 1, 1, 1]),
+            ..Default::default()
+        };
+        let result = boxen("Hi", Some(options)).unwrap();
+        assert_eq!(result, "┌────┐\nL    │\no Hi │\ng    │\n└────┘");
+    }
 
-    // Force enable colors for tests (colored crate disables them in non-TTY environments)
-    #[ctor::ctor]
-    fn init_colors() {
+    #[test]
+    fn test_title_position_left_alignment_and_truncation() {
+        let options = BoxenOptions {
+            title: Some("X".to_string()),
+            title_position: TitlePosition::Left,
+            title_alignment: TitleAlignment::Right,
+            ..Default::default()
+        };
+        let result = boxen("a\nb\nc", Some(options)).unwrap();
+        assert_eq!(result, "┌─┐\n│a│\n│b│\nXc│\n└─┘");
+
+        let options = BoxenOptions {
+            title: Some("Longer".to_string()),
+            title_position: TitlePosition::Left,
+            title_alignment: TitleAlignment::Center,
+            ..Default::default()
+        };
+        let result = boxen("a\nb", Some(options)).unwrap();
+        assert_eq!(result, "┌─┐\nLa│\nob│\n└─┘");
+    }
+
+    #[test]
+    fn test_reset_mode_targeted() {
         colored::control::set_override(true);
+        let options = BoxenOptions {
+            border_color: Some(crate::options::Color::Named("red".to_string())),
+            background_color: Some(crate::options::Color::Named("blue".to_string())),
+            reset_mode: ResetMode::Targeted,
+            ..Default::default()
+        };
+        let result = boxen("Hi", Some(options.clone())).unwrap();
+        assert!(!result.contains("\x1b[0m"));
+        assert!(result.contains("\x1b[39m"));
+        assert!(result.contains("\x1b[49m"));
+        assert_eq!(strip_ansi_codes(&result), "┌──┐\n│Hi│\n└──┘");
+
+        let full = boxen(
+            "Hi",
+            Some(BoxenOptions {
+                reset_mode: ResetMode::Full,
+                ..options
+            }),
+        )
+        .unwrap();
+        assert!(full.contains("\x1b[0m"));
     }
 
-    // Set terminal size for fullscreen tests
-    #[ctor::ctor]
-    fn init_terminal_size() {
-        unsafe {
-            std::env::set_var("COLUMNS", "80");
-            std::env::set_var("LINES", "24");
-        }
+    #[test]
+    fn test_spinner_box_frames() {
+        let options = BoxenOptions {
+            padding: Spacing::from(1),
+            ..Default::default()
+        };
+        let frames: Vec<String> = (0..SPINNER_FRAMES.len() + 1)
+            .map(|frame| spinner_box(frame, "Working", &options).unwrap())
+            .collect();
+
+        let glyph = |rendered: &str| rendered.lines().nth(2).unwrap().chars().nth(4).unwrap();
+        assert_eq!(glyph(&frames[0]), '⠋');
+        assert_eq!(glyph(&frames[1]), '⠙');
+        assert_ne!(frames[0], frames[1]);
+        // Frames wrap around
+        assert_eq!(frames[SPINNER_FRAMES.len()], frames[0]);
+
+        let width = text_width(frames[0].lines().next().unwrap());
+        assert!(
+            frames
+                .iter()
+                .flat_map(|rendered| rendered.lines())
+                .all(|line| text_width(line) == width)
+        );
     }
 
     #[test]
-    fn test_basic_box_rendering() {
-        let result = boxen("Hello", None).unwrap();
+    fn test_tab_width_in_title_and_caption() {
+        let options = BoxenOptions {
+            title: Some("ab\tc".to_string()),
+            caption: Some("\tend".to_string()),
+            tab_width: Some(4),
+            width: Some(Width::Fixed(12)),
+            ..Default::default()
+        };
+        let result = boxen("x", Some(options)).unwrap();
+        let lines: Vec<&str> = result.lines().collect();
+        // The tab stop is relative to the title's first column, not the border
+        assert_eq!(lines[0], "┌ab  c─────┐");
+        assert_eq!(lines[3], "    end     ");
+        assert!(lines[..3].iter().all(|line| text_width(line) == 12));
 
-        // Should contain the text
-        assert!(result.contains("Hello"));
+        // Without tab_width the title keeps its tab, measured as one column
+        let options = BoxenOptions {
+            title: Some("ab\tc".to_string()),
+            width: Some(Width::Fixed(12)),
+            ..Default::default()
+        };
+        let result = boxen("x", Some(options)).unwrap();
+        assert_eq!(result.lines().next().unwrap(), "┌ab\tc──────┐");
+    }
 
-        // Should have single-line borders (default)
-        assert!(result.contains("┌")); // Top-left corner
-        assert!(result.contains("┐")); // Top-right corner
-        assert!(result.contains("└")); // Bottom-left corner
-        assert!(result.contains("┘")); // Bottom-right corner
-        assert!(result.contains("│")); // Vertical borders
-        assert!(result.contains("─")); // Horizontal borders
+    #[test]
+    fn test_tab_width_in_content() {
+        let options = BoxenOptions {
+            tab_width: Some(8),
+            ..Default::default()
+        };
+        let result = boxen("name\tvalue\nid\t1", Some(options)).unwrap();
+        assert_eq!(
+            result,
+            "┌─────────────┐\n│name    value│\n│id      1    │\n└─────────────┘"
+        );
+    }
 
-        // Should be 3 lines (top border, content, bottom border)
+    #[test]
+    fn test_columns() {
+        let options = BoxenOptions {
+            columns: true,
+            ..Default::default()
+        };
+        let result = boxen("name\tvalue\nid\t1\nplain line", Some(options.clone())).unwrap();
+        assert_eq!(
+            result,
+            "┌───────────┐\n│name  value│\n│id    1    │\n│plain line │\n└───────────┘"
+        );
+
+        // Styled first cells are measured without their escape sequences
+        let result = boxen("\x1b[1mkey\x1b[0m\tv\nlonger\tw", Some(options.clone())).unwrap();
+        let plain = strip_ansi_codes(&result);
+        let rows: Vec<&str> = plain.lines().collect();
+        assert_eq!(rows[1..3], ["│key     v│", "│longer  w│"]);
+
+        // Column rows are not wrapped, even where the text would otherwise break
+        let options = BoxenOptions {
+            width: Some(Width::Fixed(12)),
+            ..options
+        };
+        let result = boxen("a\tone two three", Some(options.clone())).unwrap();
         assert_eq!(result.lines().count(), 3);
+        let result = boxen("one two three", Some(options)).unwrap();
+        assert!(result.lines().count() > 3);
     }
 
     #[test]
-    fn test_empty_text() {
-        let result = boxen("", None).unwrap();
+    fn test_columns_every_tab() {
+        let options = BoxenOptions {
+            columns: true,
+            ..Default::default()
+        };
+        let result = boxen("a\tb\tc\nlong\tx\ny\tmiddle\tz", Some(options)).unwrap();
+        assert!(!result.contains('\t'));
+        assert_eq!(
+            result,
+            "┌───────────────┐\n│a     b       c│\n│long  x        │\n│y     middle  z│\n└───────────────┘"
+        );
+    }
 
-        // Should still render a box
-        assert!(result.contains("┌"));
-        assert!(result.contains("┐"));
-        assert!(result.contains("└"));
-        assert!(result.contains("┘"));
+    #[test]
+    fn test_columns_inside_inner_frame() {
+        let options = BoxenOptions {
+            inner_border: Some(BorderStyle::Single),
+            border_style: BorderStyle::Double,
+            columns: true,
+            ..Default::default()
+        };
+        let result = boxen("k\tv\nlong\tw", Some(options)).unwrap();
+        let lines: Vec<&str> = result.lines().collect();
+        assert_eq!(lines[2], "║│k     v│║");
+        assert_eq!(lines[3], "║│long  w│║");
+        assert!(lines.iter().all(|line| text_width(line) == 11));
+    }
+
+    #[test]
+    fn test_line_ending_crlf() {
+        let options = BoxenOptions {
+            line_ending: LineEnding::CrLf,
+            margin: Spacing::from([0, 0, 1, 0]),
+            title: Some("T".to_string()),
+            ..Default::default()
+        };
+        let result = boxen("a\nb", Some(options.clone())).unwrap();
+        assert_eq!(result, "┌T┐\r\n│a│\r\n│b│\r\n└─┘\r\n\r\n");
+        assert!(!result.replace("\r\n", "").contains('\n'));
+
+        let mut output = Vec::new();
+        write_boxen(&mut output, "x", Some(options)).unwrap();
+        assert_eq!(
+            String::from_utf8(output).unwrap(),
+            "┌T┐\r\n│x│\r\n└─┘\r\n\r\n\r\n"
+        );
+    }
+
+    #[test]
+    fn test_boxen_with_width_fn_is_per_call() {
+        fn narrow(_: char) -> usize {
+            1
+        }
+        fn wide(ch: char) -> usize {
+            if ch.is_ascii() { 1 } else { 2 }
+        }
+        let options = BoxenOptions {
+            width: Some(Width::Fixed(6)),
+            ..Default::default()
+        };
+
+        let render = |width_fn: fn(char) -> usize| {
+            let options = options.clone();
+            std::thread::spawn(move || {
+                (0..20)
+                    .map(|_| {
+                        boxen_with_width_fn("日本語です", Some(options.clone()), width_fn).unwrap()
+                    })
+                    .collect::<Vec<_>>()
+            })
+        };
+        let (narrow_results, wide_results) = (render(narrow), render(wide));
+        let narrow_results = narrow_results.join().unwrap();
+        let wide_results = wide_results.join().unwrap();
+
+        assert!(
+            narrow_results
+                .iter()
+                .all(|r| r == "┌────┐\n│日本語で│\n│す   │\n└────┘")
+        );
+        assert!(
+            wide_results
+                .iter()
+                .all(|r| r == "┌────┐\n│日本│\n│語で│\n│す  │\n└────┘")
+        );
+
+        // The function only applies to the call it was passed to
+        assert_eq!(text_width("日本"), 4);
+        assert_eq!(boxen("日本語です", Some(options)).unwrap(), wide_results[0]);
+    }
+
+    #[test]
+    fn test_boxen_with_width_fn_not_seen_by_callbacks() {
+        let seen = std::sync::Arc::new(std::sync::Mutex::new(None));
+        let options = BoxenOptions {
+            width: Some(Width::Dynamic(std::sync::Arc::new({
+                let seen = seen.clone();
+                move |available| {
+                    let nested = boxen("日本", None).unwrap();
+                    *seen.lock().unwrap() = Some((text_width("日本"), nested));
+                    available.min(6)
+                }
+            }))),
+            ..Default::default()
+        };
+
+        let result = boxen_with_width_fn("日本", Some(options), |_| 1).unwrap();
+        assert_eq!(result, "┌────┐\n│日本  │\n└────┘");
+        let (width, nested) = seen.lock().unwrap().take().unwrap();
+        assert_eq!(width, 4);
+        assert_eq!(nested, "┌────┐\n│日本│\n└────┘");
+    }
+
+    #[test]
+    fn test_inset_shadow_padding_backgrounds() {
+        colored::control::set_override(true);
+        let options = BoxenOptions {
+            padding: Spacing::from(1),
+            inset_shadow: true,
+            ..Default::default()
+        };
+        let result = boxen("Hi", Some(options)).unwrap();
+        let lines: Vec<&str> = result.lines().collect();
+        assert_eq!(
+            strip_ansi_codes(&result),
+            "┌────────┐\n│        │\n│   Hi   │\n│        │\n└────────┘"
+        );
+
+        let dark = "\x1b[100m";
+        let light = "\x1b[47m";
+        assert_eq!(lines[1], format!("│{dark}        \x1b[0m│"));
+        assert_eq!(lines[2], format!("│{dark}   \x1b[0mHi{light}   \x1b[0m│"));
+        assert_eq!(lines[3], format!("│{light}        \x1b[0m│"));
 
-        // Should be 3 lines
-        assert_eq!(result.lines().count(), 3);
+        // Content keeps its own background between the shaded edges
+        let options = BoxenOptions {
+            padding: Spacing::from([0, 1, 0, 1]),
+            background_color: Some(crate::options::Color::Named("blue".to_string())),
+            inset_shadow: true,
+            width: Some(Width::Fixed(8)),
+            ..Default::default()
+        };
+        let result = boxen("Hi", Some(options)).unwrap();
+        assert_eq!(
+            result.lines().nth(1).unwrap(),
+            format!("│{dark} \x1b[0m\x1b[44mHi  \x1b[0m{light} \x1b[0m│")
+        );
     }
 
     #[test]
-    fn test_multiline_text() {
-        let result = boxen("Hello\nWorld", None).unwrap();
-
-        // Should contain both lines
-        assert!(result.contains("Hello"));
-        assert!(result.contains("World"));
-
-        // Should be 4 lines (top border, 2 content lines, bottom border)
-        assert_eq!(result.lines().count(), 4);
+    fn test_title_gradient_endpoints() {
+        colored::control::set_override(true);
+        let options = BoxenOptions {
+            title: Some("Gradient".to_string()),
+            title_color: Some(crate::options::Color::Named("green".to_string())),
+            title_gradient: Some((
+                crate::options::Color::Rgb(255, 0, 0),
+                crate::options::Color::Rgb(0, 0, 255),
+            )),
+            width: Some(Width::Fixed(12)),
+            ..Default::default()
+        };
+        let result = boxen("x", Some(options)).unwrap();
+        let top = result.lines().next().unwrap();
+
+        assert!(top.starts_with(&format!("┌{}", "G".truecolor(255, 0, 0))));
+        assert!(top.contains(&"t".truecolor(0, 0, 255).to_string()));
+        // The midpoint is blended and title_color is not used
+        assert!(top.contains(&"d".truecolor(146, 0, 109).to_string()));
+        assert!(!top.contains("\x1b[32m"));
+        assert_eq!(strip_ansi_codes(top), "┌Gradient──┐");
     }
 
     #[test]
-    fn test_no_border_style() {
+    fn test_collapse_margin_to_edge() {
         let options = BoxenOptions {
-            border_style: BorderStyle::None,
+            margin: Spacing::from(1),
+            collapse_margin_to_edge: true,
             ..Default::default()
         };
+        let result = crate::boxen("Hi", Some(options)).unwrap();
+        let lines: Vec<&str> = result.lines().collect();
+        assert_eq!(lines[0], "┌──┐   ");
+        assert!(lines.iter().all(|line| !line.starts_with(' ')));
+        // The bottom margin is kept
+        assert_eq!(lines.len(), 4);
 
-        let result = boxen("Hello", Some(options)).unwrap();
-
-        // Should contain the text
-        assert!(result.contains("Hello"));
-
-        // Should not contain border characters
-        assert!(!result.contains("┌"));
-        assert!(!result.contains("│"));
+        let options = BoxenOptions {
+            margin: Spacing::from(1),
+            float: crate::options::Float::Right,
+            collapse_margin_to_edge: true,
+            ..Default::default()
+        };
+        let result = crate::boxen("Hi", Some(options)).unwrap();
+        assert!(result.lines().next().unwrap().ends_with('┐'));
+        assert_eq!(text_width(result.lines().next().unwrap()), 80);
 
-        // Should be 1 line (just content)
-        assert_eq!(result.lines().count(), 1);
+        // Without the option the left margin is kept
+        let options = BoxenOptions {
+            margin: Spacing::from(1),
+            ..Default::default()
+        };
+        let result = crate::boxen("Hi", Some(options)).unwrap();
+        assert!(result.lines().nth(1).unwrap().starts_with("   ┌"));
     }
 
     #[test]
-    fn test_different_border_styles() {
-        let styles = vec![
-            BorderStyle::Single,
-            BorderStyle::Double,
-            BorderStyle::Round,
-            BorderStyle::Bold,
-            BorderStyle::Classic,
-        ];
-
-        for style in styles {
-            let options = BoxenOptions {
-                border_style: style,
+    fn test_boxen_line_iter_matches_boxen() {
+        use crate::options::{Color, Float, ScrollbarState};
+
+        let text = "first line\nsecond, somewhat longer line\n\nlast";
+        let configurations = [
+            BoxenOptions::default(),
+            BoxenOptions {
+                padding: Spacing::from(1),
+                margin: Spacing::from(2),
+                title: Some("Title".to_string()),
+                caption: Some("caption".to_string()),
                 ..Default::default()
-            };
-
-            let result = boxen("Test", Some(options)).unwrap();
-
-            // Should contain the text
-            assert!(result.contains("Test"));
+            },
+            BoxenOptions {
+                margin: Spacing::from((0, 0, 2, 0)),
+                float: Float::Center,
+                ..Default::default()
+            },
+            BoxenOptions {
+                border_style: BorderStyle::None,
+                padding: Spacing::from(1),
+                title: Some("Borderless".to_string()),
+                background_color: Some(Color::Named("blue".to_string())),
+                ..Default::default()
+            },
+            BoxenOptions {
+                title: Some("Above".to_string()),
+                title_position: TitlePosition::Above,
+                float: Float::Right,
+                scrollbar: Some(ScrollbarState::new(2, 10, 3)),
+                inset_shadow: true,
+                padding: Spacing::from(1),
+                ..Default::default()
+            },
+            BoxenOptions {
+                tile_horizontal: true,
+                reset_mode: ResetMode::Targeted,
+                border_color: Some(Color::Named("red".to_string())),
+                line_ending: LineEnding::CrLf,
+                ..Default::default()
+            },
+        ];
 
-            // Should be 3 lines
-            assert_eq!(result.lines().count(), 3);
+        for options in configurations {
+            let expected = crate::boxen(text, Some(options.clone())).unwrap();
+            let rows = boxen_line_iter(text, Some(options))
+                .unwrap()
+                .collect::<BoxenResult<Vec<_>>>()
+                .unwrap();
+            let expected: Vec<&str> = expected.lines().collect();
+            assert_eq!(rows, expected);
         }
     }
 
     #[test]
-    fn test_padding() {
+    fn test_boxen_line_iter_is_lazy() {
+        let text = "row\n".repeat(500);
         let options = BoxenOptions {
-            padding: Spacing {
-                top: 1,
-                right: 2,
-                bottom: 1,
-                left: 2,
-            },
+            ignore_terminal_bounds: true,
             ..Default::default()
         };
+        let mut rows = boxen_line_iter(&text, Some(options)).unwrap();
+        assert_eq!(rows.next().unwrap().unwrap(), "┌───┐");
+        assert_eq!(rows.next().unwrap().unwrap(), "│row│");
+        // The other 499 content rows and the bottom border remain
+        assert_eq!(rows.count(), 500);
+
+        let result = boxen_line_iter(
+            "Hi",
+            Some(BoxenOptions {
+                width: Some(Width::Fixed(1)),
+                ..Default::default()
+            }),
+        );
+        assert!(result.is_err());
+    }
 
-        let result = boxen("Hello", Some(options)).unwrap();
+    #[test]
+    fn test_truncation_indicator() {
+        let options = BoxenOptions {
+            height: Some(Height::Fixed(4)),
+            truncation_indicator: Some('▼'),
+            ..Default::default()
+        };
 
-        // Should be 5 lines (top border, top padding, content, bottom padding, bottom border)
-        assert_eq!(result.lines().count(), 5);
+        let result = crate::boxen("1\n2\n3\n4", Some(options.clone())).unwrap();
+        assert_eq!(result.lines().last().unwrap(), "└▼┘");
+        let result = crate::boxen("one\ntwo\nthree", Some(options.clone())).unwrap();
+        assert_eq!(result, "┌─────┐\n│one  │\n│two  │\n└──▼──┘");
 
-        // Content line should have padding
-        let lines: Vec<&str> = result.lines().collect();
-        let content_line = lines[2]; // Middle line should be content
-        assert!(content_line.contains("Hello"));
+        // Content that fits, even exactly, leaves the border alone
+        let result = crate::boxen("one\ntwo", Some(options.clone())).unwrap();
+        assert_eq!(result.lines().last().unwrap(), "└───┘");
+        let result = crate::boxen("one", Some(options.clone())).unwrap();
+        assert_eq!(result.lines().last().unwrap(), "└───┘");
 
-        // Should have left padding (2 spaces after left border)
-        assert!(content_line.starts_with("│  Hello"));
+        // Without an indicator truncation is silent, as before
+        let options = BoxenOptions {
+            truncation_indicator: None,
+            ..options
+        };
+        let result = crate::boxen("one\ntwo\nthree", Some(options)).unwrap();
+        assert_eq!(result.lines().last().unwrap(), "└─────┘");
     }
 
     #[test]
-    fn test_margins() {
+    fn test_mirror() {
+        use crate::options::{BorderChars, ScrollbarState};
+
+        let chars = BorderChars::from_template("┌─┐R┘─└L").unwrap();
         let options = BoxenOptions {
-            margin: Spacing {
-                top: 1,
-                right: 1,
-                bottom: 1,
-                left: 1,
-            },
-            ..Default::default() // Don't specify width to avoid calculation issues
+            border_style: BorderStyle::Custom(chars),
+            padding: Spacing::from((0, 0, 0, 2)),
+            width: Some(Width::Fixed(8)),
+            title: Some("T".to_string()),
+            mirror: true,
+            ..Default::default()
         };
+        let result = crate::boxen("ab", Some(options.clone())).unwrap();
+        // Right border characters on the left, padding on the right, text in order
+        assert_eq!(result, "┐─────T┌\nR  ab  L\n┘──────└");
 
-        let result = boxen("Hello", Some(options)).unwrap();
+        // The scrollbar thumb follows the right border to the left side
+        let options = BoxenOptions {
+            scrollbar: Some(ScrollbarState::new(0, 1, 1)),
+            title: None,
+            ..options
+        };
+        let result = crate::boxen("ab", Some(options)).unwrap();
+        assert!(result.lines().nth(1).unwrap().starts_with('█'));
 
-        // Should be 5 lines (top margin, top border, content, bottom border, bottom margin)
-        assert_eq!(result.lines().count(), 5);
+        let options = BoxenOptions {
+            margin: Spacing::from((0, 0, 0, 2)),
+            mirror: true,
+            ..Default::default()
+        };
+        let result = crate::boxen("ab", Some(options)).unwrap();
+        assert_eq!(result, "┐──┌  \n│ab│  \n┘──└  ");
+    }
 
-        // First line should be empty (top margin)
-        let lines: Vec<&str> = result.lines().collect();
-        assert_eq!(lines[0], "");
+    #[test]
+    fn test_bevel() {
+        let options = BoxenOptions {
+            bevel: 1,
+            padding: Spacing::from((1, 0)),
+            ..Default::default()
+        };
+        let result = crate::boxen("Hi", Some(options.clone())).unwrap();
+        assert_eq!(result, "┌ ── ┐\n│ Hi │\n└ ── ┘");
+        let plain = crate::boxen("Hi", Some(BoxenOptions::default())).unwrap();
+        let widths = |text: &str| text.lines().map(text_width).collect::<Vec<_>>();
 
-        // Last line should be empty (bottom margin)
-        assert_eq!(lines[4], "");
+        // The first and last fill cells are blank, with or without a title
+        let options = BoxenOptions {
+            title: Some("T".to_string()),
+            ..options
+        };
+        let result = crate::boxen("Hello", Some(options.clone())).unwrap();
+        assert_eq!(result.lines().next().unwrap(), "┌ T──── ┐");
+        let options = BoxenOptions {
+            title_alignment: TitleAlignment::Right,
+            ..options
+        };
+        let result = crate::boxen("Hello", Some(options)).unwrap();
+        assert_eq!(result.lines().next().unwrap(), "┌ ────T ┐");
 
-        // Border lines should have left margin (1 space)
-        assert!(lines[1].starts_with(" ┌"));
-        assert!(lines[3].starts_with(" └"));
+        // A bevel wider than the border blanks the whole fill, keeping the width
+        let options = BoxenOptions {
+            bevel: 10,
+            ..Default::default()
+        };
+        let result = crate::boxen("Hi", Some(options)).unwrap();
+        assert_eq!(result, "┌  ┐\n│Hi│\n└  ┘");
+        assert_eq!(widths(&result), widths(&plain));
     }
 
     #[test]
-    fn test_title_basic() {
+    fn test_render_placeholder() {
+        let placeholder = render_placeholder(&BoxenOptions::default(), 6, 4).unwrap();
+        let plain = strip_ansi_codes(&placeholder);
+        assert_eq!(plain, "┌╌╌╌╌┐\n╎    ╎\n╎    ╎\n└╌╌╌╌┘");
+        assert!(plain.lines().all(|line| text_width(line) == 6));
+        // Every border run is dimmed
+        assert!(placeholder.lines().all(|line| line.contains("\x1b[2m")));
+
+        // Color and title carry over
         let options = BoxenOptions {
-            title: Some("Title".to_string()),
+            title: Some("Drop".to_string()),
+            border_color: Some(crate::options::Color::Named("red".to_string())),
             ..Default::default()
         };
+        let placeholder = render_placeholder(&options, 10, 3).unwrap();
+        assert!(placeholder.starts_with("\x1b[2;31m┌"));
+        assert!(strip_ansi_codes(&placeholder).starts_with("┌Drop╌╌╌╌┐"));
+        assert_eq!(
+            strip_ansi_codes(&render_placeholder(&BoxenOptions::default(), 1, 0).unwrap()),
+            "┌┐\n└┘"
+        );
 
-        let result = boxen("Content", Some(options)).unwrap();
-
-        // Should contain both title and content
-        assert!(result.contains("Title"));
-        assert!(result.contains("Content"));
-
-        // Title should be in the top border
-        let lines: Vec<&str> = result.lines().collect();
-        let top_border = lines[0];
-        assert!(top_border.contains("Title"));
-        assert!(top_border.starts_with("┌"));
-        assert!(top_border.ends_with("┐"));
+        // ASCII output downgrades the frame and the title
+        let options = BoxenOptions {
+            title: Some("Drop…".to_string()),
+            ascii_only: true,
+            ..options
+        };
+        let placeholder = render_placeholder(&options, 10, 3).unwrap();
+        assert!(placeholder.is_ascii());
+        assert_eq!(
+            strip_ansi_codes(&placeholder),
+            "+Drop...-+\n:        :\n+--------+"
+        );
     }
 
     #[test]
-    fn test_title_alignment() {
-        let alignments = vec![
-            TitleAlignment::Left,
-            TitleAlignment::Center,
-            TitleAlignment::Right,
-        ];
+    fn test_render_placeholder_invalid_colors_are_errors() {
+        use crate::options::Color;
 
-        for alignment in alignments {
-            let options = BoxenOptions {
-                title: Some("Title".to_string()),
-                title_alignment: alignment,
-                width: Some(Width::Fixed(20)), // Fixed width for predictable alignment
+        for options in [
+            BoxenOptions {
+                border_color: Some(Color::Auto),
                 ..Default::default()
-            };
-
-            let result = boxen("Content", Some(options)).unwrap();
+            },
+            BoxenOptions {
+                title: Some("T".to_string()),
+                title_color: Some(Color::Auto),
+                ..Default::default()
+            },
+            BoxenOptions {
+                title: Some("T".to_string()),
+                title_color: Some(Color::Named("not-a-color".to_string())),
+                ..Default::default()
+            },
+        ] {
+            assert!(render_placeholder(&options, 6, 3).is_err(), "{options:?}");
+        }
+    }
 
-            // Should contain the title
-            assert!(result.contains("Title"));
+    #[test]
+    fn test_boxen_bytes_replaces_invalid_utf8() {
+        // A stray continuation byte and a truncated three-byte sequence
+        let result = boxen_bytes(b"a\x80b\nc\xE2\x82", None).unwrap();
+        assert_eq!(result, "┌───┐\n│a�b│\n│c� │\n└───┘");
+        assert!(result.lines().all(|line| text_width(line) == 5));
 
-            let lines: Vec<&str> = result.lines().collect();
-            let top_border = lines[0];
-            assert!(top_border.contains("Title"));
-        }
+        let valid = "日本 ok".as_bytes();
+        assert_eq!(
+            boxen_bytes(valid, None).unwrap(),
+            boxen("日本 ok", None).unwrap()
+        );
     }
 
     #[test]
-    fn test_title_truncation() {
+    fn test_truncated_styled_title_resets_before_border() {
         let options = BoxenOptions {
-            title: Some("Very Long Title That Should Be Truncated".to_string()),
-            width: Some(Width::Fixed(15)), // Small width to force truncation
+            title: Some("\x1b[32mA very long green title\x1b[0m".to_string()),
+            width: Some(Width::Fixed(10)),
             ..Default::default()
         };
+        let result = boxen("x", Some(options)).unwrap();
+        let top = result.lines().next().unwrap();
+        assert_eq!(top, "┌\x1b[32mA very l\x1b[0m┐");
+        assert_eq!(text_width(top), 10);
 
-        let result = boxen("Content", Some(options)).unwrap();
+        // Sequences are never split, and an explicit reset is not doubled
+        assert_eq!(
+            cut_to_width("\x1b[1mab\x1b[0mcd", 3, Measure::default()),
+            "\x1b[1mab\x1b[0mc"
+        );
+        assert_eq!(
+            cut_to_width("\x1b[1;31mabc", 0, Measure::default()),
+            "\x1b[1;31m\x1b[0m"
+        );
+        assert_eq!(cut_to_width("plain title", 5, Measure::default()), "plain");
+    }
 
-        // Should contain some part of the title
-        let lines: Vec<&str> = result.lines().collect();
-        let top_border = lines[0];
+    #[test]
+    fn test_join_vertical_connectors() {
+        let wide = boxen(
+            "wide box",
+            Some(BoxenOptions {
+                padding: Spacing::from([0, 1, 0, 1]),
+                ..Default::default()
+            }),
+        )
+        .unwrap();
+        let narrow = boxen("tiny", None).unwrap();
 
-        // Should start and end with border characters
-        assert!(top_border.starts_with("┌"));
-        assert!(top_border.ends_with("┐"));
+        let joined = join_vertical(&[&wide, &narrow], true);
+        let lines: Vec<&str> = joined.lines().collect();
+        assert_eq!(lines.len(), 3 + 1 + 3);
+        // The narrow box is 6 wide, so the connector sits in column 3
+        assert_eq!(lines[2], "└──┬───────┘");
+        assert_eq!(lines[3], "   │");
+        assert_eq!(lines[4], "┌──┴─┐");
 
-        // Should be exactly the specified width
-        assert_eq!(text_width(top_border), 15);
+        assert_eq!(
+            join_vertical(&[&wide, &narrow], false),
+            format!("{wide}\n{narrow}")
+        );
+        assert_eq!(join_vertical(&[&wide], true), wide);
+    }
+
+    #[test]
+    fn test_join_vertical_connectors_keep_colors_and_text() {
+        colored::control::set_override(true);
+        let colored_box = boxen(
+            "ab",
+            Some(BoxenOptions {
+                border_color: Some(crate::options::Color::Named("red".to_string())),
+                ..Default::default()
+            }),
+        )
+        .unwrap();
+        let plain = boxen(
+            "xyz",
+            Some(BoxenOptions {
+                border_style: BorderStyle::None,
+                ..Default::default()
+            }),
+        )
+        .unwrap();
+
+        let joined = join_vertical(&[&colored_box, &plain], true);
+        let lines: Vec<&str> = joined.lines().collect();
+        assert_eq!(strip_ansi_codes(lines[2]), "└┬─┘");
+        assert!(lines[2].starts_with("\x1b[31m"));
+        assert_eq!(lines[3], " │");
+        // Text without a border is never overwritten
+        assert_eq!(lines[4], "xyz");
     }
 
     #[test]
@@ -1513,7 +5666,7 @@ mod tests {
     #[test]
     fn test_process_content() {
         let options = BoxenOptions::default();
-        let content = process_content("Hello\nWorld", &options).unwrap();
+        let content = process_content("Hello\nWorld", &options, Measure::default()).unwrap();
 
         assert_eq!(content.lines.len(), 2);
         assert!(content.lines[0].contains("Hello"));
@@ -1529,7 +5682,7 @@ mod tests {
 
         let border_chars = BorderChars::single();
         let options = BoxenOptions::default();
-        let result = render_top_border(&border_chars, &options, 10).unwrap();
+        let result = render_top_border(&border_chars, &options, 10, Measure::default()).unwrap();
 
         assert_eq!(result, "┌──────────┐");
         assert_eq!(text_width(&result), 12); // 10 + 2 corners
@@ -1553,7 +5706,6 @@ mod tests {
     fn test_add_line_with_float_positioning() {
         use crate::options::Float;
 
-        let mut result = String::new();
         let options = BoxenOptions {
             float: Float::Left,
             margin: Spacing {
@@ -1573,9 +5725,9 @@ mod tests {
             inner_height: 1,
         };
 
-        add_line_with_float_positioning(&mut result, "test", &options, &layout);
+        let result = float_position_line("test", &options, &layout, Measure::default());
 
-        assert_eq!(result, "   test  \n");
+        assert_eq!(result, "   test  ");
     }
 
     #[test]
@@ -2197,7 +6349,12 @@ mod tests {
             ..Default::default()
         };
 
-        let content = process_content("Line 1\nLine 2\nLine 3\nLine 4\nLine 5", &options).unwrap();
+        let content = process_content(
+            "Line 1\nLine 2\nLine 3\nLine 4\nLine 5",
+            &options,
+            Measure::default(),
+        )
+        .unwrap();
 
         // Content height should be constrained by available space
         // Total height: 8, borders: 2, padding: 2 vertical = 4 available for content
@@ -2600,6 +6757,21 @@ mod tests {
         assert!(result.len() > uncolored_result.len());
     }
 
+    #[test]
+    fn test_text_color_integration() {
+        use crate::options::Color;
+
+        let options = BoxenOptions {
+            text_color: Some(Color::Named("white".to_string())),
+            background_color: Some(Color::Named("blue".to_string())),
+            ..Default::default()
+        };
+        let result = boxen("Hi", Some(options)).unwrap();
+        let content_row = result.lines().nth(1).unwrap();
+        assert_eq!(content_row, "│\x1b[44;37mHi\x1b[0m│");
+        assert!(result.lines().next().unwrap().starts_with('┌'));
+    }
+
     #[test]
     fn test_dim_border_integration() {
         let options = BoxenOptions {
@@ -2617,6 +6789,19 @@ mod tests {
         assert!(result.len() > normal_result.len());
     }
 
+    #[test]
+    fn test_dim_content() {
+        let options = BoxenOptions {
+            dim_content: true,
+            ..Default::default()
+        };
+        let result = boxen("Hello", Some(options)).unwrap();
+        let lines: Vec<&str> = result.lines().collect();
+
+        assert_eq!(lines[0], "┌─────┐");
+        assert_eq!(lines[1], format!("│{}│", "Hello".dimmed()));
+    }
+
     #[test]
     fn test_border_and_background_color_combination() {
         use crate::options::Color;
@@ -2803,7 +6988,7 @@ mod tests {
 
         for style in border_styles {
             let options = BoxenOptions {
-                border_style: style,
+                border_style: style.clone(),
                 border_color: Some(Color::Named("green".to_string())),
                 background_color: Some(Color::Named("black".to_string())),
                 ..Default::default()
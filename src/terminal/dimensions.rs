@@ -198,6 +198,28 @@ pub fn calculate_border_width(border_style: &BorderStyle) -> usize {
     }
 }
 
+/// Calculate the width consumed by side borders repeated `thickness` times
+///
+/// Each side border is drawn `thickness` characters wide, so a visible style
+/// takes `2 * thickness` columns. A thickness of 0 is treated as 1.
+///
+/// # Examples
+///
+/// ```
+/// use ::boxen::terminal::calculate_border_width_with_thickness;
+/// use ::boxen::BorderStyle;
+///
+/// assert_eq!(calculate_border_width_with_thickness(&BorderStyle::Single, 2), 4);
+/// assert_eq!(calculate_border_width_with_thickness(&BorderStyle::None, 2), 0);
+/// ```
+#[must_use]
+pub fn calculate_border_width_with_thickness(
+    border_style: &BorderStyle,
+    thickness: usize,
+) -> usize {
+    calculate_border_width(border_style) * thickness.max(1)
+}
+
 /// Calculate the maximum content width given terminal constraints and options
 ///
 /// This function takes into account the terminal width, border width, padding,
@@ -374,6 +396,26 @@ mod tests {
         assert_eq!(calculate_border_width(&BorderStyle::Bold), 2);
     }
 
+    #[test]
+    fn test_calculate_border_width_with_thickness() {
+        assert_eq!(
+            calculate_border_width_with_thickness(&BorderStyle::Single, 1),
+            2
+        );
+        assert_eq!(
+            calculate_border_width_with_thickness(&BorderStyle::Double, 2),
+            4
+        );
+        assert_eq!(
+            calculate_border_width_with_thickness(&BorderStyle::Round, 0),
+            2
+        );
+        assert_eq!(
+            calculate_border_width_with_thickness(&BorderStyle::None, 3),
+            0
+        );
+    }
+
     #[test]
     fn test_calculate_max_content_width_basic() {
         let result = calculate_max_content_width(
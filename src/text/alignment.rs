@@ -1,7 +1,7 @@
 /// Text alignment functionality
 use crate::memory::pool::with_pooled_string;
 use crate::options::{Spacing, TextAlignment};
-use crate::text::measurement::text_width;
+use crate::text::measurement::{Measure, strip_ansi_codes, text_width};
 
 /// Align a single line of text within a given width.
 ///
@@ -9,7 +9,38 @@ use crate::text::measurement::text_width;
 /// string building to minimize allocations.
 #[must_use]
 pub fn align_line(line: &str, alignment: TextAlignment, width: usize) -> String {
-    let line_width = text_width(line);
+    align_line_with(line, alignment, width, ' ')
+}
+
+/// Align a single line of text within a given width, padding with `fill`.
+///
+/// Useful for leaders such as a table of contents. A wide `fill` character
+/// is repeated as often as it fits, and any leftover column is a space.
+///
+/// # Examples
+///
+/// ```rust
+/// use ::boxen::{TextAlignment, text::align_line_with};
+///
+/// assert_eq!(
+///     align_line_with("Chapter 1", TextAlignment::Left, 20, '.'),
+///     "Chapter 1..........."
+/// );
+/// ```
+#[must_use]
+pub fn align_line_with(line: &str, alignment: TextAlignment, width: usize, fill: char) -> String {
+    align_line_measured(line, alignment, width, fill, Measure::default())
+}
+
+/// [`align_line_with`] with widths taken from `measure`
+pub(crate) fn align_line_measured(
+    line: &str,
+    alignment: TextAlignment,
+    width: usize,
+    fill: char,
+    measure: Measure,
+) -> String {
+    let line_width = measure.text_width(line);
 
     // If line is already wider than target width, return as-is
     if line_width >= width {
@@ -17,27 +48,35 @@ pub fn align_line(line: &str, alignment: TextAlignment, width: usize) -> String
     }
 
     let padding_needed = width - line_width;
+    let fill_width = unicode_width::UnicodeWidthChar::width(fill)
+        .unwrap_or(1)
+        .max(1);
 
     // Use pooled buffer for result
     with_pooled_string(|result| {
         // Reserve capacity upfront to avoid reallocations
         result.reserve(width);
 
+        let fill_columns = |columns: usize| {
+            std::iter::repeat_n(fill, columns / fill_width)
+                .chain(std::iter::repeat_n(' ', columns % fill_width))
+        };
+
         match alignment {
             TextAlignment::Left => {
                 result.push_str(line);
-                result.extend(std::iter::repeat_n(' ', padding_needed));
+                result.extend(fill_columns(padding_needed));
             }
             TextAlignment::Right => {
-                result.extend(std::iter::repeat_n(' ', padding_needed));
+                result.extend(fill_columns(padding_needed));
                 result.push_str(line);
             }
             TextAlignment::Center => {
                 let left_padding = padding_needed / 2;
                 let right_padding = padding_needed - left_padding;
-                result.extend(std::iter::repeat_n(' ', left_padding));
+                result.extend(fill_columns(left_padding));
                 result.push_str(line);
-                result.extend(std::iter::repeat_n(' ', right_padding));
+                result.extend(fill_columns(right_padding));
             }
         }
 
@@ -45,6 +84,26 @@ pub fn align_line(line: &str, alignment: TextAlignment, width: usize) -> String
     })
 }
 
+/// Check whether a line reads as a single number, such as `42`, `-1,234.50`,
+/// or `+7.5%`.
+///
+/// ANSI sequences and surrounding whitespace are ignored. An optional sign may
+/// lead and a percent sign may trail; digits may be grouped with `,` or `_`
+/// and contain at most one decimal point.
+#[must_use]
+pub fn is_numeric_line(line: &str) -> bool {
+    let plain = strip_ansi_codes(line);
+    let trimmed = plain.trim();
+    let unsigned = trimmed.strip_prefix(['+', '-']).unwrap_or(trimmed);
+    let body = unsigned.strip_suffix('%').unwrap_or(unsigned);
+
+    body.chars().any(|ch| ch.is_ascii_digit())
+        && body.matches('.').count() <= 1
+        && body
+            .chars()
+            .all(|ch| ch.is_ascii_digit() || matches!(ch, '.' | ',' | '_'))
+}
+
 /// Align multiple lines of text within a given width.
 ///
 /// Optimized version that pre-allocates the result vector to avoid reallocations.
@@ -224,6 +283,23 @@ pub fn apply_height_constraints(lines: &[String], max_height: usize) -> Vec<Stri
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_is_numeric_line() {
+        for line in [
+            "42",
+            " -1,234.50 ",
+            "+7.5%",
+            "1_000",
+            "\x1b[32m99\x1b[0m",
+            ".5",
+        ] {
+            assert!(is_numeric_line(line), "{line:?} should be numeric");
+        }
+        for line in ["", "-", "1.2.3", "v1.0", "12 apples", "1e5", "--5"] {
+            assert!(!is_numeric_line(line), "{line:?} should not be numeric");
+        }
+    }
+
     #[test]
     fn test_align_line_left() {
         assert_eq!(align_line("hello", TextAlignment::Left, 10), "hello     ");
@@ -238,6 +314,31 @@ mod tests {
         assert_eq!(align_line("exact", TextAlignment::Right, 5), "exact");
     }
 
+    #[test]
+    fn test_align_line_with_fill() {
+        assert_eq!(
+            align_line_with("Chapter 1", TextAlignment::Left, 20, '.'),
+            "Chapter 1..........."
+        );
+        assert_eq!(
+            align_line_with("42", TextAlignment::Right, 6, '.'),
+            "....42"
+        );
+        assert_eq!(
+            align_line_with("ab", TextAlignment::Center, 7, '-'),
+            "--ab---"
+        );
+        assert_eq!(
+            align_line_with("toolong", TextAlignment::Left, 3, '.'),
+            "toolong"
+        );
+
+        // A wide fill leaves the odd column as a space
+        let aligned = align_line_with("x", TextAlignment::Left, 6, '・');
+        assert_eq!(aligned, "x・・ ");
+        assert_eq!(text_width(&aligned), 6);
+    }
+
     #[test]
     fn test_align_line_center() {
         assert_eq!(align_line("hello", TextAlignment::Center, 10), "  hello   ");
@@ -0,0 +1,169 @@
+//! ANSI escape sequence tokenization
+//!
+//! Splits styled text into printable runs and the escape sequences between
+//! them. Measurement and wrapping are built on [`tokenize`], so downstream code
+//! using it sees text exactly the way boxen does.
+
+/// One piece of a string split by [`tokenize`]
+///
+/// Every variant borrows from the input, and concatenating the tokens in order
+/// reproduces it exactly.
+#[non_exhaustive]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum AnsiToken<'a> {
+    /// Printable text, including any `ESC` that does not start a sequence
+    Text(&'a str),
+    /// Select Graphic Rendition sequence (`ESC [ ... m`), i.e. colors and styles
+    Sgr(&'a str),
+    /// Any other control sequence (`ESC [ ...` ending in a letter other than `m`)
+    Csi(&'a str),
+    /// Operating System Command (`ESC ] ...`) ended by `BEL` or `ESC \`, such
+    /// as a hyperlink
+    Osc(&'a str),
+}
+
+/// Split `text` into printable runs and escape sequences
+///
+/// Escape sequences take no columns when displayed; only `Text` tokens are
+/// measured. An unterminated sequence runs to the end of the input.
+///
+/// # Examples
+///
+/// ```rust
+/// use ::boxen::text::ansi::{AnsiToken, tokenize};
+///
+/// assert_eq!(
+///     tokenize("\x1b[31mred\x1b[0m plain"),
+///     vec![
+///         AnsiToken::Sgr("\x1b[31m"),
+///         AnsiToken::Text("red"),
+///         AnsiToken::Sgr("\x1b[0m"),
+///         AnsiToken::Text(" plain"),
+///     ]
+/// );
+/// ```
+#[must_use]
+pub fn tokenize(text: &str) -> Vec<AnsiToken<'_>> {
+    let bytes = text.as_bytes();
+    let mut tokens = Vec::new();
+    let mut text_start = 0;
+    let mut search_from = 0;
+
+    while let Some(offset) = text[search_from..].find('\x1b') {
+        let start = search_from + offset;
+        let (end, token) = match bytes.get(start + 1) {
+            Some(b'[') => {
+                let end = csi_end(bytes, start + 2);
+                let sequence = &text[start..end];
+                if sequence.ends_with('m') {
+                    (end, AnsiToken::Sgr(sequence))
+                } else {
+                    (end, AnsiToken::Csi(sequence))
+                }
+            }
+            Some(b']') => {
+                let end = osc_end(bytes, start + 2);
+                (end, AnsiToken::Osc(&text[start..end]))
+            }
+            _ => {
+                // A lone ESC is ordinary text
+                search_from = start + 1;
+                continue;
+            }
+        };
+
+        if text_start < start {
+            tokens.push(AnsiToken::Text(&text[text_start..start]));
+        }
+        tokens.push(token);
+        text_start = end;
+        search_from = end;
+    }
+
+    if text_start < text.len() {
+        tokens.push(AnsiToken::Text(&text[text_start..]));
+    }
+    tokens
+}
+
+/// End of a control sequence whose parameters start at `from`: just past the
+/// first letter, which is never part of a multi-byte character
+fn csi_end(bytes: &[u8], from: usize) -> usize {
+    bytes[from..]
+        .iter()
+        .position(u8::is_ascii_alphabetic)
+        .map_or(bytes.len(), |index| from + index + 1)
+}
+
+/// End of an OSC sequence whose payload starts at `from`: just past `BEL` or
+/// the `ESC \` string terminator
+fn osc_end(bytes: &[u8], from: usize) -> usize {
+    let mut index = from;
+    while index < bytes.len() {
+        match bytes[index] {
+            b'\x07' => return index + 1,
+            b'\x1b' if bytes.get(index + 1) == Some(&b'\\') => return index + 2,
+            _ => index += 1,
+        }
+    }
+    bytes.len()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_tokenize_color_hyperlink_and_text() {
+        let text = "\x1b[1;32mok\x1b[0m see \x1b]8;;https://example.com\x1b\\docs\x1b]8;;\x07!";
+        assert_eq!(
+            tokenize(text),
+            vec![
+                AnsiToken::Sgr("\x1b[1;32m"),
+                AnsiToken::Text("ok"),
+                AnsiToken::Sgr("\x1b[0m"),
+                AnsiToken::Text(" see "),
+                AnsiToken::Osc("\x1b]8;;https://example.com\x1b\\"),
+                AnsiToken::Text("docs"),
+                AnsiToken::Osc("\x1b]8;;\x07"),
+                AnsiToken::Text("!"),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_tokenize_plain_and_edge_cases() {
+        assert_eq!(tokenize(""), vec![]);
+        assert_eq!(tokenize("日本 plain"), vec![AnsiToken::Text("日本 plain")]);
+        assert_eq!(
+            tokenize("a\x1b[2Kb"),
+            vec![
+                AnsiToken::Text("a"),
+                AnsiToken::Csi("\x1b[2K"),
+                AnsiToken::Text("b"),
+            ]
+        );
+        // A lone ESC stays in the text; unterminated sequences run to the end
+        assert_eq!(tokenize("a\x1bb"), vec![AnsiToken::Text("a\x1bb")]);
+        assert_eq!(
+            tokenize("a\x1b[31"),
+            vec![AnsiToken::Text("a"), AnsiToken::Csi("\x1b[31")]
+        );
+        assert_eq!(tokenize("\x1b]8;;url"), vec![AnsiToken::Osc("\x1b]8;;url")]);
+    }
+
+    #[test]
+    fn test_tokens_reassemble_input() {
+        let text = "\x1b[31m☎\u{FE0F}\x1b[39m\x1b]0;title\x07\x1b x";
+        let joined: String = tokenize(text)
+            .into_iter()
+            .map(|token| match token {
+                AnsiToken::Text(part)
+                | AnsiToken::Sgr(part)
+                | AnsiToken::Csi(part)
+                | AnsiToken::Osc(part) => part,
+            })
+            .collect();
+        assert_eq!(joined, text);
+    }
+}
@@ -0,0 +1,115 @@
+//! ASCII downgrading for terminals and logs that cannot render Unicode
+//!
+//! Non-ASCII characters are transliterated to a close ASCII equivalent where one
+//! exists (smart quotes, dashes, `…`, accented Latin letters) and replaced with
+//! `?` otherwise. Zero-width characters such as combining marks and variation
+//! selectors are dropped, and ANSI escape sequences pass through untouched.
+
+use std::borrow::Cow;
+use unicode_width::UnicodeWidthChar;
+
+/// Downgrade text to ASCII-only output
+///
+/// Returns the input unchanged (borrowed) when it is already ASCII.
+///
+/// # Examples
+///
+/// ```rust
+/// use ::boxen::text::to_ascii;
+///
+/// assert_eq!(to_ascii("“café” — done…"), "\"cafe\" - done...");
+/// assert_eq!(to_ascii("日本"), "??");
+/// assert_eq!(to_ascii("plain"), "plain");
+/// ```
+#[must_use]
+pub fn to_ascii(text: &str) -> Cow<'_, str> {
+    if text.is_ascii() {
+        return Cow::Borrowed(text);
+    }
+
+    let mut result = String::with_capacity(text.len());
+    for ch in text.chars() {
+        if ch.is_ascii() {
+            result.push(ch);
+        } else if let Some(replacement) = transliterate(ch) {
+            result.push_str(replacement);
+        } else if UnicodeWidthChar::width(ch).unwrap_or(0) > 0 {
+            result.push('?');
+        }
+    }
+    Cow::Owned(result)
+}
+
+/// Map a non-ASCII character to its closest ASCII spelling, if it has one
+fn transliterate(ch: char) -> Option<&'static str> {
+    let replacement = match ch {
+        '\u{2026}' => "...",
+        '\u{2018}' | '\u{2019}' | '\u{201A}' | '\u{2032}' => "'",
+        '\u{201C}' | '\u{201D}' | '\u{201E}' | '\u{2033}' | '\u{00AB}' | '\u{00BB}' => "\"",
+        '\u{2010}'..='\u{2015}' | '\u{2212}' => "-",
+        '\u{00A0}' | '\u{2002}'..='\u{200A}' | '\u{3000}' => " ",
+        '\u{2022}' | '\u{00B7}' | '\u{2219}' => "*",
+        '\u{2192}' => "->",
+        '\u{2190}' => "<-",
+        '\u{00D7}' => "x",
+        '\u{00A9}' => "(c)",
+        '\u{00AE}' => "(R)",
+        '\u{2122}' => "TM",
+        '\u{00C0}'..='\u{00C5}' => "A",
+        '\u{00C6}' => "AE",
+        '\u{00C7}' => "C",
+        '\u{00C8}'..='\u{00CB}' => "E",
+        '\u{00CC}'..='\u{00CF}' => "I",
+        '\u{00D1}' => "N",
+        '\u{00D2}'..='\u{00D6}' | '\u{00D8}' => "O",
+        '\u{00D9}'..='\u{00DC}' => "U",
+        '\u{00DD}' => "Y",
+        '\u{00DF}' => "ss",
+        '\u{00E0}'..='\u{00E5}' => "a",
+        '\u{00E6}' => "ae",
+        '\u{00E7}' => "c",
+        '\u{00E8}'..='\u{00EB}' => "e",
+        '\u{00EC}'..='\u{00EF}' => "i",
+        '\u{00F1}' => "n",
+        '\u{00F2}'..='\u{00F6}' | '\u{00F8}' => "o",
+        '\u{00F9}'..='\u{00FC}' => "u",
+        '\u{00FD}' | '\u{00FF}' => "y",
+        _ => return None,
+    };
+    Some(replacement)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_ascii_input_is_borrowed() {
+        assert!(matches!(to_ascii("Hello"), Cow::Borrowed("Hello")));
+    }
+
+    #[test]
+    fn test_transliterates_punctuation() {
+        assert_eq!(to_ascii("‘a’ “b” c…"), "'a' \"b\" c...");
+        assert_eq!(to_ascii("a–b—c"), "a-b-c");
+        assert_eq!(to_ascii("• item → next"), "* item -> next");
+    }
+
+    #[test]
+    fn test_transliterates_accented_letters() {
+        assert_eq!(to_ascii("Crème Brûlée"), "Creme Brulee");
+        assert_eq!(to_ascii("Straße"), "Strasse");
+    }
+
+    #[test]
+    fn test_replaces_unknown_and_drops_zero_width() {
+        assert_eq!(to_ascii("日本"), "??");
+        assert_eq!(to_ascii("e\u{0301}"), "e");
+        assert_eq!(to_ascii("☎\u{FE0F}"), "?");
+    }
+
+    #[test]
+    fn test_preserves_ansi_sequences() {
+        assert_eq!(to_ascii("\x1b[31mé\x1b[0m"), "\x1b[31me\x1b[0m");
+    }
+}
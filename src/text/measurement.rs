@@ -1,10 +1,61 @@
 use crate::error::BoxenError;
+use crate::text::ansi::{AnsiToken, tokenize};
 use unicode_width::UnicodeWidthStr;
 
+/// Per-character display width function, see [`crate::boxen_with_width_fn`]
+pub(crate) type WidthFn = fn(char) -> usize;
+
+/// How display widths are measured during one render
+///
+/// The default measures Unicode widths. [`crate::boxen_with_width_fn`] gives
+/// every character the width returned by the caller's function instead; the
+/// measure is passed down to content processing, wrapping, alignment, and
+/// border sizing, so nothing outside that render is affected.
+#[derive(Debug, Clone, Copy, Default)]
+pub(crate) struct Measure {
+    width_fn: Option<WidthFn>,
+}
+
+impl Measure {
+    /// Measure every character with `width_fn`
+    pub(crate) const fn with_width_fn(width_fn: WidthFn) -> Self {
+        Self {
+            width_fn: Some(width_fn),
+        }
+    }
+
+    /// Whether a caller's function replaces Unicode widths
+    pub(crate) const fn is_custom(self) -> bool {
+        self.width_fn.is_some()
+    }
+
+    /// Display width of `text`, ignoring escape sequences
+    pub(crate) fn text_width(self, text: &str) -> usize {
+        match self.width_fn {
+            Some(width_fn) => strip_ansi_codes(text).chars().map(width_fn).sum(),
+            None => text_width(text),
+        }
+    }
+
+    /// Display width of `ch`, see [`char_width_with_selector`]
+    pub(crate) fn char_width(self, ch: char, next: Option<char>) -> Option<usize> {
+        match self.width_fn {
+            Some(width_fn) => (!ch.is_control()).then(|| width_fn(ch)),
+            None => char_width_with_selector(ch, next),
+        }
+    }
+}
+
 /// Calculate the display width of text, handling Unicode and ANSI escape sequences
 /// Optimized version that avoids allocation when no ANSI codes are present
 #[must_use]
 pub fn text_width(text: &str) -> usize {
+    // Fastest path: printable ASCII is one column per byte. Control characters
+    // are excluded because `\r\n` measures as a single column.
+    if text.bytes().all(|byte| matches!(byte, b' '..=b'~')) {
+        return text.len();
+    }
+
     // Fast path: if no ANSI codes, measure directly
     if !text.contains('\x1b') {
         return UnicodeWidthStr::width(text);
@@ -15,6 +66,29 @@ pub fn text_width(text: &str) -> usize {
     UnicodeWidthStr::width(clean_text.as_str())
 }
 
+/// Display width of a string as it appears in a terminal
+///
+/// This is the measurement boxen uses for content, titles, and borders, exposed
+/// for building content outside the library. Escape sequences take no space:
+/// SGR styling (`ESC [ ... m`) and other CSI sequences are ignored, as are OSC
+/// sequences such as hyperlinks (`ESC ] 8 ;; url ESC \`). Wide characters
+/// (CJK, most emoji) count as two columns and combining marks as zero.
+///
+/// # Examples
+///
+/// ```rust
+/// use ::boxen::text::display_width;
+///
+/// assert_eq!(display_width("plain"), 5);
+/// assert_eq!(display_width("\x1b[1;31mbold red\x1b[0m"), 8);
+/// assert_eq!(display_width("\x1b]8;;https://example.com\x1b\\link\x1b]8;;\x1b\\"), 4);
+/// assert_eq!(display_width("日本語"), 6);
+/// ```
+#[must_use]
+pub fn display_width(text: &str) -> usize {
+    text_width(text)
+}
+
 /// Strip ANSI escape sequences from text
 /// Optimized version that pre-allocates capacity and uses efficient iteration
 #[must_use]
@@ -24,32 +98,280 @@ pub fn strip_ansi_codes(text: &str) -> String {
         return text.to_string();
     }
 
+    // Keep only the printable runs between escape sequences
     let mut result = String::with_capacity(text.len());
+    for token in tokenize(text) {
+        if let AnsiToken::Text(part) = token {
+            result.push_str(part);
+        }
+    }
+    result
+}
+
+/// Number of bytes in `text` taken up by ANSI escape sequences
+///
+/// This is `text.len()` minus the length of [`strip_ansi_codes`]`(text)`,
+/// useful for budgeting buffers around styled output.
+///
+/// # Examples
+///
+/// ```rust
+/// use ::boxen::text::ansi_overhead;
+///
+/// assert_eq!(ansi_overhead("plain"), 0);
+/// assert_eq!(ansi_overhead("\x1b[31mred\x1b[0m"), 9);
+/// ```
+#[must_use]
+pub fn ansi_overhead(text: &str) -> usize {
+    if !text.contains('\x1b') {
+        return 0;
+    }
+    text.len() - strip_ansi_codes(text).len()
+}
+
+/// Variation selector 16, which requests emoji presentation of the preceding character
+pub(crate) const EMOJI_PRESENTATION_SELECTOR: char = '\u{FE0F}';
+
+/// Zero-width joiner (`U+200D`), which fuses neighbouring emoji into one glyph
+pub(crate) const ZERO_WIDTH_JOINER: char = '\u{200D}';
+
+/// Display width of a character, accounting for a following VS16 selector
+///
+/// A base character such as `☎` is one column on its own but two columns when
+/// followed by `U+FE0F`. The selector itself has no width, so per-character
+/// loops should pass the next character here and consume the selector together
+/// with its base. Returns `None` for control characters, like `UnicodeWidthChar`.
+pub(crate) fn char_width_with_selector(ch: char, next: Option<char>) -> Option<usize> {
+    if next == Some(EMOJI_PRESENTATION_SELECTOR) && ch != EMOJI_PRESENTATION_SELECTOR {
+        let mut pair = [0u8; 8];
+        let base_len = ch.encode_utf8(&mut pair).len();
+        let selector_len = EMOJI_PRESENTATION_SELECTOR
+            .encode_utf8(&mut pair[base_len..])
+            .len();
+        let pair = std::str::from_utf8(&pair[..base_len + selector_len]).unwrap_or_default();
+        Some(UnicodeWidthStr::width(pair))
+    } else {
+        unicode_width::UnicodeWidthChar::width(ch)
+    }
+}
+
+/// Truncate text to at most `width` display columns, preserving ANSI sequences
+///
+/// Escape sequences after the cut point are kept (only visible characters are
+/// dropped), so styles opened before the cut are still closed by their resets.
+/// A wide character that would straddle the boundary is dropped entirely.
+///
+/// # Examples
+///
+/// ```rust
+/// use ::boxen::text::{text_width, truncate_to_width};
+///
+/// assert_eq!(truncate_to_width("Hello, world", 5), "Hello");
+/// assert_eq!(truncate_to_width("日本語", 3), "日");
+///
+/// let colored = truncate_to_width("\x1b[31mred text\x1b[0m", 3);
+/// assert_eq!(colored, "\x1b[31mred\x1b[0m");
+/// assert_eq!(text_width(&colored), 3);
+/// ```
+#[must_use]
+pub fn truncate_to_width(text: &str, width: usize) -> String {
+    truncate_to_width_measured(text, width, Measure::default())
+}
+
+/// [`truncate_to_width`] with widths taken from `measure`
+pub(crate) fn truncate_to_width_measured(text: &str, width: usize, measure: Measure) -> String {
+    if measure.text_width(text) <= width {
+        return text.to_string();
+    }
+
+    let mut result = String::with_capacity(text.len());
+    let mut current_width = 0;
+    let mut truncated = false;
     let mut chars = text.chars().peekable();
 
     while let Some(ch) = chars.next() {
-        if ch == '\x1b' {
-            // Found escape sequence start
-            if chars.peek() == Some(&'[') {
-                chars.next(); // consume '['
+        if ch == '\x1b' && chars.peek() == Some(&'[') {
+            result.push(ch);
+            result.push(chars.next().unwrap_or('['));
+            for escape_char in chars.by_ref() {
+                result.push(escape_char);
+                if escape_char.is_ascii_alphabetic() {
+                    break;
+                }
+            }
+            continue;
+        }
+
+        let char_width = measure.char_width(ch, chars.peek().copied()).unwrap_or(0);
+        let selector = chars.next_if_eq(&EMOJI_PRESENTATION_SELECTOR);
+        if truncated || current_width + char_width > width {
+            truncated = true;
+            continue;
+        }
+
+        result.push(ch);
+        if let Some(selector) = selector {
+            result.push(selector);
+        }
+        current_width += char_width;
+    }
+
+    result
+}
+
+/// Extract the display columns `start..start + width` of a single line
+///
+/// SGR styles that are active when the slice begins are re-emitted at its start
+/// and closed with a reset at its end, so every slice renders with the colors
+/// it had in the original line. Escape sequences are never split. A wide
+/// character that straddles either edge is replaced by spaces for the columns
+/// that fall inside the slice, keeping columns aligned across slices.
+pub(crate) fn slice_columns(line: &str, start: usize, width: usize) -> String {
+    let end = start + width;
+    let mut result = String::new();
+    let mut active_styles: Vec<String> = Vec::new();
+    let mut started = false;
+    let mut last_emitted = false;
+    let mut column = 0;
+    let mut chars = line.chars().peekable();
+
+    let begin = |result: &mut String, started: &mut bool, styles: &[String]| {
+        if !*started {
+            *started = true;
+            for style in styles {
+                result.push_str(style);
+            }
+        }
+    };
+
+    while let Some(ch) = chars.next() {
+        if ch == '\x1b' && chars.peek() == Some(&'[') {
+            let mut sequence = String::from(ch);
+            sequence.push(chars.next().unwrap_or('['));
+            for escape_char in chars.by_ref() {
+                sequence.push(escape_char);
+                if escape_char.is_ascii_alphabetic() {
+                    break;
+                }
+            }
+            if sequence.ends_with('m') {
+                if sequence == "\x1b[0m" || sequence == "\x1b[m" {
+                    active_styles.clear();
+                } else {
+                    active_styles.push(sequence.clone());
+                }
+            }
+            if started && column <= end {
+                result.push_str(&sequence);
+            }
+            continue;
+        }
+
+        let char_width = char_width_with_selector(ch, chars.peek().copied()).unwrap_or(0);
+        let selector = chars.next_if_eq(&EMOJI_PRESENTATION_SELECTOR);
+
+        if char_width == 0 {
+            // Combining marks follow their base character
+            if last_emitted {
+                result.push(ch);
+            }
+            continue;
+        }
 
-                // Skip until we find the end of the escape sequence
+        let char_end = column + char_width;
+        if column >= start && char_end <= end {
+            begin(&mut result, &mut started, &active_styles);
+            result.push(ch);
+            if let Some(selector) = selector {
+                result.push(selector);
+            }
+            last_emitted = true;
+        } else {
+            let visible = char_end.min(end).saturating_sub(column.max(start));
+            if visible > 0 {
+                begin(&mut result, &mut started, &active_styles);
+                result.push_str(&" ".repeat(visible));
+            }
+            last_emitted = false;
+        }
+
+        column = char_end;
+        if column >= end && chars.peek() != Some(&'\x1b') {
+            break;
+        }
+    }
+
+    if started && !active_styles.is_empty() {
+        result.push_str("\x1b[0m");
+    }
+
+    result
+}
+
+/// Replace each tab with spaces up to the next multiple of `tab_width` columns
+///
+/// Columns are counted from the start of each line, ignoring ANSI escape
+/// sequences. A `tab_width` of `0` removes tabs.
+///
+/// # Examples
+///
+/// ```rust
+/// use ::boxen::text::expand_tabs;
+///
+/// assert_eq!(expand_tabs("a\tb", 4), "a   b");
+/// assert_eq!(expand_tabs("abcd\tb\n\tc", 4), "abcd    b\n    c");
+/// assert_eq!(expand_tabs("\x1b[1mab\x1b[0m\tc", 4), "\x1b[1mab\x1b[0m  c");
+/// ```
+#[must_use]
+pub fn expand_tabs(text: &str, tab_width: usize) -> std::borrow::Cow<'_, str> {
+    expand_tabs_measured(text, tab_width, Measure::default())
+}
+
+/// [`expand_tabs`] with widths taken from `measure`
+pub(crate) fn expand_tabs_measured(
+    text: &str,
+    tab_width: usize,
+    measure: Measure,
+) -> std::borrow::Cow<'_, str> {
+    if !text.contains('\t') {
+        return std::borrow::Cow::Borrowed(text);
+    }
+
+    let mut result = String::with_capacity(text.len() + tab_width * 4);
+    let mut column = 0;
+    let mut chars = text.chars().peekable();
+    while let Some(ch) = chars.next() {
+        match ch {
+            '\t' => {
+                let spaces = if tab_width == 0 {
+                    0
+                } else {
+                    tab_width - column % tab_width
+                };
+                result.extend(std::iter::repeat_n(' ', spaces));
+                column += spaces;
+            }
+            '\n' => {
+                result.push(ch);
+                column = 0;
+            }
+            '\x1b' if chars.peek() == Some(&'[') => {
+                result.push(ch);
                 for escape_char in chars.by_ref() {
-                    // ANSI escape sequences end with a letter (A-Z, a-z)
+                    result.push(escape_char);
                     if escape_char.is_ascii_alphabetic() {
                         break;
                     }
                 }
-            } else {
-                // Not a CSI sequence, keep the escape character
+            }
+            _ => {
                 result.push(ch);
+                column += measure.char_width(ch, chars.peek().copied()).unwrap_or(0);
             }
-        } else {
-            result.push(ch);
         }
     }
 
-    result
+    std::borrow::Cow::Owned(result)
 }
 
 /// Calculate the maximum width of multiple lines of text
@@ -106,6 +428,49 @@ mod tests {
         assert_eq!(text_width("a"), 1);
     }
 
+    #[test]
+    fn test_ascii_fast_path_matches_unicode_width() {
+        let printable: String = (b' '..=b'~').map(char::from).collect();
+        assert_eq!(
+            text_width(&printable),
+            UnicodeWidthStr::width(printable.as_str())
+        );
+        for ch in printable.chars() {
+            let s = ch.to_string();
+            assert_eq!(text_width(&s), UnicodeWidthStr::width(s.as_str()), "{ch:?}");
+        }
+
+        // Control characters take the Unicode path and keep its results
+        for s in ["a\r\nb", "tab\there", "bell\x07", "line\n"] {
+            assert_eq!(text_width(s), UnicodeWidthStr::width(s), "{s:?}");
+        }
+        assert_eq!(text_width("\x1b[31mred\x1b[0m"), 3);
+    }
+
+    #[test]
+    fn test_display_width() {
+        assert_eq!(display_width(""), 0);
+        assert_eq!(display_width("\x1b[31mred\x1b[0m"), 3);
+        assert_eq!(display_width("\x1b[38;2;255;0;0mtrue\x1b[39m color"), 10);
+
+        // Hyperlinks terminated by ST or BEL
+        let st_link = "\x1b]8;;https://example.com\x1b\\docs\x1b]8;;\x1b\\";
+        let bel_link = "see \x1b]8;;https://example.com\x07docs\x1b]8;;\x07!";
+        assert_eq!(display_width(st_link), 4);
+        assert_eq!(display_width(bel_link), 9);
+        assert_eq!(strip_ansi_codes(bel_link), "see docs!");
+
+        assert_eq!(display_width("你好, world"), 11);
+        assert_eq!(display_width("\x1b[32m日本\x1b[0m"), 4);
+    }
+
+    #[test]
+    fn test_hyperlinked_content_box_width() {
+        let link = "\x1b]8;;https://example.com\x1b\\docs\x1b]8;;\x1b\\";
+        let result = crate::boxen(link, None).unwrap();
+        assert!(result.lines().all(|line| display_width(line) == 6));
+    }
+
     #[test]
     fn test_unicode_width() {
         // Wide characters (CJK)
@@ -119,6 +484,52 @@ mod tests {
         assert_eq!(text_width("a\u{200B}b"), 2); // a + zero-width space + b
     }
 
+    #[test]
+    fn test_truncate_to_width() {
+        assert_eq!(truncate_to_width("short", 10), "short");
+        assert_eq!(truncate_to_width("abcdef", 0), "");
+        assert_eq!(truncate_to_width("a日b", 2), "a");
+        assert_eq!(truncate_to_width("☎\u{FE0F}x", 1), "");
+        assert_eq!(
+            truncate_to_width("\x1b[1mbold\x1b[0m plain", 2),
+            "\x1b[1mbo\x1b[0m"
+        );
+    }
+
+    #[test]
+    fn test_typographic_characters_are_single_width() {
+        for ch in [
+            'ﬁ', 'ﬂ', 'ﬀ', '‘', '’', '“', '”', '–', '—', '…', '•', '€', '©',
+        ] {
+            assert_eq!(text_width(&ch.to_string()), 1, "{ch:?}");
+        }
+        assert_eq!(text_width("ﬁle"), 3);
+        assert_eq!(text_width("“quoted”"), 8);
+    }
+
+    #[test]
+    fn test_combining_marks_are_zero_width() {
+        assert_eq!(text_width("cafe\u{0301}"), 4);
+        assert_eq!(text_width("café"), 4);
+        assert_eq!(text_width("a\u{0301}\u{0323}"), 1);
+        assert_eq!(text_width("\x1b[1mcafe\u{0301}\x1b[0m"), 4);
+    }
+
+    #[test]
+    fn test_variation_selector_width() {
+        assert_eq!(text_width("☎"), 1);
+        assert_eq!(text_width("☎\u{FE0F}"), 2);
+        assert_eq!(text_width("\u{FE0F}"), 0);
+        assert_eq!(text_width("\x1b[31m☎\u{FE0F}\x1b[0m"), 2);
+
+        assert_eq!(char_width_with_selector('☎', None), Some(1));
+        assert_eq!(
+            char_width_with_selector('☎', Some(EMOJI_PRESENTATION_SELECTOR)),
+            Some(2)
+        );
+        assert_eq!(char_width_with_selector('a', Some('b')), Some(1));
+    }
+
     #[test]
     fn test_ansi_escape_sequences() {
         // Basic color codes
@@ -180,4 +591,28 @@ mod tests {
         assert_eq!(text_width("\t"), 1); // Tab is treated as single character
         assert_eq!(text_width("\n"), 1); // Newline has width 1 in unicode-width
     }
+
+    #[test]
+    fn test_ansi_overhead() {
+        let sequences = ["\x1b[1;38;2;255;0;0m", "\x1b[49m", "\x1b[0m"];
+        let styled = format!("{}日本{}語{}", sequences[0], sequences[1], sequences[2]);
+        let expected: usize = sequences.iter().map(|sequence| sequence.len()).sum();
+        assert_eq!(ansi_overhead(&styled), expected);
+        assert_eq!(ansi_overhead(""), 0);
+        // Hyperlinks count in full
+        assert_eq!(ansi_overhead("\x1b]8;;u\x07x\x1b]8;;\x07"), 13);
+    }
+
+    #[test]
+    fn test_expand_tabs() {
+        assert!(matches!(
+            expand_tabs("no tabs", 4),
+            std::borrow::Cow::Borrowed(_)
+        ));
+        assert_eq!(expand_tabs("\t", 4), "    ");
+        assert_eq!(expand_tabs("abc\t|", 4), "abc |");
+        // Wide characters advance two columns towards the stop
+        assert_eq!(expand_tabs("日\t|", 4), "日  |");
+        assert_eq!(expand_tabs("a\tb", 0), "ab");
+    }
 }
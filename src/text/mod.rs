@@ -225,18 +225,28 @@
 
 /// Text alignment functionality
 pub mod alignment;
+/// ANSI escape sequence tokenization
+pub mod ansi;
+/// ASCII downgrading of Unicode text
+pub mod ascii;
 /// Text measurement and width calculation
 pub mod measurement;
+/// Unicode normalization before measurement
+pub mod normalization;
 /// Unicode width caching for performance
 pub mod width_cache;
 /// Text wrapping and line breaking
 pub mod wrapping;
 
 pub use alignment::{
-    align_line, align_lines, apply_height_constraints, apply_padding, calculate_content_height,
-    calculate_content_width, process_text_alignment, process_text_with_height_constraints,
+    align_line, align_line_with, align_lines, apply_height_constraints, apply_padding,
+    calculate_content_height, calculate_content_width, is_numeric_line, process_text_alignment,
+    process_text_with_height_constraints,
 };
+pub use ansi::{AnsiToken, tokenize};
+pub use ascii::to_ascii;
 pub use measurement::*;
+pub use normalization::normalize;
 pub use width_cache::cached_unicode_width;
 pub use wrapping::*;
 
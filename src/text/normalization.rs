@@ -0,0 +1,81 @@
+//! Unicode normalization of content before it is measured
+//!
+//! The same visible text can be encoded precomposed (`é`) or decomposed (`e`
+//! followed by a combining acute accent). Normalizing to one form first keeps
+//! width measurement and wrapping consistent regardless of the input encoding.
+
+use crate::options::NormalizationForm;
+use std::borrow::Cow;
+use unicode_normalization::{IsNormalized, UnicodeNormalization, is_nfc_quick, is_nfd_quick};
+
+/// Normalize text to the given Unicode normalization form
+///
+/// Returns the input unchanged (borrowed) when it is already normalized.
+///
+/// # Examples
+///
+/// ```rust
+/// use ::boxen::{NormalizationForm, text::{normalize, text_width}};
+///
+/// let decomposed = "cafe\u{0301}";
+/// let composed = normalize(decomposed, NormalizationForm::Nfc);
+///
+/// assert_eq!(composed, "caf\u{e9}");
+/// assert_eq!(composed.chars().count(), 4);
+/// assert_eq!(text_width(&composed), 4);
+/// ```
+#[must_use]
+pub fn normalize(text: &str, form: NormalizationForm) -> Cow<'_, str> {
+    let quick_check = match form {
+        NormalizationForm::Nfc => is_nfc_quick(text.chars()),
+        NormalizationForm::Nfd => is_nfd_quick(text.chars()),
+    };
+    if quick_check == IsNormalized::Yes {
+        return Cow::Borrowed(text);
+    }
+
+    let normalized: String = match form {
+        NormalizationForm::Nfc => text.nfc().collect(),
+        NormalizationForm::Nfd => text.nfd().collect(),
+    };
+    if normalized == text {
+        Cow::Borrowed(text)
+    } else {
+        Cow::Owned(normalized)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_normalized_input_is_borrowed() {
+        assert!(matches!(
+            normalize("café", NormalizationForm::Nfc),
+            Cow::Borrowed("café")
+        ));
+        assert!(matches!(
+            normalize("plain", NormalizationForm::Nfd),
+            Cow::Borrowed("plain")
+        ));
+    }
+
+    #[test]
+    fn test_nfc_composes() {
+        assert_eq!(normalize("cafe\u{0301}", NormalizationForm::Nfc), "café");
+    }
+
+    #[test]
+    fn test_nfd_decomposes() {
+        assert_eq!(normalize("café", NormalizationForm::Nfd), "cafe\u{0301}");
+    }
+
+    #[test]
+    fn test_preserves_ansi_sequences() {
+        assert_eq!(
+            normalize("\x1b[31mcafe\u{0301}\x1b[0m", NormalizationForm::Nfc),
+            "\x1b[31mcafé\x1b[0m"
+        );
+    }
+}
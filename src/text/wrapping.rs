@@ -1,6 +1,9 @@
 use crate::error::BoxenError;
 use crate::memory::pool::with_pooled_string;
-use crate::text::measurement::{strip_ansi_codes, text_width};
+use crate::text::ansi::{AnsiToken, tokenize};
+use crate::text::measurement::{
+    EMOJI_PRESENTATION_SELECTOR, Measure, ZERO_WIDTH_JOINER, strip_ansi_codes, text_width,
+};
 use textwrap::{Options, WordSeparator, WordSplitter, wrap};
 
 /// Wrap text to fit within a specified width, preserving ANSI escape sequences
@@ -11,6 +14,15 @@ use textwrap::{Options, WordSeparator, WordSplitter, wrap};
 /// Returns `BoxenError::TextProcessingError` if:
 /// - Width is 0 (cannot wrap text to zero width)
 pub fn wrap_text(text: &str, width: usize) -> Result<Vec<String>, BoxenError> {
+    wrap_text_measured(text, width, Measure::default())
+}
+
+/// [`wrap_text`] with widths taken from `measure`
+pub(crate) fn wrap_text_measured(
+    text: &str,
+    width: usize,
+    measure: Measure,
+) -> Result<Vec<String>, BoxenError> {
     if width == 0 {
         return Err(BoxenError::text_processing_error(
             "Cannot wrap text with zero width".to_string(),
@@ -34,7 +46,7 @@ pub fn wrap_text(text: &str, width: usize) -> Result<Vec<String>, BoxenError> {
     let mut lines = Vec::with_capacity(estimated_capacity);
 
     for line in text.lines() {
-        lines.extend(wrap_line(line, width));
+        lines.extend(wrap_line_measured(line, width, measure));
     }
 
     Ok(lines)
@@ -44,33 +56,56 @@ pub fn wrap_text(text: &str, width: usize) -> Result<Vec<String>, BoxenError> {
 /// Optimized version with fast path for lines that don't need wrapping
 #[must_use]
 pub fn wrap_line(line: &str, width: usize) -> Vec<String> {
+    wrap_line_measured(line, width, Measure::default())
+}
+
+/// [`wrap_line`] with widths taken from `measure`
+pub(crate) fn wrap_line_measured(line: &str, width: usize, measure: Measure) -> Vec<String> {
     if width == 0 {
         return vec![line.to_string()];
     }
 
     // Fast path: if the line fits within the width, return it as-is
-    if text_width(line) <= width {
+    if measure.text_width(line) <= width {
         return vec![line.to_string()];
     }
 
-    // For lines with ANSI codes, we need custom wrapping logic
-    if line.contains('\x1b') {
-        wrap_line_with_ansi(line, width)
+    // For lines with ANSI codes, or when a custom width function is measuring
+    // (textwrap has its own), we need custom wrapping logic
+    if line.contains('\x1b') || measure.is_custom() {
+        wrap_line_with_ansi(line, width, measure)
     } else {
         // Use textwrap for plain text - create options once to avoid repeated allocation
+        let has_zwj = line.contains(ZERO_WIDTH_JOINER);
         let options = Options::new(width)
             .word_separator(WordSeparator::AsciiSpace)
-            .word_splitter(WordSplitter::HyphenSplitter);
-
-        wrap(line, &options)
-            .into_iter()
-            .map(|s| s.to_string())
-            .collect()
+            .word_splitter(WordSplitter::HyphenSplitter)
+            .break_words(!has_zwj);
+
+        let wrapped = wrap(line, &options);
+
+        // textwrap measures per character, so it undercounts emoji presentation
+        // sequences and would split ZWJ sequences when breaking long words;
+        // re-wrap any line that came out too wide with the cluster-aware wrapper
+        if has_zwj || line.contains(EMOJI_PRESENTATION_SELECTOR) {
+            wrapped
+                .into_iter()
+                .flat_map(|s| {
+                    if text_width(&s) > width {
+                        wrap_line_with_ansi(&s, width, measure)
+                    } else {
+                        vec![s.to_string()]
+                    }
+                })
+                .collect()
+        } else {
+            wrapped.into_iter().map(|s| s.to_string()).collect()
+        }
     }
 }
 
 /// Wrap a line containing ANSI escape sequences
-fn wrap_line_with_ansi(line: &str, width: usize) -> Vec<String> {
+fn wrap_line_with_ansi(line: &str, width: usize, measure: Measure) -> Vec<String> {
     let mut result = Vec::new();
 
     with_pooled_string(|current_line| {
@@ -80,40 +115,52 @@ fn wrap_line_with_ansi(line: &str, width: usize) -> Vec<String> {
             active_styles.reserve(50); // Typical ANSI sequence length
 
             let mut current_width = 0;
-            let mut chars = line.chars().peekable();
-
-            while let Some(ch) = chars.next() {
-                if ch == '\x1b' && chars.peek() == Some(&'[') {
-                    // Handle ANSI escape sequence - use pooled buffer
-                    with_pooled_string(|escape_seq| {
-                        // Reserve capacity for typical ANSI escape sequence (e.g., "\x1b[31m" = 5 chars)
-                        escape_seq.reserve(20); // Reserve for longer sequences like "\x1b[1;32m"
-                        escape_seq.push('\x1b');
-                        escape_seq.push(chars.next().unwrap()); // consume '['
-
-                        // Collect the full escape sequence
-                        for escape_char in chars.by_ref() {
-                            escape_seq.push(escape_char);
-                            if escape_char.is_ascii_alphabetic() {
-                                break;
-                            }
-                        }
 
-                        // Add to current line without affecting width
-                        current_line.push_str(escape_seq.as_str());
-
-                        // Track styles for line continuation
-                        if escape_seq.ends_with("m") {
-                            if escape_seq.as_str() == "\x1b[0m" {
-                                active_styles.clear(); // Reset
-                            } else {
-                                active_styles.push_str(escape_seq.as_str());
+            for token in tokenize(line) {
+                let text = match token {
+                    AnsiToken::Text(text) => text,
+                    AnsiToken::Sgr(sequence) => {
+                        // Styles are carried over to continuation lines
+                        current_line.push_str(sequence);
+                        if sequence == "\x1b[0m" {
+                            active_styles.clear();
+                        } else {
+                            active_styles.push_str(sequence);
+                        }
+                        continue;
+                    }
+                    // Other sequences stay where they are and take no columns
+                    AnsiToken::Csi(sequence) | AnsiToken::Osc(sequence) => {
+                        current_line.push_str(sequence);
+                        continue;
+                    }
+                };
+
+                let mut chars = text.chars().peekable();
+                while let Some(ch) = chars.next() {
+                    // Regular character, kept together with a trailing VS16 selector
+                    let mut char_width = measure.char_width(ch, chars.peek().copied()).unwrap_or(0);
+                    let selector = chars.next_if_eq(&EMOJI_PRESENTATION_SELECTOR);
+
+                    // Characters joined by ZWJ render as a single glyph, so the
+                    // whole sequence moves to the next line as one unit
+                    let mut joined = String::new();
+                    while let Some(joiner) = chars.next_if_eq(&ZERO_WIDTH_JOINER) {
+                        joined.push(joiner);
+                        if let Some(next) = chars.next_if(|&next| next != '\x1b') {
+                            joined.push(next);
+                            if let Some(selector) = chars.next_if_eq(&EMOJI_PRESENTATION_SELECTOR) {
+                                joined.push(selector);
                             }
                         }
-                    });
-                } else {
-                    // Regular character
-                    let char_width = unicode_width::UnicodeWidthChar::width(ch).unwrap_or(0);
+                    }
+                    if !joined.is_empty() {
+                        let mut cluster = String::with_capacity(joined.len() + 8);
+                        cluster.push(ch);
+                        cluster.extend(selector);
+                        cluster.push_str(&joined);
+                        char_width = measure.text_width(&cluster);
+                    }
 
                     if current_width + char_width > width && !current_line.is_empty() {
                         // Need to wrap - finish current line and start new one
@@ -124,6 +171,10 @@ fn wrap_line_with_ansi(line: &str, width: usize) -> Vec<String> {
                     }
 
                     current_line.push(ch);
+                    if let Some(selector) = selector {
+                        current_line.push(selector);
+                    }
+                    current_line.push_str(&joined);
                     current_width += char_width;
                 }
             }
@@ -169,7 +220,7 @@ pub fn wrap_text_preserve_words(text: &str, width: usize) -> Result<Vec<String>,
                 vec![line.to_string()]
             } else if line.contains('\x1b') {
                 // For ANSI text, use our custom wrapper
-                wrap_line_with_ansi(line, width)
+                wrap_line_with_ansi(line, width, Measure::default())
             } else {
                 // Use textwrap with word boundary preservation
                 let options = Options::new(width)
@@ -250,6 +301,63 @@ mod tests {
         assert_eq!(result, vec!["你好", "世界"]);
     }
 
+    #[test]
+    fn test_variation_selector_wrapping() {
+        let text = "☎\u{FE0F}☎\u{FE0F}☎\u{FE0F}";
+        let wrapped = wrap_text(text, 4).unwrap();
+        assert_eq!(wrapped, vec!["☎\u{FE0F}☎\u{FE0F}", "☎\u{FE0F}"]);
+
+        let colored = "\x1b[31m☎\u{FE0F}☎\u{FE0F}☎\u{FE0F}\x1b[0m";
+        for line in wrap_text(colored, 3).unwrap() {
+            assert!(text_width(&line) <= 3);
+            assert!(!strip_ansi_codes(&line).starts_with('\u{FE0F}'));
+        }
+    }
+
+    #[test]
+    fn test_zwj_sequence_wrapping() {
+        let family = "👨\u{200D}👩\u{200D}👧";
+        let text = format!("ab{family}cd");
+        let wrapped = wrap_text(&text, 3).unwrap();
+        assert_eq!(
+            wrapped,
+            vec!["ab".to_string(), format!("{family}c"), "d".to_string()]
+        );
+
+        let words = format!("hi {family} {family} ok");
+        let wrapped = wrap_text(&words, 4).unwrap();
+        assert!(wrapped.iter().all(|line| text_width(line) <= 4));
+        assert_eq!(
+            wrapped.iter().filter(|line| line.contains(family)).count(),
+            2
+        );
+
+        let colored = format!("\x1b[32m{family}{family}{family}\x1b[0m");
+        let wrapped = wrap_text(&colored, 3).unwrap();
+        assert_eq!(wrapped.len(), 3);
+        for line in &wrapped {
+            assert_eq!(strip_ansi_codes(line), family);
+        }
+    }
+
+    #[test]
+    fn test_trailing_combining_marks_stay_with_base() {
+        assert_eq!(wrap_text("a\u{0301}", 1).unwrap(), vec!["a\u{0301}"]);
+        assert_eq!(
+            wrap_text("ab\u{0301}\u{0302}", 1).unwrap(),
+            vec!["a", "b\u{0301}\u{0302}"]
+        );
+
+        // The ANSI-aware path keeps a mark that lands exactly at the width
+        let colored = "\x1b[1ma\u{0301}b\u{0301}\x1b[0m";
+        let wrapped = wrap_text(colored, 1).unwrap();
+        assert_eq!(wrapped.len(), 2);
+        for line in &wrapped {
+            assert_eq!(text_width(line), 1);
+            assert!(strip_ansi_codes(line).ends_with('\u{0301}'));
+        }
+    }
+
     #[test]
     fn test_zero_width_error() {
         let result = wrap_text("hello", 0);
@@ -297,6 +405,18 @@ mod tests {
         assert!(result.iter().any(|line| line.contains("world")));
     }
 
+    #[test]
+    fn test_hyperlink_wrapping() {
+        // The URL inside the OSC sequence takes no columns
+        let link = "\x1b]8;;https://example.com\x1b\\abcdef\x1b]8;;\x1b\\";
+        let result = wrap_line(link, 3);
+        assert_eq!(
+            result,
+            vec!["\x1b]8;;https://example.com\x1b\\abc", "def\x1b]8;;\x1b\\"]
+        );
+        assert!(result.iter().all(|line| text_width(line) == 3));
+    }
+
     #[test]
     fn test_complex_ansi_sequences() {
         let text = "\x1b[1;32mbold green\x1b[0m normal \x1b[31mred\x1b[0m";
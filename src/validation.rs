@@ -355,10 +355,12 @@
 //! - **Performance Tests**: Validation speed with large content
 //! - **Integration Tests**: End-to-end validation with real terminal constraints
 
-use crate::error::{BoxenError, ErrorRecommendation};
+use crate::error::{BoxenError, BoxenResult, ErrorRecommendation};
 use crate::options::{BoxenOptions, Height, Spacing, Width};
-use crate::terminal::{get_terminal_height, get_terminal_width};
-use crate::text::text_width;
+use crate::terminal::{
+    calculate_border_width_with_thickness, get_terminal_height, get_terminal_width,
+};
+use crate::text::{text_width, wrap_text};
 
 /// Minimum dimensions required for a box configuration
 #[derive(Debug, Clone)]
@@ -449,10 +451,12 @@ pub fn calculate_minimum_dimensions(text: &str, options: &BoxenOptions) -> Minim
     } else {
         0
     };
+    let side_border_width =
+        calculate_border_width_with_thickness(&options.border_style, options.border_thickness);
     let total_padding_width = options.padding.horizontal();
     let total_padding_height = options.padding.vertical();
 
-    let min_width = content_width + border_width + total_padding_width;
+    let min_width = content_width + side_border_width + total_padding_width;
     let min_height = content_height + border_width + total_padding_height;
 
     MinimumDimensions {
@@ -463,11 +467,12 @@ pub fn calculate_minimum_dimensions(text: &str, options: &BoxenOptions) -> Minim
     }
 }
 
-/// Validate width constraints
+/// Validate width constraints, applying the width fix to `fixed`
 fn validate_width_constraints(
     result: &mut ValidationResult,
     options: &BoxenOptions,
     min_dims: &MinimumDimensions,
+    fixed: &mut BoxenOptions,
 ) {
     if let Some(ref width_spec) = options.width {
         // Calculate actual width using terminal width as available space
@@ -475,6 +480,8 @@ fn validate_width_constraints(
         let specified_width = width_spec.calculate(terminal_width);
 
         if specified_width < min_dims.width {
+            // Fixed widths include the horizontal margins
+            fixed.width = Some(Width::Fixed(min_dims.width + options.margin.horizontal()));
             let recommendations = vec![
                 ErrorRecommendation::with_auto_fix(
                     "Specified width is too small".to_string(),
@@ -483,7 +490,8 @@ fn validate_width_constraints(
                         min_dims.width, specified_width
                     ),
                     format!(".width({})", min_dims.width),
-                ),
+                )
+                .with_fixed_options(fixed.clone()),
                 ErrorRecommendation::suggestion_only(
                     "Alternative: Reduce padding".to_string(),
                     format!(
@@ -515,11 +523,12 @@ fn validate_width_constraints(
     }
 }
 
-/// Validate height constraints
+/// Validate height constraints, applying the height fix to `fixed`
 fn validate_height_constraints(
     result: &mut ValidationResult,
     options: &BoxenOptions,
     min_dims: &MinimumDimensions,
+    fixed: &mut BoxenOptions,
 ) {
     if let Some(ref height_spec) = options.height {
         // Calculate actual height using terminal height as available space
@@ -527,6 +536,8 @@ fn validate_height_constraints(
         let specified_height = height_spec.calculate(terminal_height.unwrap_or(24));
 
         if specified_height < min_dims.height {
+            // Fixed heights include the vertical margins
+            fixed.height = Some(Height::Fixed(min_dims.height + options.margin.vertical()));
             let recommendations = vec![
                 ErrorRecommendation::with_auto_fix(
                     "Specified height is too small".to_string(),
@@ -535,7 +546,8 @@ fn validate_height_constraints(
                         min_dims.height, specified_height
                     ),
                     format!(".height({})", min_dims.height),
-                ),
+                )
+                .with_fixed_options(fixed.clone()),
                 ErrorRecommendation::suggestion_only(
                     "Alternative: Reduce padding".to_string(),
                     format!(
@@ -689,9 +701,13 @@ pub fn validate_configuration(text: &str, options: &BoxenOptions) -> ValidationR
     let min_dims = calculate_minimum_dimensions(text, options);
     result.minimum_dimensions = Some(min_dims.clone());
 
-    validate_width_constraints(&mut result, options, &min_dims);
-    validate_height_constraints(&mut result, options, &min_dims);
-    validate_terminal_constraints(&mut result, options, &min_dims);
+    // Fixes accumulate, so the last one clears every dimension error
+    let mut fixed = options.clone();
+    validate_width_constraints(&mut result, options, &min_dims, &mut fixed);
+    validate_height_constraints(&mut result, options, &min_dims, &mut fixed);
+    if !options.ignore_terminal_bounds {
+        validate_terminal_constraints(&mut result, options, &min_dims);
+    }
     collect_configuration_warnings(&mut result, text, options);
 
     result
@@ -716,6 +732,202 @@ pub fn suggest_optimal_dimensions(text: &str, options: &BoxenOptions) -> (usize,
     (optimal_width, optimal_height)
 }
 
+/// Calculate how many terminal rows a box will occupy at a given total width
+///
+/// The text is wrapped at the content width derived from `width` (after
+/// borders and padding), and the result counts every row of output: margins,
+/// borders, padding, content, and a borderless title line.
+///
+/// # Errors
+///
+/// Returns the same errors as [`crate::boxen`] when the configuration cannot be
+/// rendered at `width`, for example `BoxenError::InvalidDimensions` when the
+/// width leaves no room for content.
+///
+/// # Examples
+///
+/// ```rust
+/// use ::boxen::validation::height_at_width;
+/// use ::boxen::BoxenOptions;
+///
+/// let rows = height_at_width("Hello World", &BoxenOptions::default(), 9).unwrap();
+/// assert_eq!(rows, 4); // top border, "Hello", "World", bottom border
+/// ```
+pub fn height_at_width(text: &str, options: &BoxenOptions, width: usize) -> BoxenResult<usize> {
+    let mut sized = options.clone();
+    sized.width = Some(Width::Fixed(width));
+    // Vertical margins render as bare newlines, so count them separately
+    sized.margin.top = 0;
+    sized.margin.bottom = 0;
+
+    let rendered = crate::boxen(text, Some(sized))?;
+    Ok(rendered.lines().count() + options.margin.vertical())
+}
+
+/// Choose a box width that wraps `text` to approximately `target_lines` lines
+///
+/// Binary-searches the narrowest content width whose wrapped line count is at
+/// most `target_lines`, then adds borders and horizontal padding so the result
+/// can be passed straight to `.width()`. Widths are capped at the available
+/// terminal space, so very long text may still need more lines than requested.
+/// A `target_lines` of zero is treated as one.
+///
+/// # Examples
+///
+/// ```rust
+/// use ::boxen::validation::width_for_line_count;
+/// use ::boxen::{BoxenOptions, Width};
+///
+/// let text = "one two three four five six";
+/// let options = BoxenOptions::default();
+/// let width = width_for_line_count(text, &options, 2);
+///
+/// let lines = ::boxen::boxen(text, Some(BoxenOptions {
+///     width: Some(Width::Fixed(width)),
+///     ..options
+/// }))
+/// .unwrap()
+/// .lines()
+/// .count();
+/// assert_eq!(lines, 2 + 2); // content lines plus top and bottom border
+/// ```
+#[must_use]
+pub fn width_for_line_count(text: &str, options: &BoxenOptions, target_lines: usize) -> usize {
+    let target_lines = target_lines.max(1);
+    let side_border_width =
+        calculate_border_width_with_thickness(&options.border_style, options.border_thickness);
+    let overhead = side_border_width + options.padding.horizontal();
+
+    let natural_width = text.lines().map(text_width).max().unwrap_or(0).max(1);
+    let upper = options
+        .calculate_max_content_width()
+        .map_or(natural_width, |max| natural_width.min(max.max(1)));
+
+    let line_count = |width: usize| wrap_text(text, width).map_or(usize::MAX, |lines| lines.len());
+
+    // Line count never increases as the width grows, so search for the
+    // narrowest width that meets the target
+    let (mut low, mut high) = (1, upper);
+    while low < high {
+        let mid = low + (high - low) / 2;
+        if line_count(mid) <= target_lines {
+            high = mid;
+        } else {
+            low = mid + 1;
+        }
+    }
+
+    low + overhead
+}
+
+/// Choose a total box width whose shape is closest to `ratio` columns per row
+///
+/// Picks the content width, up to the available terminal space, whose box
+/// width divided by its height (borders and padding included, margins
+/// excluded) is nearest to `ratio`.
+/// Any `width` or `height` in `options` is ignored. The result includes
+/// horizontal margins, so it can be passed straight to `.width()`.
+///
+/// # Examples
+///
+/// ```rust
+/// use ::boxen::validation::width_for_aspect_ratio;
+/// use ::boxen::BoxenOptions;
+///
+/// let text = "a paragraph of text that should become a wide, short card";
+/// let width = width_for_aspect_ratio(text, &BoxenOptions::default(), 4.0);
+/// assert!(width > 20);
+/// ```
+#[must_use]
+pub fn width_for_aspect_ratio(text: &str, options: &BoxenOptions, ratio: f32) -> usize {
+    let unconstrained = BoxenOptions {
+        width: None,
+        height: None,
+        ..options.clone()
+    };
+    let border_width = if options.border_style.is_visible() {
+        2
+    } else {
+        0
+    };
+    let side_border_width =
+        calculate_border_width_with_thickness(&options.border_style, options.border_thickness);
+    let horizontal_overhead = side_border_width + options.padding.horizontal();
+    let vertical_overhead = border_width + options.padding.vertical();
+
+    let upper = unconstrained
+        .calculate_max_content_width()
+        .unwrap_or(1)
+        .max(1);
+    let shape = |width: usize| {
+        let rows = wrap_text(text, width).map_or(usize::MAX, |lines| lines.len());
+        (width + horizontal_overhead) as f32 / rows.saturating_add(vertical_overhead) as f32
+    };
+
+    // Rows never increase as the width grows, so the shape only widens: search
+    // for the narrowest width that reaches the ratio, then compare it with the
+    // width just below
+    let (mut low, mut high) = (1, upper);
+    while low < high {
+        let mid = low + (high - low) / 2;
+        if shape(mid) >= ratio {
+            high = mid;
+        } else {
+            low = mid + 1;
+        }
+    }
+    let best_content_width = if low > 1 && ratio - shape(low - 1) <= (shape(low) - ratio).abs() {
+        low - 1
+    } else {
+        low
+    };
+
+    best_content_width + horizontal_overhead + options.margin.horizontal()
+}
+
+/// Check that every line of a rendered box has the same display width
+///
+/// Escape sequences are ignored when measuring. Blank rows from vertical
+/// margins count as lines too, so check boxes rendered without them. Useful
+/// as a self-check after combining many layout options.
+///
+/// # Errors
+///
+/// Returns `BoxenError::RenderingError` naming the first line (1-based) whose
+/// width differs from the first line's.
+///
+/// # Examples
+///
+/// ```rust
+/// use ::boxen::{boxen, validation::assert_rectangular};
+///
+/// let rendered = boxen("Hello", None).unwrap();
+/// assert_eq!(assert_rectangular(&rendered).unwrap(), 7);
+/// assert!(assert_rectangular("┌──┐\n│Hi │\n└──┘").is_err());
+/// ```
+pub fn assert_rectangular(box_str: &str) -> BoxenResult<usize> {
+    let mut lines = box_str.lines();
+    let width = lines.next().map_or(0, text_width);
+
+    for (index, line) in lines.enumerate() {
+        let line_width = text_width(line);
+        if line_width != width {
+            let line_number = index + 2;
+            return Err(BoxenError::rendering_error(
+                format!(
+                    "Line {line_number} is {line_width} columns wide, expected {width} like line 1"
+                ),
+                vec![ErrorRecommendation::suggestion_only(
+                    "Ragged box".to_string(),
+                    format!("Check the alignment and wrapping of line {line_number}: {line:?}"),
+                )],
+            ));
+        }
+    }
+
+    Ok(width)
+}
+
 /// Auto-adjust options to fix common configuration issues
 #[must_use]
 pub fn auto_adjust_options(text: &str, mut options: BoxenOptions) -> BoxenOptions {
@@ -957,10 +1169,11 @@ pub mod recovery {
                             recover_from_invalid_height(text, recovered_options, *h);
                     }
                 }
-                BoxenError::ConfigurationError { message, .. } => {
-                    if message.contains("terminal width") || message.contains("terminal height") {
-                        recovered_options = recover_from_terminal_overflow(text, recovered_options);
-                    }
+                BoxenError::ConfigurationError { message, .. }
+                    if message.contains("terminal width")
+                        || message.contains("terminal height") =>
+                {
+                    recovered_options = recover_from_terminal_overflow(text, recovered_options);
                 }
                 _ => {}
             }
@@ -974,6 +1187,28 @@ pub mod recovery {
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_assert_rectangular() {
+        colored::control::set_override(true);
+        let options = BoxenOptions {
+            title: Some("日本".to_string()),
+            border_color: Some(crate::options::Color::Named("red".to_string())),
+            padding: Spacing::from(1),
+            ..Default::default()
+        };
+        let rendered = crate::boxen("wide 世界\nnarrow", Some(options)).unwrap();
+        assert_eq!(assert_rectangular(&rendered).unwrap(), 17);
+        assert_eq!(assert_rectangular("").unwrap(), 0);
+
+        let malformed = "┌───┐\n│ok │\n│bad│x\n└───┘";
+        match assert_rectangular(malformed) {
+            Err(BoxenError::RenderingError { message, .. }) => {
+                assert!(message.starts_with("Line 3 is 6 columns wide"), "{message}");
+            }
+            other => panic!("Expected RenderingError, got {other:?}"),
+        }
+    }
+
     #[test]
     fn test_calculate_minimum_dimensions() {
         let options = BoxenOptions::default();
@@ -997,6 +1232,65 @@ mod tests {
         assert_eq!(min_dims.height, 5); // 1 + 2 border + 2 padding
     }
 
+    #[test]
+    fn test_height_at_width_narrower_is_taller() {
+        let text = "The quick brown fox jumps over the lazy dog and keeps running";
+        let options = BoxenOptions {
+            padding: Spacing::from((1, 0)),
+            ..Default::default()
+        };
+
+        let wide = height_at_width(text, &options, 70).unwrap();
+        let narrow = height_at_width(text, &options, 24).unwrap();
+
+        assert_eq!(wide, 3);
+        assert!(narrow > wide);
+    }
+
+    #[test]
+    fn test_height_at_width_counts_margins() {
+        let options = BoxenOptions {
+            margin: Spacing::from((0, 2)),
+            ..Default::default()
+        };
+
+        let rows = height_at_width("Hello", &options, 20).unwrap();
+        let rendered = crate::boxen("Hello", Some(options)).unwrap();
+
+        assert_eq!(rows, 7);
+        assert_eq!(rendered.matches('\n').count(), rows);
+    }
+
+    #[test]
+    fn test_height_at_width_too_narrow() {
+        let result = height_at_width("Hello", &BoxenOptions::default(), 1);
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_width_for_line_count_targets_three_lines() {
+        let text = "Boxen draws tidy frames around terminal text and keeps every line aligned with its neighbours";
+        let options = BoxenOptions {
+            padding: Spacing::from((1, 0)),
+            ..Default::default()
+        };
+
+        let width = width_for_line_count(text, &options, 3);
+        let content_width = width - 2 - options.padding.horizontal();
+        let lines = wrap_text(text, content_width).unwrap().len();
+        assert!((2..=4).contains(&lines), "wrapped to {lines} lines");
+
+        let rows = height_at_width(text, &options, width).unwrap();
+        assert_eq!(rows, lines + 2);
+    }
+
+    #[test]
+    fn test_width_for_line_count_single_line() {
+        let options = BoxenOptions::default();
+        assert_eq!(width_for_line_count("Hello world", &options, 1), 13);
+        assert_eq!(width_for_line_count("Hello world", &options, 0), 13);
+    }
+
     #[test]
     fn test_validate_configuration_valid() {
         let options = BoxenOptions::default();
@@ -1006,6 +1300,18 @@ mod tests {
         assert!(result.errors.is_empty());
     }
 
+    #[test]
+    fn test_validate_configuration_ignore_terminal_bounds() {
+        let mut options = BoxenOptions {
+            width: Some(Width::Fixed(get_terminal_width() * 3)),
+            ..Default::default()
+        };
+        assert!(!validate_configuration("Hello", &options).is_valid);
+
+        options.ignore_terminal_bounds = true;
+        assert!(validate_configuration("Hello", &options).is_valid);
+    }
+
     #[test]
     fn test_validate_configuration_width_too_small() {
         let options = BoxenOptions {
@@ -1022,6 +1328,38 @@ mod tests {
         assert!(recommendations[0].auto_fix.is_some());
     }
 
+    #[test]
+    fn test_validate_configuration_fixed_options_render() {
+        let options = BoxenOptions {
+            width: Some(Width::Fixed(4)),
+            height: Some(Height::Fixed(2)),
+            padding: Spacing::from(1),
+            margin: Spacing::from((1, 1)),
+            ..Default::default()
+        };
+        let text = "Hello\nWorld";
+        let result = validate_configuration(text, &options);
+        assert_eq!(result.errors.len(), 2);
+        assert!(crate::boxen(text, Some(options.clone())).is_err());
+
+        let fixes: Vec<BoxenOptions> = result
+            .errors
+            .iter()
+            .filter_map(|error| error.recommendations()[0].fixed_options.clone())
+            .collect();
+        assert_eq!(fixes.len(), 2);
+
+        // The width fix comes first and only changes the width
+        assert_eq!(fixes[0].height, Some(Height::Fixed(2)));
+
+        // The last fix carries the earlier one too, so it renders on its own
+        let fixed = fixes[1].clone();
+        assert_eq!(fixed.width, fixes[0].width);
+        assert!(validate_configuration(text, &fixed).is_valid);
+        let rendered = crate::boxen(text, Some(fixed)).unwrap();
+        assert!(rendered.contains("Hello") && rendered.contains("World"));
+    }
+
     #[test]
     fn test_suggest_optimal_dimensions() {
         let options = BoxenOptions::default();
@@ -0,0 +1,52 @@
+//! Color decisions made per render, kept apart from tests that force colors on
+//! process-wide
+
+use ::boxen::{BoxenOptions, Color, boxen, write_boxen};
+
+fn emits_ansi() -> bool {
+    let options = BoxenOptions {
+        border_color: Some(Color::Named("red".to_string())),
+        ..Default::default()
+    };
+    boxen("x", Some(options)).unwrap().contains('\x1b')
+}
+
+#[test]
+fn test_forced_color_write_does_not_leak_into_later_renders() {
+    let before = emits_ansi();
+
+    let forced = BoxenOptions {
+        border_color: Some(Color::Named("red".to_string())),
+        force_color: Some(true),
+        ..Default::default()
+    };
+    let mut output = Vec::new();
+    write_boxen(&mut output, "x", Some(forced.clone())).unwrap();
+    assert!(String::from_utf8(output).unwrap().contains("\x1b[31m"));
+    assert_eq!(emits_ansi(), before);
+
+    // Failed renders restore the state too
+    let invalid = BoxenOptions {
+        width: Some(::boxen::Width::Fixed(1)),
+        ..forced
+    };
+    assert!(write_boxen(&mut Vec::new(), "x", Some(invalid)).is_err());
+    assert_eq!(emits_ansi(), before);
+}
+
+#[test]
+fn test_force_color_applies_to_one_render() {
+    let red = |force_color| BoxenOptions {
+        border_color: Some(Color::Named("red".to_string())),
+        inner_border: Some(::boxen::BorderStyle::Single),
+        inner_border_color: Some(Color::Named("blue".to_string())),
+        force_color,
+        ..Default::default()
+    };
+    let before = emits_ansi();
+
+    let forced = boxen("x", Some(red(Some(true)))).unwrap();
+    assert!(forced.contains("\x1b[31m") && forced.contains("\x1b[34m"));
+    assert!(!boxen("x", Some(red(Some(false)))).unwrap().contains('\x1b'));
+    assert_eq!(emits_ansi(), before);
+}
@@ -215,7 +215,7 @@ fn test_performance_different_border_styles() {
             let result = boxen(
                 text,
                 Some(BoxenOptions {
-                    border_style: *style,
+                    border_style: style.clone(),
                     ..Default::default()
                 }),
             );
@@ -469,3 +469,26 @@ fn test_performance_edge_cases() {
         );
     }
 }
+
+#[test]
+fn test_performance_ascii_width_fast_path() {
+    let ascii_line = "2024-01-01T00:00:00Z INFO request handled in 12ms status=200 ".repeat(20);
+    let unicode_line = "2024-01-01T00:00:00Z INFO réquest handled in 12ms status=200 ".repeat(20);
+    assert_eq!(
+        ::boxen::text::text_width(&ascii_line),
+        ::boxen::text::text_width(&unicode_line)
+    );
+
+    let start = Instant::now();
+    let mut total = 0;
+    for _ in 0..10_000 {
+        total += ::boxen::text::text_width(std::hint::black_box(&ascii_line));
+    }
+    let duration = start.elapsed();
+
+    assert_eq!(total, ascii_line.len() * 10_000);
+    assert!(
+        duration.as_millis() < REPEATED_RENDER_THRESHOLD,
+        "ASCII width measurement took too long: {duration:?} (threshold: {REPEATED_RENDER_THRESHOLD}ms)"
+    );
+}
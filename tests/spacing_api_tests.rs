@@ -10,6 +10,18 @@ fn test_spacing_uniform() {
     assert_eq!(spacing.left, 2);
 }
 
+#[test]
+fn test_spacing_clamp() {
+    let clamped = Spacing::from(10).clamp(5);
+    assert!(clamped.top <= 5 && clamped.right <= 5 && clamped.bottom <= 5 && clamped.left <= 5);
+    assert_eq!(clamped, Spacing::uniform(5));
+
+    // Sides already under the cap are kept
+    let mixed = Spacing::from((1, 8, 3, 12)).clamp(4);
+    assert_eq!(mixed, Spacing::from((1, 4, 3, 4)));
+    assert_eq!(Spacing::default().clamp(0), Spacing::default());
+}
+
 #[test]
 fn test_spacing_symmetric() {
     let spacing = Spacing::symmetric(4, 2);